@@ -0,0 +1,104 @@
+//! This module contains a helper to offload the execution of GraphQL
+//! requests onto a thread pool
+//!
+//! This is not async execution. All database access in wundergraph goes
+//! through diesel, which is blocking, and juniper 0.14 does not provide an
+//! async resolver API (`GraphQLTypeAsync`). Executing a request directly on
+//! the thread of an async server therefore blocks that thread for the whole
+//! query time. [`execute_async`](fn.execute_async.html) moves the blocking
+//! execution of a request onto a `futures::executor::ThreadPool` and returns
+//! a future resolving to the serialized response. The number of threads of
+//! the pool should match the size of the used connection pool.
+//!
+//! The context is moved into the blocking work as a whole, so any pooled
+//! connection held by the context is used on the worker thread:
+//!
+//! ```ignore
+//! async fn graphql(
+//!     state: AppState,
+//!     request: GraphQLRequest<WundergraphScalarValue>,
+//! ) -> Result<String, failure::Error> {
+//!     let ctx = MyContext::new(state.pool.get()?);
+//!     let response = execute_async(&state.thread_pool, state.schema.clone(), request, ctx).await;
+//!     Ok(serde_json::to_string(&response)?)
+//! }
+//! ```
+//!
+//! Requests are executed the same way as by `GraphQLRequest::execute`,
+//! so read snapshots and the redaction policy of the context apply.
+
+use crate::scalar::WundergraphScalarValue;
+use futures::executor::ThreadPool;
+use futures::future::{FutureExt, RemoteHandle};
+use juniper::http::GraphQLRequest;
+use juniper::{GraphQLType, RootNode};
+use serde::{Serialize, Serializer};
+use std::sync::Arc;
+
+/// The response of a GraphQL request executed via
+/// [`execute_async`](fn.execute_async.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsyncGraphQLResponse {
+    ok: bool,
+    body: serde_json::Value,
+}
+
+impl AsyncGraphQLResponse {
+    /// Check if the request was executed, as opposed to
+    /// failed to parse or validate
+    ///
+    /// Could be used to determine the http status code
+    pub fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    /// The serialized GraphQL response
+    pub fn body(&self) -> &serde_json::Value {
+        &self.body
+    }
+
+    /// Take the serialized GraphQL response
+    pub fn into_body(self) -> serde_json::Value {
+        self.body
+    }
+}
+
+impl Serialize for AsyncGraphQLResponse {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.body.serialize(serializer)
+    }
+}
+
+/// Execute the given GraphQL request on the given thread pool
+///
+/// The request is executed synchronously on one of the worker threads,
+/// the returned future only waits for the result. The context is moved to the worker thread and dropped there after
+/// the request was executed. If the execution panics the panic is
+/// resumed when the returned future is polled.
+pub fn execute_async<Q, M, Ctx>(
+    pool: &ThreadPool,
+    schema: Arc<RootNode<'static, Q, M, WundergraphScalarValue>>,
+    request: GraphQLRequest<WundergraphScalarValue>,
+    context: Ctx,
+) -> RemoteHandle<AsyncGraphQLResponse>
+where
+    Q: GraphQLType<WundergraphScalarValue, Context = Ctx> + 'static,
+    M: GraphQLType<WundergraphScalarValue, Context = Ctx> + 'static,
+    RootNode<'static, Q, M, WundergraphScalarValue>: Send + Sync,
+    Ctx: Send + 'static,
+{
+    let (task, handle) = async move {
+        let response = request.execute(&schema, &context);
+        AsyncGraphQLResponse {
+            ok: response.is_ok(),
+            body: serde_json::to_value(&response)
+                .expect("A GraphQL response is always serializable"),
+        }
+    }
+    .remote_handle();
+    pool.spawn_ok(task);
+    handle
+}
@@ -1,3 +1,4 @@
+use crate::error::RedactionPolicy;
 use diesel::Connection;
 
 /// A trait for types that could be used as context types for wundergraph
@@ -7,6 +8,24 @@ pub trait WundergraphContext {
 
     /// Get a connection from the context
     fn get_connection(&self) -> &Self::Connection;
+
+    /// Indicates if all database queries executed for a single GraphQL
+    /// query should see the same snapshot of the database
+    ///
+    /// Each top level field of a query is resolved inside of its own read
+    /// only transaction, see the [`transaction`](transaction/index.html)
+    /// module for details. Mutations are not affected.
+    /// Defaults to `false`.
+    fn read_snapshot(&self) -> bool {
+        false
+    }
+
+    /// Controls which details of errors are sent to clients
+    ///
+    /// Defaults to [`RedactionPolicy::HideInternal`](../error/enum.RedactionPolicy.html).
+    fn redaction_policy(&self) -> RedactionPolicy {
+        RedactionPolicy::default()
+    }
 }
 
 impl<Conn> WundergraphContext for Conn
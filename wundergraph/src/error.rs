@@ -1,6 +1,15 @@
 //! This module contains all error handling related functionality in wundergraph
+//!
+//! Errors returned from resolvers are converted into GraphQL errors carrying
+//! a stable, machine readable `extensions.code` value. Which further details
+//! are exposed to clients is controlled by the
+//! [`RedactionPolicy`](enum.RedactionPolicy.html) returned from
+//! [`WundergraphContext::redaction_policy`](../prelude/trait.WundergraphContext.html#method.redaction_policy).
 
+use crate::context::WundergraphContext;
 use crate::scalar::WundergraphScalarValue;
+use diesel::result::{DatabaseErrorInformation, DatabaseErrorKind, Error as DieselError};
+use juniper::{FieldError, IntoFieldError, Object, Value};
 use thiserror::Error;
 
 /// The main error type of wundergraph
@@ -10,6 +19,15 @@ pub enum WundergraphError {
     /// graphql arguments
     #[error("Could not build filter from arguments")]
     CouldNotBuildFilterArgument,
+    /// Indicates that a filter or order argument contained an invalid
+    /// value for a specific field
+    #[error("Could not build filter for field {field} using operator {operator}")]
+    InvalidFilter {
+        /// The name of the field the filter was applied to
+        field: String,
+        /// The name of the operator that could not be parsed
+        operator: String,
+    },
     /// Indicates that a unknown database field name was passed into
     /// wundergraph
     #[error("Requested unkown field {name}")]
@@ -21,6 +39,10 @@ pub enum WundergraphError {
     /// given arguments
     #[error("Could not build primary key filter from arguments")]
     NoPrimaryKeyArgumentFound,
+    /// Indicates that a unique key filter could not be build from the
+    /// given arguments
+    #[error("Could not build unique key filter from arguments")]
+    NoUniqueKeyArgumentFound,
     /// Indicates that building a graphql return value failed
     #[error("Failed to build a return value")]
     JuniperError {
@@ -34,7 +56,283 @@ pub enum WundergraphError {
         #[from]
         inner: diesel::result::Error,
     },
+    /// Indicates that a received change event could not be parsed
+    #[cfg(feature = "subscriptions")]
+    #[error("Received invalid change event {payload}")]
+    InvalidChangeEvent {
+        /// The payload of the change event
+        payload: String,
+    },
+    /// Indicates that a subscription document could not be executed
+    #[cfg(feature = "subscriptions")]
+    #[error("Failed to execute subscription: {message}")]
+    InvalidSubscription {
+        /// The error message returned from juniper
+        message: String,
+    },
+    /// Indicates that listening for notifications failed
+    #[cfg(feature = "pg_notify")]
+    #[error("Failed to listen for notifications")]
+    PostgresError {
+        /// Error returned from postgres
+        #[from]
+        inner: postgres_internal::Error,
+    },
 }
 
 /// Commonly used result type
 pub type Result<T> = std::result::Result<T, WundergraphError>;
+
+/// Controls which details of an error are sent to clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionPolicy {
+    /// Expose everything, including messages returned from the database
+    ///
+    /// Useful while developing, should not be used in production
+    ShowAll,
+    /// Expose the error code and the names of violated constraints,
+    /// affected tables and columns, but hide any internal message
+    HideInternal,
+    /// Only expose the error code
+    CodeOnly,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        RedactionPolicy::HideInternal
+    }
+}
+
+/// The kind of a database constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// A unique or primary key constraint
+    Unique,
+    /// A foreign key constraint
+    ForeignKey,
+    /// Any other named constraint, for example a check constraint
+    Other,
+}
+
+impl ConstraintKind {
+    /// The error code used for violations of this constraint kind
+    pub fn code(self) -> &'static str {
+        match self {
+            ConstraintKind::Unique => "UNIQUE_VIOLATION",
+            ConstraintKind::ForeignKey => "FOREIGN_KEY_VIOLATION",
+            ConstraintKind::Other => "CONSTRAINT_VIOLATION",
+        }
+    }
+
+    fn message(self) -> &'static str {
+        match self {
+            ConstraintKind::Unique => "Violated unique constraint",
+            ConstraintKind::ForeignKey => "Violated foreign key constraint",
+            ConstraintKind::Other => "Violated constraint",
+        }
+    }
+}
+
+/// Information about a violated database constraint
+///
+/// Diesel only distinguishes unique and foreign key violations, all other
+/// database errors naming a violated constraint are reported as
+/// [`ConstraintKind::Other`](enum.ConstraintKind.html#variant.Other).
+/// The set of known columns depends on the backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintViolation {
+    /// The kind of the violated constraint
+    pub kind: ConstraintKind,
+    /// The name of the violated constraint, if known
+    pub constraint: Option<String>,
+    /// The table the constraint belongs to, if known
+    pub table: Option<String>,
+    /// The columns covered by the constraint, if known
+    pub columns: Vec<String>,
+}
+
+impl ConstraintViolation {
+    /// Extract the violated constraint from a diesel error
+    ///
+    /// Returns `None` if the error is not caused by a constraint violation
+    pub fn from_diesel(error: &DieselError) -> Option<Self> {
+        let (kind, info) = match error {
+            DieselError::DatabaseError(kind, info) => (kind, info),
+            _ => return None,
+        };
+        let kind = match kind {
+            DatabaseErrorKind::UniqueViolation => ConstraintKind::Unique,
+            DatabaseErrorKind::ForeignKeyViolation => ConstraintKind::ForeignKey,
+            _ if info.constraint_name().is_some() => ConstraintKind::Other,
+            _ => return None,
+        };
+        let mut violation = Self {
+            kind,
+            constraint: info.constraint_name().map(Into::into),
+            table: info.table_name().map(Into::into),
+            columns: info.column_name().map(Into::into).into_iter().collect(),
+        };
+        if violation.columns.is_empty() {
+            violation.parse_columns(info.message(), info.details());
+        }
+        Some(violation)
+    }
+
+    fn parse_columns(&mut self, message: &str, details: Option<&str>) {
+        // Postgres: Key (hero_id, episode)=(1, 1) already exists.
+        if let Some(key) = details
+            .and_then(|d| d.split("Key (").nth(1))
+            .and_then(|d| d.split(")=").next())
+        {
+            self.columns = key.split(',').map(|c| c.trim().to_owned()).collect();
+            return;
+        }
+        // Sqlite: UNIQUE constraint failed: appears_in.hero_id, appears_in.episode
+        if let Some(failed) = message.split("constraint failed: ").nth(1) {
+            for column in failed.split(',') {
+                let mut parts = column.trim().rsplitn(2, '.');
+                let name = parts.next().unwrap_or_default();
+                if let Some(table) = parts.next() {
+                    self.table = Some(table.to_owned());
+                }
+                self.columns.push(name.to_owned());
+            }
+        }
+    }
+}
+
+impl WundergraphError {
+    /// A stable, machine readable code describing this error
+    ///
+    /// This code is sent to clients as `extensions.code`
+    pub fn code(&self) -> &'static str {
+        match self {
+            WundergraphError::CouldNotBuildFilterArgument => "INVALID_ARGUMENT",
+            WundergraphError::InvalidFilter { .. } => "INVALID_FILTER",
+            WundergraphError::UnknownDatabaseField { .. } => "UNKNOWN_FIELD",
+            WundergraphError::NoPrimaryKeyArgumentFound => "INVALID_PRIMARY_KEY",
+            WundergraphError::NoUniqueKeyArgumentFound => "INVALID_UNIQUE_KEY",
+            WundergraphError::JuniperError { .. } => "INTERNAL_ERROR",
+            WundergraphError::DieselError { inner } => {
+                if let Some(violation) = ConstraintViolation::from_diesel(inner) {
+                    return violation.kind.code();
+                }
+                match inner {
+                    DieselError::NotFound => "NOT_FOUND",
+                    DieselError::DatabaseError(DatabaseErrorKind::SerializationFailure, _) => {
+                        "SERIALIZATION_FAILURE"
+                    }
+                    DieselError::DatabaseError(..) => "DATABASE_ERROR",
+                    _ => "INTERNAL_ERROR",
+                }
+            }
+            #[cfg(feature = "subscriptions")]
+            WundergraphError::InvalidChangeEvent { .. } => "INVALID_CHANGE_EVENT",
+            #[cfg(feature = "subscriptions")]
+            WundergraphError::InvalidSubscription { .. } => "INVALID_SUBSCRIPTION",
+            #[cfg(feature = "pg_notify")]
+            WundergraphError::PostgresError { .. } => "DATABASE_ERROR",
+        }
+    }
+
+    /// Convert this error into a GraphQL error, exposing only
+    /// the details allowed by the given redaction policy
+    pub fn to_field_error(&self, policy: RedactionPolicy) -> FieldError<WundergraphScalarValue> {
+        let mut extensions = Object::with_capacity(4);
+        extensions.add_field("code", Value::scalar(self.code()));
+        let mut message = self.to_string();
+        match self {
+            WundergraphError::InvalidFilter { field, operator }
+                if policy != RedactionPolicy::CodeOnly =>
+            {
+                extensions.add_field("field", Value::scalar(field.as_str()));
+                extensions.add_field("operator", Value::scalar(operator.as_str()));
+            }
+            WundergraphError::UnknownDatabaseField { name }
+                if policy != RedactionPolicy::CodeOnly =>
+            {
+                extensions.add_field("field", Value::scalar(name.as_str()));
+            }
+            WundergraphError::JuniperError { inner } if policy == RedactionPolicy::ShowAll => {
+                message = inner.message().to_owned();
+            }
+            WundergraphError::DieselError { inner } => {
+                if let Some(violation) = ConstraintViolation::from_diesel(inner) {
+                    message = violation.kind.message().to_owned();
+                    if policy != RedactionPolicy::CodeOnly {
+                        add_violation(&mut extensions, violation);
+                    }
+                }
+                if policy == RedactionPolicy::ShowAll {
+                    if let DieselError::DatabaseError(_, info) = inner {
+                        add_database_details(&mut extensions, &**info);
+                    }
+                    message = inner.to_string();
+                }
+            }
+            _ => {}
+        }
+        FieldError::new(message, Value::object(extensions))
+    }
+}
+
+fn add_violation(extensions: &mut Object<WundergraphScalarValue>, violation: ConstraintViolation) {
+    if let Some(constraint) = violation.constraint {
+        extensions.add_field("constraint", Value::scalar(constraint));
+    }
+    if let Some(table) = violation.table {
+        extensions.add_field("table", Value::scalar(table));
+    }
+    if !violation.columns.is_empty() {
+        extensions.add_field(
+            "columns",
+            Value::list(violation.columns.into_iter().map(Value::scalar).collect()),
+        );
+    }
+}
+
+fn add_database_details(
+    extensions: &mut Object<WundergraphScalarValue>,
+    info: &(dyn DatabaseErrorInformation + Send + Sync),
+) {
+    if let Some(details) = info.details() {
+        extensions.add_field("details", Value::scalar(details));
+    }
+    if let Some(hint) = info.hint() {
+        extensions.add_field("hint", Value::scalar(hint));
+    }
+}
+
+/// Uses the default redaction policy, as no context is available here
+impl IntoFieldError<WundergraphScalarValue> for WundergraphError {
+    fn into_field_error(self) -> FieldError<WundergraphScalarValue> {
+        self.to_field_error(RedactionPolicy::default())
+    }
+}
+
+/// Convert an error into a GraphQL error with structured extensions,
+/// using the redaction policy of the given context
+///
+/// Using `?` on a wundergraph or diesel error inside of a resolver only
+/// keeps the error message, so resolvers should map errors with this
+/// function first, or use [`resolve_with`](fn.resolve_with.html).
+pub fn field_error<E, Ctx>(error: E, ctx: &Ctx) -> FieldError<WundergraphScalarValue>
+where
+    E: Into<WundergraphError>,
+    Ctx: WundergraphContext,
+{
+    error.into().to_field_error(ctx.redaction_policy())
+}
+
+/// Run a resolver returning wundergraph errors and convert a returned
+/// error into a GraphQL error using [`field_error`](fn.field_error.html)
+pub fn resolve_with<T, Ctx, F>(
+    ctx: &Ctx,
+    resolver: F,
+) -> std::result::Result<T, FieldError<WundergraphScalarValue>>
+where
+    Ctx: WundergraphContext,
+    F: FnOnce() -> Result<T>,
+{
+    resolver().map_err(|e| field_error(e, ctx))
+}
@@ -0,0 +1,440 @@
+//! This module contains helper types and functions to expose a wundergraph
+//! service as subgraph of an [Apollo Federation](https://www.apollographql.com/docs/federation/federation-spec/)
+//! gateway
+//!
+//! If the `federation` feature is enabled `query_object!` generates the
+//! following additional fields:
+//!
+//! * `_service: _Service!` returning the schema of the service in the GraphQL
+//!   schema definition language. Each registered entity is annotated with a
+//!   `@key` directive listing its primary key fields.
+//! * `_entities(representations: [_Any!]!): [_Entity]!` resolving entity
+//!   representations send by the gateway. Representations are grouped by
+//!   their `__typename`, so each entity type is loaded using a single query.
+//!
+//! Entities are annotated with the GraphQL names of their primary key fields,
+//! so `@key` fields always match fields of the printed type. Representations
+//! use the same names.
+//!
+//! The federation specification defines `_Any` as custom scalar accepting
+//! representation objects. Juniper 0.14 only passes scalar values to custom
+//! scalar types: object values are rejected while coercing variables and
+//! while validating literals, before `from_input_value` is called. Therefore
+//! `_Any` is exposed as input object containing a `__typename` field and a
+//! field for each primary key field of any registered entity. Those fields
+//! accept any scalar value. Gateways only pass representations as variables
+//! and never introspect the schema of the service, so this is transparent to
+//! them. The federation specific types and fields are not part of the
+//! returned schema definition. Once wundergraph depends on a juniper version
+//! accepting object values for custom scalars `_Any` should become
+//! `scalar _Any` as required by the specification.
+//!
+//! Juniper 0.14 does not provide a printer for the schema definition
+//! language, therefore the schema returned by `_service` is printed by
+//! [`print_schema`](fn.print_schema.html) based on the registered meta types.
+//! This printer should be replaced by juniper's schema language printer when
+//! upgrading juniper.
+
+use crate::query_builder::selection::query_resolver::get_sub_field;
+use crate::scalar::WundergraphScalarValue;
+use indexmap::IndexMap;
+use juniper::meta::{Argument, DeprecationStatus, Field, MetaType};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{
+    graphql_scalar, FromInputValue, GraphQLType, InputValue, LookAheadMethods, LookAheadSelection,
+    Object, ParseScalarResult, Registry, Selection, Type, Value,
+};
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// Types and fields defined by the federation specification
+///
+/// Those are not part of the schema definition returned by `_service`
+const FEDERATION_NAMES: &[&str] = &[
+    "_Any",
+    "_AnyValue",
+    "_Entity",
+    "_Service",
+    "_service",
+    "_entities",
+];
+const BUILTIN_SCALARS: &[&str] = &["String", "Int", "Float", "Boolean", "ID"];
+
+/// A scalar value used as value of primary key fields in entity representations
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnyValue(WundergraphScalarValue);
+
+graphql_scalar!(AnyValue as "_AnyValue" where Scalar = WundergraphScalarValue {
+    resolve(&self) -> Value {
+        Value::Scalar(self.0.clone())
+    }
+
+    from_input_value(v: &InputValue) -> Option<AnyValue> {
+        match *v {
+            InputValue::Scalar(ref s) => Some(AnyValue(s.clone())),
+            _ => None,
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        match value {
+            ScalarToken::String(v) => Ok(v.into()),
+            ScalarToken::Int(v) => v
+                .parse::<i32>()
+                .map(Into::into)
+                .or_else(|_| v.parse::<i64>().map(Into::into))
+                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value))),
+            ScalarToken::Float(v) => v
+                .parse::<f64>()
+                .map(Into::into)
+                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value))),
+        }
+    }
+});
+
+/// The `_Any` type, representing an entity by its type name and
+/// its primary key
+#[derive(Debug, Clone, PartialEq)]
+pub struct Any(InputValue<WundergraphScalarValue>);
+
+impl Any {
+    /// The GraphQL type name of the represented entity
+    pub fn type_name(&self) -> Option<&str> {
+        if let InputValue::Object(ref o) = self.0 {
+            o.iter()
+                .find(|(name, _)| name.item == "__typename")
+                .and_then(|(_, value)| value.item.as_scalar_value::<String>())
+                .map(String::as_str)
+        } else {
+            None
+        }
+    }
+
+    /// The raw representation, containing the primary key of the entity
+    pub fn value(&self) -> &InputValue<WundergraphScalarValue> {
+        &self.0
+    }
+
+    /// The primary key of the represented entity, as object mapping
+    /// column names to values
+    ///
+    /// `keys` pairs the GraphQL names of the primary key fields with
+    /// the corresponding column names, as returned by
+    /// [`key_fields`](fn.key_fields.html).
+    pub fn primary_key(&self, keys: &[(&str, &str)]) -> InputValue<WundergraphScalarValue> {
+        let mut map = IndexMap::with_capacity(keys.len());
+        if let InputValue::Object(ref o) = self.0 {
+            for (field, column) in keys {
+                if let Some((_, value)) = o.iter().find(|(name, _)| name.item == *field) {
+                    map.insert(*column, value.item.clone());
+                }
+            }
+        }
+        InputValue::object(map)
+    }
+}
+
+/// Pair the GraphQL names of the primary key fields of an entity with
+/// the corresponding primary key columns
+///
+/// `indices` are the positions of the primary key fields in `field_names`,
+/// given in the same order as `columns`.
+#[doc(hidden)]
+pub fn key_fields(
+    field_names: &[&'static str],
+    indices: Vec<usize>,
+    columns: Vec<&'static str>,
+) -> Vec<(&'static str, &'static str)> {
+    indices
+        .into_iter()
+        .map(|idx| field_names[idx])
+        .zip(columns)
+        .collect()
+}
+
+impl GraphQLType<WundergraphScalarValue> for Any {
+    type Context = ();
+    /// Names of all primary key fields
+    type TypeInfo = Vec<&'static str>;
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some("_Any")
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let mut fields = vec![registry.arg::<String>("__typename", &())];
+        fields.extend(
+            info.iter()
+                .map(|name| registry.arg::<Option<AnyValue>>(name, &())),
+        );
+        registry
+            .build_input_object_type::<Self>(info, &fields)
+            .description("The representation of an entity")
+            .into_meta()
+    }
+}
+
+impl FromInputValue<WundergraphScalarValue> for Any {
+    fn from_input_value(value: &InputValue<WundergraphScalarValue>) -> Option<Self> {
+        if let InputValue::Object(_) = *value {
+            Some(Any(value.clone()))
+        } else {
+            None
+        }
+    }
+}
+
+/// The `_Entity` union of all entities registered in `query_object!`
+#[derive(Debug, Clone, Copy)]
+pub struct Entity;
+
+impl GraphQLType<WundergraphScalarValue> for Entity {
+    type Context = ();
+    /// Names of all entity types
+    type TypeInfo = Vec<&'static str>;
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some("_Entity")
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let types = info
+            .iter()
+            .map(|name| Type::NonNullNamed(Cow::Borrowed(*name)))
+            .collect::<Vec<_>>();
+        registry.build_union_type::<Self>(info, &types).into_meta()
+    }
+}
+
+/// The `_Service` type, providing the schema of the service
+#[derive(Debug, Clone, Copy)]
+pub struct Service;
+
+impl GraphQLType<WundergraphScalarValue> for Service {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some("_Service")
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = [registry.field::<Option<String>>("sdl", &())];
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+/// Resolve the `_Service` object for a given schema definition
+#[doc(hidden)]
+pub fn service_value(
+    sdl: String,
+    look_ahead: &LookAheadSelection<'_, WundergraphScalarValue>,
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+) -> Value<WundergraphScalarValue> {
+    let mut obj = Object::with_capacity(1);
+    if look_ahead.select_child("sdl").is_some() {
+        obj.add_field(get_sub_field("sdl", selection).1, Value::scalar(sdl));
+    }
+    if look_ahead.select_child("__typename").is_some() {
+        obj.add_field(
+            get_sub_field("__typename", selection).1,
+            Value::scalar(String::from("_Service")),
+        );
+    }
+    Value::Object(obj)
+}
+
+/// Add the type name to already resolved entities if it is requested
+#[doc(hidden)]
+pub fn add_type_names(
+    values: &mut [Value<WundergraphScalarValue>],
+    type_name: &str,
+    look_ahead: &LookAheadSelection<'_, WundergraphScalarValue>,
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+) {
+    if look_ahead.select_child("__typename").is_some() {
+        let alias = get_sub_field("__typename", selection).1;
+        for value in values {
+            if let Value::Object(ref mut obj) = *value {
+                obj.add_field(alias, Value::scalar(type_name.to_owned()));
+            }
+        }
+    }
+}
+
+/// Print the given schema types in the GraphQL schema definition language
+///
+/// Each entity is given as pair of its type name and the GraphQL names
+/// of its primary key fields.
+///
+/// Only used because juniper 0.14 does not expose a schema language printer.
+#[doc(hidden)]
+pub fn print_schema(
+    types: Vec<&MetaType<'_, WundergraphScalarValue>>,
+    query: &MetaType<'_, WundergraphScalarValue>,
+    mutation: Option<&MetaType<'_, WundergraphScalarValue>>,
+    entities: &[(&str, Vec<&str>)],
+) -> String {
+    let query_name = query.name().unwrap_or("Query");
+    let mutation_name = mutation.and_then(|m| m.name());
+    let mut out = String::new();
+    if query_name != "Query" || mutation_name.map_or(false, |m| m != "Mutation") {
+        out.push_str("schema {\n");
+        let _ = writeln!(out, "  query: {}", query_name);
+        if let Some(mutation_name) = mutation_name {
+            let _ = writeln!(out, "  mutation: {}", mutation_name);
+        }
+        out.push_str("}\n\n");
+    }
+
+    let mut types = types
+        .into_iter()
+        .filter(|t| {
+            t.name().map_or(false, |name| {
+                !name.starts_with("__")
+                    && !FEDERATION_NAMES.contains(&name)
+                    && !BUILTIN_SCALARS.contains(&name)
+            })
+        })
+        .collect::<Vec<_>>();
+    // Print the root types first, all other types in alphabetic order
+    // to get a stable output
+    types.sort_by_key(|t| {
+        let name = t.name();
+        (
+            name != Some(query_name),
+            name != mutation_name,
+            name.map(String::from),
+        )
+    });
+
+    for tpe in types {
+        print_type(&mut out, tpe, entities);
+        out.push('\n');
+    }
+    out.pop();
+    out
+}
+
+fn print_type(
+    out: &mut String,
+    tpe: &MetaType<'_, WundergraphScalarValue>,
+    entities: &[(&str, Vec<&str>)],
+) {
+    print_description(out, tpe.description(), "");
+    match *tpe {
+        MetaType::Scalar(ref s) => {
+            let _ = writeln!(out, "scalar {}", s.name);
+        }
+        MetaType::Object(ref o) => {
+            let _ = write!(out, "type {}", o.name);
+            if !o.interface_names.is_empty() {
+                let _ = write!(out, " implements {}", o.interface_names.join(" & "));
+            }
+            if let Some((_, keys)) = entities.iter().find(|(name, _)| *name == o.name) {
+                let _ = write!(out, " @key(fields: \"{}\")", keys.join(" "));
+            }
+            print_fields(out, &o.fields);
+        }
+        MetaType::Interface(ref i) => {
+            let _ = write!(out, "interface {}", i.name);
+            print_fields(out, &i.fields);
+        }
+        MetaType::Union(ref u) => {
+            let _ = writeln!(out, "union {} = {}", u.name, u.of_type_names.join(" | "));
+        }
+        MetaType::Enum(ref e) => {
+            let _ = writeln!(out, "enum {} {{", e.name);
+            for value in &e.values {
+                print_description(out, value.description.as_ref(), "  ");
+                let _ = write!(out, "  {}", value.name);
+                print_deprecation(out, &value.deprecation_status);
+                out.push('\n');
+            }
+            out.push_str("}\n");
+        }
+        MetaType::InputObject(ref i) => {
+            let _ = writeln!(out, "input {} {{", i.name);
+            for field in &i.input_fields {
+                print_description(out, field.description.as_ref(), "  ");
+                out.push_str("  ");
+                print_argument(out, field);
+                out.push('\n');
+            }
+            out.push_str("}\n");
+        }
+        MetaType::List(_) | MetaType::Nullable(_) | MetaType::Placeholder(_) => {}
+    }
+}
+
+fn print_fields(out: &mut String, fields: &[Field<'_, WundergraphScalarValue>]) {
+    out.push_str(" {\n");
+    for field in fields
+        .iter()
+        .filter(|f| !FEDERATION_NAMES.contains(&&*f.name))
+    {
+        print_description(out, field.description.as_ref(), "  ");
+        let _ = write!(out, "  {}", field.name);
+        if let Some(ref arguments) = field.arguments {
+            out.push('(');
+            for (idx, argument) in arguments.iter().enumerate() {
+                if idx != 0 {
+                    out.push_str(", ");
+                }
+                print_argument(out, argument);
+            }
+            out.push(')');
+        }
+        let _ = write!(out, ": {}", field.field_type);
+        print_deprecation(out, &field.deprecation_status);
+        out.push('\n');
+    }
+    out.push_str("}\n");
+}
+
+fn print_argument(out: &mut String, argument: &Argument<'_, WundergraphScalarValue>) {
+    let _ = write!(out, "{}: {}", argument.name, argument.arg_type);
+    if let Some(ref default) = argument.default_value {
+        let _ = write!(out, " = {}", default);
+    }
+}
+
+fn print_description(out: &mut String, description: Option<&String>, indent: &str) {
+    if let Some(description) = description.map(|d| d.trim()).filter(|d| !d.is_empty()) {
+        let _ = writeln!(
+            out,
+            "{}\"\"\"{}\"\"\"",
+            indent,
+            description.replace("\"\"\"", "\\\"\"\"")
+        );
+    }
+}
+
+fn print_deprecation(out: &mut String, deprecation: &DeprecationStatus) {
+    if deprecation.is_deprecated() {
+        out.push_str(" @deprecated");
+        if let Some(reason) = deprecation.reason() {
+            let _ = write!(out, "(reason: {:?})", reason);
+        }
+    }
+}
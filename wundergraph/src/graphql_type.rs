@@ -69,6 +69,12 @@ pub trait WundergraphGraphqlHelper<L, DB, Ctx> {
     ) -> meta::MetaType<'r, WundergraphScalarValue>
     where
         T: GraphQLType<WundergraphScalarValue, TypeInfo = ()>;
+
+    fn field_argument<'r>(
+        idx: usize,
+        name: &str,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Option<meta::Argument<'r, WundergraphScalarValue>>;
 }
 
 macro_rules! wundergraph_graphql_helper_impl {
@@ -92,7 +98,8 @@ macro_rules! wundergraph_graphql_helper_impl {
                 ) -> meta::MetaType<'r, WundergraphScalarValue>
                     where Type: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
                 {
-                    let fields  = [
+                    #[allow(unused_mut)]
+                    let mut fields  = vec![
                         $({
                             let mut field = registry.field::<<$T as WundergraphGraphqlMapper<Back, Ctx>>::GraphQLType>(names[$idx], &());
                             field = <$T as WundergraphGraphqlMapper<Back, Ctx>>::register_arguments(registry, field);
@@ -105,6 +112,11 @@ macro_rules! wundergraph_graphql_helper_impl {
                             field
                         },)*
                     ];
+                    #[cfg(feature = "relay")]
+                    {
+                        fields.retain(|f| f.name != crate::relay::GLOBAL_ID_FIELD);
+                        fields.push(registry.field::<juniper::ID>(crate::relay::GLOBAL_ID_FIELD, &()));
+                    }
                     let mut ty = registry.build_object_type::<Type>(
                         &(),
                         &fields,
@@ -112,8 +124,26 @@ macro_rules! wundergraph_graphql_helper_impl {
                     if let Some(doc) = Loading::TYPE_DESCRIPTION {
                         ty = ty.description(doc);
                     }
+                    #[cfg(feature = "relay")]
+                    {
+                        ty = ty.interfaces(&[registry.get_type::<crate::relay::Node>(&())]);
+                    }
                     meta::MetaType::Object(ty)
                 }
+
+                fn field_argument<'r>(
+                    idx: usize,
+                    name: &str,
+                    registry: &mut Registry<'r, WundergraphScalarValue>,
+                ) -> Option<meta::Argument<'r, WundergraphScalarValue>> {
+                    match idx {
+                        $($idx => Some(meta::Argument::new(
+                            name,
+                            registry.get_type::<<$T as WundergraphGraphqlMapper<Back, Ctx>>::GraphQLType>(&()),
+                        )),)*
+                        _ => None,
+                    }
+                }
             }
         )*
     };
@@ -92,7 +92,9 @@ pub trait PrimaryKeyInputObject<V, I> {
 
     fn from_input_value(value: &InputValue<WundergraphScalarValue>) -> Option<V>;
     fn from_look_ahead(look_ahead: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<V>;
+    fn from_id(id: &str) -> Option<V>;
     fn to_input_value(values: &V) -> InputValue<WundergraphScalarValue>;
+    fn key_names() -> Vec<&'static str>;
 }
 
 impl<A, V1, I> PrimaryKeyInputObject<V1, I> for A
@@ -130,11 +132,30 @@ where
         }
     }
 
+    fn from_id(id: &str) -> Option<V1> {
+        // Ids are always given as strings, so numeric keys
+        // are parsed into the smallest fitting integer type
+        let candidates = [
+            Some(WundergraphScalarValue::String(id.to_owned())),
+            id.parse().ok().map(WundergraphScalarValue::SmallInt),
+            id.parse().ok().map(WundergraphScalarValue::Int),
+            id.parse().ok().map(WundergraphScalarValue::BigInt),
+        ];
+        candidates
+            .iter()
+            .filter_map(Option::as_ref)
+            .find_map(|v| V1::from_look_ahead(&LookAheadValue::Scalar(v)))
+    }
+
     fn to_input_value(values: &V1) -> InputValue<WundergraphScalarValue> {
         let mut map = IndexMap::with_capacity(1);
         map.insert(Self::NAME, values.to_input_value());
         InputValue::object(map)
     }
+
+    fn key_names() -> Vec<&'static str> {
+        vec![Self::NAME]
+    }
 }
 
 macro_rules! primary_key_input_object_impl {
@@ -184,6 +205,12 @@ macro_rules! primary_key_input_object_impl {
                     }
                 }
 
+                fn from_id(_id: &str) -> Option<($($ST, )+)> {
+                    // A single id could not be mapped onto
+                    // a composite primary key
+                    None
+                }
+
                 fn to_input_value(values: &($($ST, )+)) -> InputValue<WundergraphScalarValue> {
                    let mut map = IndexMap::with_capacity($Tuple);
                    $(
@@ -191,6 +218,14 @@ macro_rules! primary_key_input_object_impl {
                    )+
                    InputValue::object(map)
                 }
+
+                fn key_names() -> Vec<&'static str> {
+                    let mut ret = Vec::new();
+                    $(
+                        ret.extend(<$T as PrimaryKeyInputObject<$ST, __I>>::key_names());
+                    )+
+                    ret
+                }
             }
         )+
     }
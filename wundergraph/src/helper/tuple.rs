@@ -3,6 +3,9 @@
 pub trait IsPrimaryKeyIndex {
     /// Check if this type represents the index with the given value
     fn is_index(v: usize) -> bool;
+
+    /// All indices represented by this type, in declaration order
+    fn indices() -> Vec<usize>;
 }
 
 /// A trait to have a type level index into a tuple
@@ -58,6 +61,10 @@ impl IsPrimaryKeyIndex for TupleIndex0 {
     fn is_index(v: usize) -> bool {
         0 == v
     }
+
+    fn indices() -> Vec<usize> {
+        vec![0]
+    }
 }
 
 macro_rules! name_from_idx {
@@ -155,6 +162,10 @@ macro_rules! create_tuple_index {
             fn is_index(v: usize) -> bool {
                 $tuple_idx == v
             }
+
+            fn indices() -> Vec<usize> {
+                vec![$tuple_idx]
+            }
         }
         create_tuple_index!(@call_tuple [$($idx)*] @  ($tuple_idx, $($T,)*));
     }
@@ -235,6 +246,14 @@ macro_rules! impl_tuple_macro_wrapper {
                     )*
                     false
                 }
+
+                fn indices() -> Vec<usize> {
+                    let mut ret = Vec::new();
+                    $(
+                        ret.extend(<$T as IsPrimaryKeyIndex>::indices());
+                    )*
+                    ret
+                }
             }
 
             impl<$($T,)*> ConcatTuples<()> for ($($T,)*)
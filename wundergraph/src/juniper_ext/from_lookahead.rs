@@ -1,5 +1,6 @@
 use crate::scalar::WundergraphScalarValue;
 use juniper::{LookAheadValue, ID};
+use std::convert::TryFrom;
 
 /// A helper trait marking how to convert a `LookAheadValue` into a specific type
 pub trait FromLookAheadValue: Sized {
@@ -35,6 +36,52 @@ impl FromLookAheadValue for i64 {
             LookAheadValue::Scalar(WundergraphScalarValue::SmallInt(ref i)) => Some(Self::from(*i)),
             LookAheadValue::Scalar(WundergraphScalarValue::Int(ref i)) => Some(Self::from(*i)),
             LookAheadValue::Scalar(WundergraphScalarValue::BigInt(ref i)) => Some(*i),
+            // Filters on `u64` columns are using `i64` values, while
+            // the corresponding graphql values are transferred as strings
+            LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) => s.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromLookAheadValue for u16 {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        match *v {
+            LookAheadValue::Scalar(WundergraphScalarValue::SmallInt(ref i)) => {
+                Self::try_from(*i).ok()
+            }
+            LookAheadValue::Scalar(WundergraphScalarValue::Int(ref i)) => Self::try_from(*i).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromLookAheadValue for u32 {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        match *v {
+            LookAheadValue::Scalar(WundergraphScalarValue::SmallInt(ref i)) => {
+                Self::try_from(*i).ok()
+            }
+            LookAheadValue::Scalar(WundergraphScalarValue::Int(ref i)) => Self::try_from(*i).ok(),
+            LookAheadValue::Scalar(WundergraphScalarValue::BigInt(ref i)) => {
+                Self::try_from(*i).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FromLookAheadValue for u64 {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        match *v {
+            LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) => s.parse().ok(),
+            LookAheadValue::Scalar(WundergraphScalarValue::SmallInt(ref i)) => {
+                Self::try_from(*i).ok()
+            }
+            LookAheadValue::Scalar(WundergraphScalarValue::Int(ref i)) => Self::try_from(*i).ok(),
+            LookAheadValue::Scalar(WundergraphScalarValue::BigInt(ref i)) => {
+                Self::try_from(*i).ok()
+            }
             _ => None,
         }
     }
@@ -60,6 +60,24 @@ impl Nameable for i64 {
     }
 }
 
+impl Nameable for u16 {
+    fn name() -> String {
+        String::from("UnsignedSmallInt")
+    }
+}
+
+impl Nameable for u32 {
+    fn name() -> String {
+        String::from("UnsignedInt")
+    }
+}
+
+impl Nameable for u64 {
+    fn name() -> String {
+        String::from("UnsignedBigInt")
+    }
+}
+
 impl Nameable for f32 {
     fn name() -> String {
         String::from("Float")
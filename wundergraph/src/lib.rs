@@ -118,17 +118,26 @@ pub extern crate paste;
 
 pub use wundergraph_derive::WundergraphEntity;
 
+#[cfg(feature = "async")]
+pub mod async_execution;
 pub mod diesel_ext;
 pub mod error;
+#[cfg(feature = "federation")]
+pub mod federation;
 pub mod helper;
 pub mod juniper_ext;
 pub mod scalar;
+pub mod transaction;
 #[macro_use]
 mod macros;
 pub(crate) mod context;
 #[doc(hidden)]
 pub mod graphql_type;
 pub mod query_builder;
+#[cfg(feature = "relay")]
+pub mod relay;
+#[cfg(feature = "subscriptions")]
+pub mod subscription;
 
 mod third_party_integrations;
 
@@ -2,6 +2,9 @@
 mod query;
 #[macro_use]
 mod mutation;
+#[cfg(feature = "subscriptions")]
+#[macro_use]
+mod subscription;
 
 #[doc(hidden)]
 #[macro_export]
@@ -294,6 +294,29 @@ macro_rules! __impl_graphql_obj_for_mutation {
     }
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_mutation_option {
+    (atomic, [atomic = $value: expr, $($rest: tt)*]) => {
+        $value
+    };
+    (payload, [payload = $value: expr, $($rest: tt)*]) => {
+        $value
+    };
+    ($option: ident, [atomic = $value: expr, $($rest: tt)*]) => {
+        $crate::__expand_mutation_option!($option, [$($rest)*])
+    };
+    ($option: ident, [payload = $value: expr, $($rest: tt)*]) => {
+        $crate::__expand_mutation_option!($option, [$($rest)*])
+    };
+    ($option: ident, [$other: ident = $($rest: tt)*]) => {
+        compile_error!(concat!("Unknown mutation object option `", stringify!($other), "`"))
+    };
+    ($option: ident, []) => {
+        false
+    };
+}
+
 /// Macro to register the main mutation object
 ///
 /// # Annotated example
@@ -355,6 +378,22 @@ macro_rules! __impl_graphql_obj_for_mutation {
 ///
 ///     /// An optional doc comment describing the main mutation object
 ///     /// Rendered as GraphQL description
+///
+///     // An optional attribute to configure the mutation object:
+///     //  * atomic: Enables the atomic mode. In this mode all mutation
+///     //    fields of one operation are executed inside of a single
+///     //    transaction, that is rolled back entirely as soon as one of
+///     //    the fields fails. Otherwise each field is executed in its own
+///     //    transaction.
+///     //  * payload: Enables the payload mode. In this mode insert and
+///     //    update mutations return a payload type like
+///     //    `CreateHeroPayload { hero, errors }` instead of the entity.
+///     //    Violated database constraints are returned as user errors
+///     //    pointing to the offending input field instead of top level
+///     //    errors. Batch inserts and deletes are not affected. In
+///     //    atomic mode a payload containing user errors rolls back the
+///     //    transaction as well.
+///     #[wundergraph(atomic = true, payload = true)]
 ///     Mutation {
 ///         // Register mutations for a wundergraph GraphQL entity
 ///         //
@@ -386,6 +425,7 @@ macro_rules! __impl_graphql_obj_for_mutation {
 macro_rules! mutation_object {
     (
         $(#[doc = $glob_doc: expr])*
+        $(#[wundergraph($($option: ident = $value: expr),+ $(,)?)])?
         $mutation_name: ident {
             $($entity_name: ident (
                 $(insert = $insert: ident)?
@@ -445,9 +485,108 @@ macro_rules! mutation_object {
                             ::std::marker::PhantomData,
                             field_name,
                             arguments,
+                            None,
+                        );
+                        executor.resolve(info, &wrapper)
+                    }
+
+                    fn resolve(
+                        &self,
+                        info: &Self::TypeInfo,
+                        _selection_set: ::std::option::Option<&[$crate::juniper::Selection<$crate::scalar::WundergraphScalarValue>]>,
+                        executor: &$crate::juniper::Executor<Self::Context, $crate::scalar::WundergraphScalarValue>,
+                    ) -> $crate::juniper::Value<$crate::scalar::WundergraphScalarValue> {
+                        let fields = [<$mutation_name _fields>](
+                            ::std::marker::PhantomData,
+                            ::std::cell::Cell::new(false),
+                        );
+                        if !$crate::__expand_mutation_option!(atomic, [$($($option = $value,)+)?]) {
+                            return executor.resolve_into_value(info, &fields);
+                        }
+                        // Run all fields inside of a single transaction and
+                        // roll back all of them as soon as one field failed
+                        let conn = <Ctx as $crate::WundergraphContext>::get_connection(executor.context());
+                        let r = $crate::diesel::Connection::transaction(conn, || {
+                            let value = executor.resolve_into_value(info, &fields);
+                            if fields.1.get() {
+                                Err($crate::diesel::result::Error::RollbackTransaction)
+                            } else {
+                                Ok(value)
+                            }
+                        });
+                        match r {
+                            ::std::result::Result::Ok(v) => v,
+                            ::std::result::Result::Err($crate::diesel::result::Error::RollbackTransaction) => {
+                                executor.push_error($crate::juniper::FieldError::new(
+                                    "Rolled back all mutations because of a failed field",
+                                    $crate::juniper::Value::null(),
+                                ));
+                                $crate::juniper::Value::null()
+                            }
+                            ::std::result::Result::Err(e) => {
+                                executor.push_error($crate::error::field_error(e, executor.context()));
+                                $crate::juniper::Value::null()
+                            }
+                        }
+                    }
+                }
+            }
+
+            #[derive(Debug)]
+            #[doc(hidden)]
+            /// An internal helper type
+            pub struct [<$mutation_name _fields>]<C>(
+                // Use Arc<Mutex<C>> here to force make this Sync
+                ::std::marker::PhantomData<std::sync::Arc<std::sync::Mutex<C>>>,
+                // Set as soon as one of the fields failed
+                ::std::cell::Cell<bool>,
+            );
+
+            $crate::__impl_graphql_obj_for_mutation! {
+                mutation_name = {[<$mutation_name _fields>]},
+                structs = [$($entity_name(
+                    $(insert = $insert,)?
+                    $(update = $update,)?
+                    $(delete = ($($delete)*))?
+                ),)*],
+                body = {
+                    type Context = Ctx;
+
+                    type TypeInfo = ();
+
+                    fn name(info: &Self::TypeInfo) -> ::std::option::Option<&str> {
+                        <[<$mutation_name _inner>]<Ctx> as $crate::juniper::GraphQLType<$crate::scalar::WundergraphScalarValue>>::name(info)
+                    }
+
+                    fn meta<'r>(
+                        info: &Self::TypeInfo,
+                        registry: &mut $crate::juniper::Registry<'r, $crate::scalar::WundergraphScalarValue>
+                    ) -> $crate::juniper::meta::MetaType<'r, $crate::scalar::WundergraphScalarValue>
+                    where
+                        $crate::scalar::WundergraphScalarValue: 'r
+                    {
+                        <[<$mutation_name _inner>]<Ctx> as $crate::juniper::GraphQLType<$crate::scalar::WundergraphScalarValue>>::meta(info, registry)
+                    }
+
+                    fn resolve_field(
+                        &self,
+                        info: &Self::TypeInfo,
+                        field_name: &str,
+                        arguments: &$crate::juniper::Arguments<$crate::scalar::WundergraphScalarValue>,
+                        executor: &$crate::juniper::Executor<Self::Context, $crate::scalar::WundergraphScalarValue>,
+                    ) -> $crate::juniper::ExecutionResult<$crate::scalar::WundergraphScalarValue> {
+                        let wrapper = [<$mutation_name _wrapper>](
+                            ::std::marker::PhantomData,
+                            field_name,
+                            arguments,
+                            Some(&self.1),
                         );
                         executor.resolve(info, &wrapper)
                     }
+
+                    fn concrete_type_name(&self, _context: &Self::Context, _info: &Self::TypeInfo) -> String {
+                        String::from(stringify!($mutation_name))
+                    }
                 }
             }
 
@@ -459,6 +598,8 @@ macro_rules! mutation_object {
                 ::std::marker::PhantomData<std::sync::Arc<std::sync::Mutex<C>>>,
                 &'a str,
                 &'a $crate::juniper::Arguments<'a, $crate::scalar::WundergraphScalarValue>,
+                // Marks the failure of the field for atomic mutations
+                ::std::option::Option<&'a ::std::cell::Cell<bool>>,
             );
 
             $crate::__impl_graphql_obj_for_mutation! {
@@ -496,7 +637,8 @@ macro_rules! mutation_object {
                     ) -> $crate::juniper::Value<$crate::scalar::WundergraphScalarValue> {
                         let inner = [<$mutation_name _inner>] (
                             ::std::marker::PhantomData,
-                            selection_set
+                            selection_set,
+                            self.3,
                         );
                         let r = <[<$mutation_name _inner>]<Ctx> as $crate::juniper::GraphQLType<$crate::scalar::WundergraphScalarValue>>::resolve_field(
                             &inner,
@@ -508,6 +650,9 @@ macro_rules! mutation_object {
                         match r {
                             ::std::result::Result::Ok(v) => v,
                             ::std::result::Result::Err(e) => {
+                                if let ::std::option::Option::Some(failed) = self.3 {
+                                    failed.set(true);
+                                }
                                 executor.push_error(e);
                                 $crate::juniper::Value::null()
                             }
@@ -524,6 +669,8 @@ macro_rules! mutation_object {
                 // Use Arc<Mutex<C>> here to force make this Sync
                 ::std::marker::PhantomData<std::sync::Arc<std::sync::Mutex<C>>>,
                 ::std::option::Option<&'a [$crate::juniper::Selection<'a, $crate::scalar::WundergraphScalarValue>]>,
+                // Marks the failure of the field for atomic mutations
+                ::std::option::Option<&'a ::std::cell::Cell<bool>>,
             );
 
             $crate::__impl_graphql_obj_for_mutation! {
@@ -549,14 +696,28 @@ macro_rules! mutation_object {
                     ) -> $crate::juniper::meta::MetaType<'r, $crate::scalar::WundergraphScalarValue>
                     where $crate::scalar::WundergraphScalarValue: 'r
                     {
+                        #[allow(unused_variables)]
+                        let payload = $crate::__expand_mutation_option!(payload, [$($($option = $value,)+)?]);
                         let mut fields = Vec::new();
                         $(
                             $(
                                 let new = registry.arg::<$insert>(concat!("New", stringify!($entity_name)), info);
-                                let new = registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>>>(
-                                    concat!("Create", stringify!($entity_name)),
-                                    info
-                                ).argument(new);
+                                let new = if payload {
+                                    registry.field::<Option<$crate::query_builder::mutations::MutationPayload<
+                                        $crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>
+                                    >>>(
+                                        concat!("Create", stringify!($entity_name)),
+                                        &$crate::query_builder::mutations::PayloadInfo::new(
+                                            concat!("Create", stringify!($entity_name)),
+                                            stringify!($entity_name),
+                                        )
+                                    )
+                                } else {
+                                    registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>>>(
+                                        concat!("Create", stringify!($entity_name)),
+                                        info
+                                    )
+                                }.argument(new);
                                 fields.push(new);
                                 let new = registry.arg::<Vec<$insert>>(concat!("New", stringify!($entity_name), "s"), info);
                                 let new = registry.field::<Vec<$crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>>>(
@@ -570,10 +731,22 @@ macro_rules! mutation_object {
                             $(
                                 $(
                                     let update = registry.arg::<$update>(concat!("Update", stringify!($entity_name)), info);
-                                    let update = registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>>>(
-                                        concat!("Update", stringify!($entity_name)),
-                                        info
-                                    ).argument(update);
+                                    let update = if payload {
+                                        registry.field::<Option<$crate::query_builder::mutations::MutationPayload<
+                                            $crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>
+                                        >>>(
+                                            concat!("Update", stringify!($entity_name)),
+                                            &$crate::query_builder::mutations::PayloadInfo::new(
+                                                concat!("Update", stringify!($entity_name)),
+                                                stringify!($entity_name),
+                                            )
+                                        )
+                                    } else {
+                                        registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>>>(
+                                            concat!("Update", stringify!($entity_name)),
+                                            info
+                                        )
+                                    }.argument(update);
                                     fields.push(update);
                                 )*
                             )*
@@ -592,21 +765,39 @@ macro_rules! mutation_object {
                         arguments: &$crate::juniper::Arguments<$crate::scalar::WundergraphScalarValue>,
                         executor: &$crate::juniper::Executor<Self::Context, $crate::scalar::WundergraphScalarValue>,
                     ) -> $crate::juniper::ExecutionResult<$crate::scalar::WundergraphScalarValue> {
+                        #[allow(unused_variables)]
+                        let payload = $crate::__expand_mutation_option!(payload, [$($($option = $value,)+)?]);
                         match field_name {
                             $(
                                 $(
                                     concat!("Create", stringify!($entity_name)) => {
-                                        $crate::query_builder::mutations::handle_insert::<
-                                            DB,
-                                        $insert,
-                                        $entity_name,
-                                        Self::Context>
-                                            (
+                                        if payload {
+                                            $crate::query_builder::mutations::resolve_payload::<
+                                                $insert,
+                                                $crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>,
+                                                _,
+                                                _,
+                                            >(
+                                                concat!("Create", stringify!($entity_name)),
+                                                stringify!($entity_name),
                                                 self.1,
                                                 executor,
-                                                arguments,
-                                                concat!("New", stringify!($entity_name))
+                                                self.2,
+                                                |selection, executor| $crate::query_builder::mutations::handle_insert::<
+                                                    DB,
+                                                    $insert,
+                                                    $entity_name,
+                                                    Self::Context,
+                                                >(selection, executor, arguments, concat!("New", stringify!($entity_name))),
                                             )
+                                        } else {
+                                            $crate::query_builder::mutations::handle_insert::<
+                                                DB,
+                                                $insert,
+                                                $entity_name,
+                                                Self::Context,
+                                            >(self.1, executor, arguments, concat!("New", stringify!($entity_name)))
+                                        }
                                     }
                                     concat!("Create", stringify!($entity_name), "s") => {
                                         $crate::query_builder::mutations::handle_batch_insert::<
@@ -626,17 +817,33 @@ macro_rules! mutation_object {
                                 $(
                                     $(
                                         concat!("Update", stringify!($entity_name)) => {
-                                            $crate::query_builder::mutations::handle_update::<
-                                                DB,
-                                            $update,
-                                            $entity_name,
-                                            Self::Context
+                                            if payload {
+                                                $crate::query_builder::mutations::resolve_payload::<
+                                                    $update,
+                                                    $crate::graphql_type::GraphqlWrapper<$entity_name, DB, Ctx>,
+                                                    _,
+                                                    _,
                                                 >(
+                                                    concat!("Update", stringify!($entity_name)),
+                                                    stringify!($entity_name),
                                                     self.1,
                                                     executor,
-                                                    arguments,
-                                                    concat!("Update", stringify!($entity_name))
+                                                    self.2,
+                                                    |selection, executor| $crate::query_builder::mutations::handle_update::<
+                                                        DB,
+                                                        $update,
+                                                        $entity_name,
+                                                        Self::Context,
+                                                    >(selection, executor, arguments, concat!("Update", stringify!($entity_name))),
                                                 )
+                                            } else {
+                                                $crate::query_builder::mutations::handle_update::<
+                                                    DB,
+                                                    $update,
+                                                    $entity_name,
+                                                    Self::Context,
+                                                >(self.1, executor, arguments, concat!("Update", stringify!($entity_name)))
+                                            }
                                         }
                                     )*
                                 )*
@@ -113,17 +113,17 @@ macro_rules! __expand_filter {
     };
     (
         $registry: ident, $entity: ident, $conn: ty, $graphql_struct: ident,
-        meta = [#[wundegraph(filter = true $($stuff:tt)*)], $($rest:tt)*]
+        meta = [#[wundergraph(filter = true $($stuff:tt)*)], $($rest:tt)*]
     ) => {
         $crate::__expand_filter!($registry, $entity, $conn, $graphql_struct, meta = [])
     };
     (
         $registry: ident, $entity: ident, $conn: ty, $graphql_struct: ident,
-        meta = [#[wundegraph(filter = false $($stuff:tt)*)], $($rest:tt)*]
+        meta = [#[wundergraph(filter = false $($stuff:tt)*)], $($rest:tt)*]
     ) => {};
     (
         $registry: ident, $entity: ident, $conn: ty, $graphql_struct: ident,
-        meta = [#[wundegraph($stuff:tt $($other_stuff:tt)*)], $($rest:tt)*]
+        meta = [#[wundergraph($stuff:tt $($other_stuff:tt)*)], $($rest:tt)*]
     ) => {
         $crate::__expand_filter!(
             $registry, $entity, $conn, $graphql_struct,
@@ -198,6 +198,290 @@ macro_rules! __expand_additional_arg {
     };
 }
 
+#[cfg(feature = "relay")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_node_fields {
+    ($registry: ident, $fields: ident, $info: ident) => {
+        let arg = $registry.arg::<$crate::juniper::ID>("id", $info);
+        $fields.push(
+            $registry
+                .field::<Option<$crate::relay::Node>>("node", $info)
+                .argument(arg),
+        );
+        let arg = $registry.arg::<Vec<$crate::juniper::ID>>("ids", $info);
+        $fields.push(
+            $registry
+                .field::<Vec<Option<$crate::relay::Node>>>("nodes", $info)
+                .argument(arg),
+        );
+    };
+}
+
+#[cfg(not(feature = "relay"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_node_fields {
+    ($registry: ident, $fields: ident, $info: ident) => {};
+}
+
+#[cfg(feature = "relay")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __resolve_node_fields {
+    (
+        $field_name: ident,
+        $executor: ident,
+        $selection: expr,
+        structs = [$($graphql_struct: ident,)*]
+    ) => {
+        if $field_name == "node" || $field_name == "nodes" {
+            use $crate::juniper::LookAheadMethods;
+            use $crate::query_builder::selection::LoadingHandler;
+
+            let look_ahead = $executor.look_ahead();
+            let ids = if $field_name == "node" {
+                vec![look_ahead
+                    .argument("id")
+                    .and_then(|id| $crate::relay::global_id_from_look_ahead(id.value()))]
+            } else {
+                match look_ahead.argument("ids").map(|ids| ids.value()) {
+                    Some($crate::juniper::LookAheadValue::List(ids)) => ids
+                        .iter()
+                        .map($crate::relay::global_id_from_look_ahead)
+                        .collect(),
+                    _ => Vec::new(),
+                }
+            };
+            let ids = ids
+                .into_iter()
+                .map(|id| id.and_then($crate::relay::decode_global_id))
+                .collect::<Vec<_>>();
+            let mut items = vec![$crate::juniper::Value::Null; ids.len()];
+            $(
+                // Group all ids of the current type, to load them
+                // using a single query
+                let (positions, keys): (Vec<_>, Vec<_>) = ids
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, id)| {
+                        let (type_name, key) = id.as_ref()?;
+                        if type_name != <$graphql_struct as LoadingHandler<DB, Ctx>>::TYPE_NAME {
+                            return None;
+                        }
+                        <
+                            <<$graphql_struct as $crate::diesel::associations::HasTable>::Table as $crate::diesel::Table>::PrimaryKey
+                            as $crate::helper::PrimaryKeyInputObject<
+                                <<&'static $graphql_struct as $crate::diesel::Identifiable>::Id as $crate::helper::UnRef<'static>>::UnRefed,
+                                ()
+                            >
+                        >::from_input_value(key).map(|key| (idx, key))
+                    })
+                    .unzip();
+                if !keys.is_empty() {
+                    // The executor of `node` refers to the `Node` interface,
+                    // fields are resolved using the concrete entity type
+                    let executor = $executor.type_sub_executor(
+                        Some(<$graphql_struct as LoadingHandler<DB, Ctx>>::TYPE_NAME),
+                        $selection,
+                    );
+                    let loaded = $crate::error::resolve_with($executor.context(), || {
+                        let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                        $graphql_struct::load_by_primary_key_list(keys, &look_ahead, $selection, &executor, q)
+                    })?;
+                    for (idx, item) in positions.into_iter().zip(loaded) {
+                        items[idx] = item;
+                    }
+                }
+            )*
+            return if $field_name == "node" {
+                Ok(items.pop().unwrap_or($crate::juniper::Value::Null))
+            } else {
+                Ok($crate::juniper::Value::List(items))
+            };
+        }
+    };
+}
+
+#[cfg(not(feature = "relay"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __resolve_node_fields {
+    (
+        $field_name: ident,
+        $executor: ident,
+        $selection: expr,
+        structs = [$($graphql_struct: ident,)*]
+    ) => {};
+}
+
+#[cfg(feature = "federation")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_federation_fields {
+    (
+        $registry: ident,
+        $fields: ident,
+        $info: ident,
+        structs = [$($graphql_struct: ident,)*]
+    ) => {
+        let mut key_names = Vec::new();
+        $(
+            for (name, _) in $crate::__federation_key_fields!($graphql_struct) {
+                if !key_names.contains(&name) {
+                    key_names.push(name);
+                }
+            }
+        )*
+        let entity_types = vec![
+            $(<$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::TYPE_NAME,)*
+        ];
+        let arg = $registry.arg::<Vec<$crate::federation::Any>>("representations", &key_names);
+        $fields.push(
+            $registry
+                .field::<Vec<Option<$crate::federation::Entity>>>("_entities", &entity_types)
+                .argument(arg),
+        );
+        $fields.push($registry.field::<$crate::federation::Service>("_service", $info));
+    };
+}
+
+#[cfg(feature = "federation")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __federation_key_fields {
+    ($graphql_struct: ident) => {
+        $crate::federation::key_fields(
+            <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FIELD_NAMES,
+            <
+                <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::PrimaryKeyIndex
+                as $crate::helper::IsPrimaryKeyIndex
+            >::indices(),
+            <
+                <<$graphql_struct as $crate::diesel::associations::HasTable>::Table as $crate::diesel::Table>::PrimaryKey
+                as $crate::helper::PrimaryKeyInputObject<
+                    <<&'static $graphql_struct as $crate::diesel::Identifiable>::Id as $crate::helper::UnRef<'static>>::UnRefed,
+                    ()
+                >
+            >::key_names(),
+        )
+    };
+}
+
+#[cfg(not(feature = "federation"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_federation_fields {
+    (
+        $registry: ident,
+        $fields: ident,
+        $info: ident,
+        structs = [$($graphql_struct: ident,)*]
+    ) => {};
+}
+
+#[cfg(feature = "federation")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __resolve_federation_fields {
+    (
+        $field_name: ident,
+        $executor: ident,
+        $selection: expr,
+        $arguments: ident,
+        structs = [$($graphql_struct: ident,)*]
+    ) => {
+        if $field_name == "_service" {
+            use $crate::query_builder::selection::LoadingHandler;
+
+            let entities = vec![
+                $(
+                    (
+                        <$graphql_struct as LoadingHandler<DB, Ctx>>::TYPE_NAME,
+                        $crate::__federation_key_fields!($graphql_struct)
+                            .into_iter()
+                            .map(|(name, _)| name)
+                            .collect::<Vec<_>>(),
+                    ),
+                )*
+            ];
+            let schema = $executor.schema();
+            let sdl = $crate::federation::print_schema(
+                schema.concrete_type_list(),
+                schema.concrete_query_type(),
+                schema.concrete_mutation_type(),
+                &entities,
+            );
+            return Ok($crate::federation::service_value(sdl, &$executor.look_ahead(), $selection));
+        }
+        if $field_name == "_entities" {
+            use $crate::query_builder::selection::LoadingHandler;
+
+            let look_ahead = $executor.look_ahead();
+            let representations = $arguments
+                .get::<Vec<$crate::federation::Any>>("representations")
+                .unwrap_or_default();
+            let mut entities = vec![$crate::juniper::Value::Null; representations.len()];
+            $(
+                // Group all representations of the current type, to
+                // load them using a single query
+                let key_fields = $crate::__federation_key_fields!($graphql_struct);
+                let (positions, keys): (Vec<_>, Vec<_>) = representations
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, r)| r.type_name() == Some(<$graphql_struct as LoadingHandler<DB, Ctx>>::TYPE_NAME))
+                    .filter_map(|(idx, r)| {
+                        <
+                            <<$graphql_struct as $crate::diesel::associations::HasTable>::Table as $crate::diesel::Table>::PrimaryKey
+                            as $crate::helper::PrimaryKeyInputObject<
+                                <<&'static $graphql_struct as $crate::diesel::Identifiable>::Id as $crate::helper::UnRef<'static>>::UnRefed,
+                                ()
+                            >
+                        >::from_input_value(&r.primary_key(&key_fields)).map(|key| (idx, key))
+                    })
+                    .unzip();
+                if !keys.is_empty() {
+                    // The executor of `_entities` refers to the `_Entity` union,
+                    // fields are resolved using the concrete entity type
+                    let executor = $executor.type_sub_executor(
+                        Some(<$graphql_struct as LoadingHandler<DB, Ctx>>::TYPE_NAME),
+                        $selection,
+                    );
+                    let mut items = $crate::error::resolve_with($executor.context(), || {
+                        let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                        $graphql_struct::load_by_primary_key_list(keys, &look_ahead, $selection, &executor, q)
+                    })?;
+                    $crate::federation::add_type_names(
+                        &mut items,
+                        <$graphql_struct as LoadingHandler<DB, Ctx>>::TYPE_NAME,
+                        &look_ahead,
+                        $selection,
+                    );
+                    for (idx, item) in positions.into_iter().zip(items) {
+                        entities[idx] = item;
+                    }
+                }
+            )*
+            return Ok($crate::juniper::Value::List(entities));
+        }
+    };
+}
+
+#[cfg(not(feature = "federation"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __resolve_federation_fields {
+    (
+        $field_name: ident,
+        $executor: ident,
+        $selection: expr,
+        $arguments: ident,
+        structs = [$($graphql_struct: ident,)*]
+    ) => {
+        let _ = $arguments;
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_graphql_obj_for_query {
@@ -215,7 +499,7 @@ macro_rules! __impl_graphql_obj_for_query {
             where Ctx: $crate::WundergraphContext,
                   DB: $crate::diesel::backend::Backend + $crate::query_builder::selection::offset::ApplyOffset + 'static,
                   DB::QueryBuilder: std::default::Default,
-                  Ctx::Connection: $crate::diesel::Connection<Backend = DB>,
+                  Ctx::Connection: $crate::diesel::Connection<Backend = DB> + $crate::transaction::ReadSnapshot,
             $([<$graphql_struct _table>]: $crate::diesel::Table + $crate::diesel::query_dsl::methods::BoxedDsl<
               'static,
               DB,
@@ -247,6 +531,7 @@ macro_rules! __impl_graphql_obj_for_query {
                   <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::PrimaryKeyIndex,
                   [<$graphql_struct _table>],
                   Ctx,
+                  PrimaryKeyValue = <[<$graphql_struct _id>] as $crate::helper::UnRef<'static>>::UnRefed,
                   >,)*
                 $(<$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FieldList:
                   $crate::graphql_type::WundergraphGraphqlHelper<$graphql_struct, DB, Ctx> +
@@ -261,6 +546,7 @@ macro_rules! __impl_graphql_obj_for_query {
                   >>,)*
                 $(&'static $graphql_struct: $crate::diesel::Identifiable<Id = [<$graphql_struct _id>]>,)*
                 $([<$graphql_struct _id>]: std::hash::Hash + std::cmp::Eq + $crate::helper::UnRef<'static>,)*
+                $(<[<$graphql_struct _id>] as $crate::helper::UnRef<'static>>::UnRefed: std::clone::Clone + std::hash::Hash + std::cmp::Eq,)*
                 $([<$graphql_struct _table>]::PrimaryKey: std::default::Default + $crate::helper::PrimaryKeyInputObject<
                   <[<$graphql_struct _id>] as $crate::helper::UnRef<'static>>::UnRefed, ()
                   >,)*
@@ -281,6 +567,11 @@ macro_rules! __impl_graphql_obj_for_query {
 
 /// Macro to register the main query object
 ///
+/// Entities registered here only need to support loading data. Registering
+/// mutations for them via `mutation_object!` is optional, so read only
+/// sources like database views, declared using `table!` with a key
+/// column, could be exposed as well.
+///
 /// # Annotated example
 /// ```
 /// ##[macro_use]
@@ -373,6 +664,27 @@ macro_rules! __impl_graphql_obj_for_query {
 ///         // Default values for all options are true. As shown below it is
 ///         // possible to have multiple flags in one attribute.
 ///         //
+///         // Besides the list field and the single entity field, taking a
+///         // primary key, a field named `{Name}ByIds` is generated. It loads
+///         // a list of entities by their primary keys, preserving the order
+///         // of the given keys and returning `null` for missing entities.
+///         // Each id of the `ids: [ID!]!` argument is the value of the
+///         // primary key, so this field is only generated for entities
+///         // with a single column primary key.
+///         //
+///         // If the `relay` feature is enabled a `node` and a `nodes` field
+///         // are generated as well, loading any registered entity by its
+///         // global id. See the `relay` module for details.
+///         //
+///         // If the `federation` feature is enabled the `_service` and
+///         // `_entities` fields required by Apollo Federation are generated,
+///         // using the primary keys of all registered entities as `@key`.
+///         // See the `federation` module for details.
+///         //
+///         // For each unique key of an entity (see the `unique` option of
+///         // `#[derive(WundergraphEntity)]`) an additional field named
+///         // `{Entity}By{Key}` is generated, returning `null` if nothing matches.
+///         //
 ///         #[wundergraph(filter = false)]
 ///         #[wundergraph(offset = true, order = false, limit = false,)]
 ///         Species,
@@ -451,7 +763,9 @@ macro_rules! query_object {
                             field_name,
                             arguments,
                         );
-                        executor.resolve(info, &wrapper)
+                        $crate::transaction::resolve_in_snapshot(executor.context(), || {
+                            executor.resolve(info, &wrapper)
+                        })
                     }
                 }
             }
@@ -545,7 +859,7 @@ macro_rules! query_object {
                     ) -> $crate::juniper::meta::MetaType<'r, $crate::scalar::WundergraphScalarValue>
                     where $crate::scalar::WundergraphScalarValue: 'r
                     {
-                        let fields = &[
+                        let mut fields = vec![
                             $(
                                 {
                                     let mut field = registry.field::<Vec<$crate::graphql_type::GraphqlWrapper<
@@ -594,12 +908,52 @@ macro_rules! query_object {
                                         stringify!($graphql_struct),
                                         info
                                     ).argument(key)
-                                }
-                                ,
+                                },
 
                             )*
                         ];
-                        let mut obj = registry.build_object_type::<Self>(info, fields);
+                        $(
+                            // Ids could only be mapped onto single column primary keys
+                            let key_names = <
+                                <<$graphql_struct as $crate::diesel::associations::HasTable>::Table as $crate::diesel::Table>::PrimaryKey
+                                as $crate::helper::PrimaryKeyInputObject<
+                                    <<&'static $graphql_struct as $crate::diesel::Identifiable>::Id as $crate::helper::UnRef<'static>>::UnRefed,
+                                    ()
+                                >
+                            >::key_names();
+                            if key_names.len() == 1 {
+                                let ids = registry.arg::<Vec<$crate::juniper::ID>>("ids", &());
+                                fields.push(registry.field::<Vec<Option<$crate::graphql_type::GraphqlWrapper<$graphql_struct, DB, Ctx>>>>(
+                                    concat!($crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*), "ByIds"),
+                                    info
+                                ).argument(ids));
+                            }
+                            for key in <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::UNIQUE_KEYS {
+                                let mut field = registry.field::<Option<$crate::graphql_type::GraphqlWrapper<$graphql_struct, DB, Ctx>>>(
+                                    key.field_name,
+                                    info
+                                );
+                                for idx in key.fields {
+                                    let arg = <<$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FieldList as $crate::graphql_type::WundergraphGraphqlHelper<$graphql_struct, DB, Ctx>>::field_argument(
+                                        *idx,
+                                        <$graphql_struct as $crate::query_builder::selection::LoadingHandler<DB, Ctx>>::FIELD_NAMES[*idx],
+                                        registry
+                                    );
+                                    if let Some(arg) = arg {
+                                        field = field.argument(arg);
+                                    }
+                                }
+                                fields.push(field);
+                            }
+                        )*
+                        $crate::__expand_node_fields!(registry, fields, info);
+                        $crate::__expand_federation_fields!(
+                            registry,
+                            fields,
+                            info,
+                            structs = [$($graphql_struct,)*]
+                        );
+                        let mut obj = registry.build_object_type::<Self>(info, &fields);
                         obj = obj.description(concat!($($glob_doc, "\n", )* ""));
                         obj.into_meta()
                     }
@@ -608,7 +962,7 @@ macro_rules! query_object {
                         &self,
                         _info: &Self::TypeInfo,
                         field_name: &str,
-                        _arguments: &$crate::juniper::Arguments<$crate::scalar::WundergraphScalarValue>,
+                        arguments: &$crate::juniper::Arguments<$crate::scalar::WundergraphScalarValue>,
                         executor: &$crate::juniper::Executor<Self::Context, $crate::scalar::WundergraphScalarValue>,
                     ) -> $crate::juniper::ExecutionResult<$crate::scalar::WundergraphScalarValue> {
                         use $crate::query_builder::selection::LoadingHandler;
@@ -617,22 +971,59 @@ macro_rules! query_object {
                         match field_name {
                             $(
                                 $crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*) => {
-                                    let look_ahead = executor.look_ahead();
-                                    let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
-                                    let items = $graphql_struct::load(&look_ahead, self.1, executor, q)?;
-                                    Ok($crate::juniper::Value::List(items))
+                                    $crate::error::resolve_with(executor.context(), || {
+                                        let look_ahead = executor.look_ahead();
+                                        let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                                        let items = $graphql_struct::load(&look_ahead, self.1, executor, q)?;
+                                        Ok($crate::juniper::Value::List(items))
+                                    })
                                 },
                                 stringify!($graphql_struct) => {
-                                    let look_ahead = executor.look_ahead();
-                                    let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
-                                    let item = $graphql_struct::load_by_primary_key(&look_ahead, self.1, executor, q)?;
-                                    Ok(item.unwrap_or($crate::juniper::Value::Null))
+                                    $crate::error::resolve_with(executor.context(), || {
+                                        let look_ahead = executor.look_ahead();
+                                        let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                                        let item = $graphql_struct::load_by_primary_key(&look_ahead, self.1, executor, q)?;
+                                        Ok(item.unwrap_or($crate::juniper::Value::Null))
+                                    })
+                                },
+                                concat!($crate::__expand_name!($graphql_struct, $(#[$($meta)*],)*), "ByIds") => {
+                                    $crate::error::resolve_with(executor.context(), || {
+                                        let look_ahead = executor.look_ahead();
+                                        let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                                        let items = $graphql_struct::load_by_primary_keys(&look_ahead, self.1, executor, q)?;
+                                        Ok($crate::juniper::Value::List(items))
+                                    })
                                 }
                             )*
-                                e => Err($crate::juniper::FieldError::new(
-                                    "Unknown field:",
-                                    $crate::juniper::Value::scalar(e),
-                                )),
+                                e => {
+                                    $crate::__resolve_node_fields!(
+                                        e,
+                                        executor,
+                                        self.1,
+                                        structs = [$($graphql_struct,)*]
+                                    );
+                                    $crate::__resolve_federation_fields!(
+                                        e,
+                                        executor,
+                                        self.1,
+                                        arguments,
+                                        structs = [$($graphql_struct,)*]
+                                    );
+                                    $(
+                                        if let Some(key) = <$graphql_struct as LoadingHandler<DB, Ctx>>::unique_key(e) {
+                                            return $crate::error::resolve_with(executor.context(), || {
+                                                let look_ahead = executor.look_ahead();
+                                                let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                                                let item = $graphql_struct::load_by_unique_key(key, &look_ahead, self.1, executor, q)?;
+                                                Ok(item.unwrap_or($crate::juniper::Value::Null))
+                                            });
+                                        }
+                                    )*
+                                    Err($crate::juniper::FieldError::new(
+                                        "Unknown field:",
+                                        $crate::juniper::Value::scalar(e),
+                                    ))
+                                }
                         }
                     }
 
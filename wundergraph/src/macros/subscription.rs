@@ -0,0 +1,222 @@
+/// Macro to register the subscription object
+///
+/// The generated object contains a field named `{Entity}Changed` for each
+/// registered entity. Each field returns the changed row of the current
+/// change event, reloaded through `LoadingHandler`, or `null` if the row
+/// does not match the given filter anymore. See the `subscription` module
+/// for how to execute subscription documents.
+///
+/// The generated type is meant to be used as query root of a
+/// [`SubscriptionSchema`](subscription/type.SubscriptionSchema.html).
+///
+/// ```
+/// ##[macro_use]
+/// # extern crate diesel;
+/// # use wundergraph::WundergraphEntity;
+/// #
+/// # table! {
+/// #     heros {
+/// #          id -> Integer,
+/// #          name -> Text,
+/// #     }
+/// # }
+/// #
+/// #[derive(WundergraphEntity, Identifiable)]
+/// #[table_name = "heros"]
+/// pub struct Hero {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// wundergraph::subscription_object! {
+///     /// An optional doc comment describing the subscription object
+///     Subscription {
+///         // Register a wundergraph GraphQL entity
+///         //
+///         // Documentation comments and deprecation attributes are
+///         // handled the same way as for `query_object!`. The filter
+///         // argument could be disabled using `#[wundergraph(filter = false)]`
+///         /// GraphQL description for the hero changed field
+///         Hero,
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! subscription_object {
+    (
+        $(#[doc = $glob_doc: expr])*
+        $subscription_name: ident {
+            $(
+                $(#[$($meta: tt)*])*
+                $graphql_struct: ident$(,)?
+            )*
+        }
+    ) => {
+        #[derive(Debug)]
+        $(#[doc = $glob_doc])*
+        // Use Arc<Mutex<C>> here to force make this Sync
+        pub struct $subscription_name<C>(::std::marker::PhantomData<std::sync::Arc<std::sync::Mutex<C>>>);
+
+        impl<C> Default for $subscription_name<C> {
+            fn default() -> Self {
+                $subscription_name(::std::marker::PhantomData)
+            }
+        }
+
+        $crate::paste::item!{
+            $crate::__impl_graphql_obj_for_query! {
+                query_name = {$subscription_name},
+                structs = [$($graphql_struct,)*],
+                body = {
+                    type Context = $crate::subscription::SubscriptionContext<Ctx>;
+                    type TypeInfo = ();
+
+                    fn name(_info: &Self::TypeInfo) -> ::std::option::Option<&str> {
+                        Some(stringify!($subscription_name))
+                    }
+
+                    #[allow(non_snake_case)]
+                    fn meta<'r>(
+                        info: &Self::TypeInfo,
+                        registry: &mut $crate::juniper::Registry<'r, $crate::scalar::WundergraphScalarValue>
+                    ) -> $crate::juniper::meta::MetaType<'r, $crate::scalar::WundergraphScalarValue>
+                    where $crate::scalar::WundergraphScalarValue: 'r
+                    {
+                        let fields = vec![
+                            $(
+                                {
+                                    let mut field = registry.field::<Option<$crate::graphql_type::GraphqlWrapper<
+                                        $graphql_struct,
+                                    <<Ctx as $crate::WundergraphContext>::Connection as $crate::diesel::Connection>::Backend, Ctx>
+                                        >>(
+                                            concat!(stringify!($graphql_struct), "Changed"),
+                                            info
+                                        );
+                                    $crate::__expand_meta!(field, $(#[$($meta)*],)*);
+                                    $crate::__expand_filter!(
+                                        registry,
+                                        field,
+                                        <Ctx as $crate::WundergraphContext>::Connection,
+                                        $graphql_struct,
+                                        meta = [$(#[$($meta)*],)*]
+                                    );
+                                    field
+                                },
+                            )*
+                        ];
+                        let mut obj = registry.build_object_type::<Self>(info, &fields);
+                        obj = obj.description(concat!($($glob_doc, "\n", )* ""));
+                        obj.into_meta()
+                    }
+
+                    fn resolve_field(
+                        &self,
+                        info: &Self::TypeInfo,
+                        field_name: &str,
+                        _arguments: &$crate::juniper::Arguments<$crate::scalar::WundergraphScalarValue>,
+                        executor: &$crate::juniper::Executor<Self::Context, $crate::scalar::WundergraphScalarValue>,
+                    ) -> $crate::juniper::ExecutionResult<$crate::scalar::WundergraphScalarValue> {
+                        let wrapper = [<$subscription_name _wrapper>](
+                            ::std::marker::PhantomData,
+                            field_name,
+                        );
+                        executor.resolve(info, &wrapper)
+                    }
+                }
+            }
+
+            #[derive(Debug)]
+            #[doc(hidden)]
+            /// An internal helper type
+            pub struct [<$subscription_name _wrapper>]<'a, C>(
+                // Use Arc<Mutex<C>> here to force make this Sync
+                ::std::marker::PhantomData<std::sync::Arc<std::sync::Mutex<C>>>,
+                &'a str,
+            );
+
+            $crate::__impl_graphql_obj_for_query! {
+                query_name = {[<$subscription_name _wrapper>]},
+                structs = [$($graphql_struct,)*],
+                lt = 'a,
+                body = {
+                    type Context = $crate::subscription::SubscriptionContext<Ctx>;
+                    type TypeInfo = ();
+
+                    fn name(info: &Self::TypeInfo) -> ::std::option::Option<&str> {
+                        <$subscription_name<Ctx> as $crate::juniper::GraphQLType<$crate::scalar::WundergraphScalarValue>>::name(info)
+                    }
+
+                    fn meta<'r>(
+                        info: &Self::TypeInfo,
+                        registry: &mut $crate::juniper::Registry<'r, $crate::scalar::WundergraphScalarValue>
+                    ) -> $crate::juniper::meta::MetaType<'r, $crate::scalar::WundergraphScalarValue>
+                    where
+                        $crate::scalar::WundergraphScalarValue: 'r
+                    {
+                        <$subscription_name<Ctx> as $crate::juniper::GraphQLType<$crate::scalar::WundergraphScalarValue>>::meta(info, registry)
+                    }
+
+                    fn resolve(
+                        &self,
+                        _info: &Self::TypeInfo,
+                        selection_set: ::std::option::Option<&[$crate::juniper::Selection<$crate::scalar::WundergraphScalarValue>]>,
+                        executor: &$crate::juniper::Executor<Self::Context, $crate::scalar::WundergraphScalarValue>,
+                    ) -> $crate::juniper::Value<$crate::scalar::WundergraphScalarValue> {
+                        use $crate::diesel::associations::HasTable;
+                        use $crate::diesel::QuerySource;
+                        use $crate::helper::NamedTable;
+                        use $crate::juniper::LookAheadMethods;
+                        use $crate::query_builder::selection::LoadingHandler;
+
+                        let subscription = executor.context();
+                        let r = (|| -> $crate::juniper::ExecutionResult<$crate::scalar::WundergraphScalarValue> {
+                            $(
+                                if self.1 == concat!(stringify!($graphql_struct), "Changed") {
+                                    // Events for other tables are not relevant
+                                    // for the current field
+                                    if subscription.event().table != <$graphql_struct as HasTable>::table().from_clause().name() {
+                                        return Ok($crate::juniper::Value::Null);
+                                    }
+                                    subscription.mark_matched();
+                                    return $crate::error::resolve_with(subscription.context(), || {
+                                        let key = <
+                                            <<$graphql_struct as HasTable>::Table as $crate::diesel::Table>::PrimaryKey
+                                            as $crate::helper::PrimaryKeyInputObject<
+                                                <<&'static $graphql_struct as $crate::diesel::Identifiable>::Id as $crate::helper::UnRef<'static>>::UnRefed,
+                                                ()
+                                            >
+                                        >::from_input_value(&subscription.event().key)
+                                            .ok_or($crate::error::WundergraphError::NoPrimaryKeyArgumentFound)?;
+                                        let executor = executor.replaced_context(subscription.context());
+                                        let look_ahead = executor.look_ahead();
+                                        let q = $graphql_struct::build_query(look_ahead.arguments(), &look_ahead)?;
+                                        let mut items = $graphql_struct::load_by_primary_key_list(
+                                            vec![key],
+                                            &look_ahead,
+                                            selection_set,
+                                            &executor,
+                                            q,
+                                        )?;
+                                        Ok(items.pop().unwrap_or($crate::juniper::Value::Null))
+                                    });
+                                }
+                            )*
+                            Err($crate::juniper::FieldError::new(
+                                "Unknown field:",
+                                $crate::juniper::Value::scalar(self.1),
+                            ))
+                        })();
+                        match r {
+                            ::std::result::Result::Ok(v) => v,
+                            ::std::result::Result::Err(e) => {
+                                executor.push_error(e);
+                                $crate::juniper::Value::null()
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
@@ -1,4 +1,5 @@
 use crate::context::WundergraphContext;
+use crate::error::resolve_with;
 use crate::query_builder::selection::fields::WundergraphFieldList;
 use crate::query_builder::selection::offset::ApplyOffset;
 use crate::query_builder::selection::order::BuildOrder;
@@ -98,22 +99,19 @@ where
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
         let conn = ctx.get_connection();
-        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
-            // this is safe becuse we do not leak to_delete out of this function
-            let static_to_delete: &'static K = unsafe { &*(to_delete as *const K) };
-            let filter = T::PrimaryKey::default().eq_all(static_to_delete.id());
-            let d = ::diesel::delete(FilterDsl::filter(Self::table(), filter));
-            #[cfg(feature = "debug")]
-            {
-                log::debug!("{}", ::diesel::debug_query(&d));
-            }
-
-            executor.resolve_with_ctx(
-                &(),
-                &DeletedCount {
-                    count: d.execute(conn)? as _,
-                },
-            )
-        })
+        let count = resolve_with(ctx, || {
+            conn.transaction(|| {
+                // this is safe becuse we do not leak to_delete out of this function
+                let static_to_delete: &'static K = unsafe { &*(to_delete as *const K) };
+                let filter = T::PrimaryKey::default().eq_all(static_to_delete.id());
+                let d = ::diesel::delete(FilterDsl::filter(Self::table(), filter));
+                #[cfg(feature = "debug")]
+                {
+                    log::debug!("{}", ::diesel::debug_query(&d));
+                }
+                Ok(d.execute(conn)?)
+            })
+        })?;
+        executor.resolve_with_ctx(&(), &DeletedCount { count: count as _ })
     }
 }
@@ -1,5 +1,6 @@
 use super::{HandleBatchInsert, HandleInsert};
 use crate::context::WundergraphContext;
+use crate::error::resolve_with;
 use crate::helper::UnRef;
 use crate::query_builder::selection::fields::WundergraphFieldList;
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
@@ -55,20 +56,22 @@ where
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
         let conn = ctx.get_connection();
-        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
-            let look_ahead = executor.look_ahead();
-            let inserted = insertable
-                .insert_into(Self::table())
-                .returning(T::PrimaryKey::default());
-            #[cfg(feature = "debug")]
-            {
-                log::debug!("{}", ::diesel::debug_query(&inserted));
-            }
-            let inserted: Id = inserted.get_result(conn)?;
-            let q = L::build_query(&[], &look_ahead)?;
-            let q = FilterDsl::filter(q, T::PrimaryKey::default().eq_all(inserted));
-            let items = L::load(&look_ahead, selection, executor, q)?;
-            Ok(items.into_iter().next().unwrap_or(Value::Null))
+        resolve_with(ctx, || {
+            conn.transaction(|| {
+                let look_ahead = executor.look_ahead();
+                let inserted = insertable
+                    .insert_into(Self::table())
+                    .returning(T::PrimaryKey::default());
+                #[cfg(feature = "debug")]
+                {
+                    log::debug!("{}", ::diesel::debug_query(&inserted));
+                }
+                let inserted: Id = inserted.get_result(conn)?;
+                let q = L::build_query(&[], &look_ahead)?;
+                let q = FilterDsl::filter(q, T::PrimaryKey::default().eq_all(inserted));
+                let items = L::load(&look_ahead, selection, executor, q)?;
+                Ok(items.into_iter().next().unwrap_or(Value::Null))
+            })
         })
     }
 }
@@ -108,22 +111,24 @@ where
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
         let conn = ctx.get_connection();
-        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
-            let look_ahead = executor.look_ahead();
-            let inserted = batch
-                .insert_into(Self::table())
-                .returning(T::PrimaryKey::default());
-            #[cfg(feature = "debug")]
-            {
-                log::debug!("{}", ::diesel::debug_query(&inserted));
-            }
-            let inserted: Vec<Id> = inserted.get_results(conn)?;
-            let mut q = L::build_query(&[], &look_ahead)?;
-            for i in inserted {
-                q = OrFilterDsl::or_filter(q, T::PrimaryKey::default().eq_all(i));
-            }
-            let items = L::load(&look_ahead, selection, executor, q)?;
-            Ok(Value::list(items))
+        resolve_with(ctx, || {
+            conn.transaction(|| {
+                let look_ahead = executor.look_ahead();
+                let inserted = batch
+                    .insert_into(Self::table())
+                    .returning(T::PrimaryKey::default());
+                #[cfg(feature = "debug")]
+                {
+                    log::debug!("{}", ::diesel::debug_query(&inserted));
+                }
+                let inserted: Vec<Id> = inserted.get_results(conn)?;
+                let mut q = L::build_query(&[], &look_ahead)?;
+                for i in inserted {
+                    q = OrFilterDsl::or_filter(q, T::PrimaryKey::default().eq_all(i));
+                }
+                let items = L::load(&look_ahead, selection, executor, q)?;
+                Ok(Value::list(items))
+            })
         })
     }
 }
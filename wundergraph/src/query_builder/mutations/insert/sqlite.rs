@@ -1,5 +1,6 @@
 use super::{HandleBatchInsert, HandleInsert};
 use crate::context::WundergraphContext;
+use crate::error::resolve_with;
 use crate::query_builder::selection::fields::WundergraphFieldList;
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::order::BuildOrder;
@@ -49,14 +50,17 @@ where
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
         let conn = ctx.get_connection();
-        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
-            let look_ahead = executor.look_ahead();
-            insertable.insert_into(T::table()).execute(conn)?;
-            let q = OrderDsl::order(L::build_query(&[], &look_ahead)?, sql::<Bool>("rowid DESC"));
-            let q = LimitDsl::limit(q, 1);
-            let items = L::load(&look_ahead, selection, executor, q)?;
+        resolve_with(ctx, || {
+            conn.transaction(|| {
+                let look_ahead = executor.look_ahead();
+                insertable.insert_into(T::table()).execute(conn)?;
+                let q =
+                    OrderDsl::order(L::build_query(&[], &look_ahead)?, sql::<Bool>("rowid DESC"));
+                let q = LimitDsl::limit(q, 1);
+                let items = L::load(&look_ahead, selection, executor, q)?;
 
-            Ok(items.into_iter().next().unwrap_or(Value::Null))
+                Ok(items.into_iter().next().unwrap_or(Value::Null))
+            })
         })
     }
 }
@@ -93,18 +97,21 @@ where
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
         let conn = ctx.get_connection();
-        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
-            let look_ahead = executor.look_ahead();
-            let n: usize = batch
-                .into_iter()
-                .map(|i| i.insert_into(T::table()).execute(conn))
-                .collect::<Result<Vec<_>, _>>()?
-                .into_iter()
-                .sum();
-            let q = OrderDsl::order(L::build_query(&[], &look_ahead)?, sql::<Bool>("rowid DESC"));
-            let q = LimitDsl::limit(q, n as i64);
-            let items = L::load(&look_ahead, selection, executor, q)?;
-            Ok(Value::list(items.into_iter().rev().collect()))
+        resolve_with(ctx, || {
+            conn.transaction(|| {
+                let look_ahead = executor.look_ahead();
+                let n: usize = batch
+                    .into_iter()
+                    .map(|i| i.insert_into(T::table()).execute(conn))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .sum();
+                let q =
+                    OrderDsl::order(L::build_query(&[], &look_ahead)?, sql::<Bool>("rowid DESC"));
+                let q = LimitDsl::limit(q, n as i64);
+                let items = L::load(&look_ahead, selection, executor, q)?;
+                Ok(Value::list(items.into_iter().rev().collect()))
+            })
         })
     }
 }
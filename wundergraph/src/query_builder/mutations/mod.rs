@@ -66,9 +66,20 @@
 //! }
 //! # fn main() {}
 //! ```
+//!
+//! # Payloads
+//!
+//! By default insert and update mutations return the mutated entity and
+//! report all errors as top level GraphQL errors. If the payload mode of
+//! [`mutation_object!`](../../macro.mutation_object.html) is enabled they
+//! return a [`MutationPayload`](struct.MutationPayload.html) instead,
+//! containing the entity and a list of [`UserError`](struct.UserError.html)s.
+//! Violated database constraints are reported as user errors pointing to the
+//! corresponding input field.
 
 mod delete;
 mod insert;
+mod payload;
 mod update;
 
 #[doc(inline)]
@@ -76,6 +87,8 @@ pub use self::delete::{DeletedCount, HandleDelete};
 #[doc(inline)]
 pub use self::insert::{HandleBatchInsert, HandleInsert};
 #[doc(inline)]
+pub use self::payload::{MutationPayload, PayloadInfo, UserError};
+#[doc(inline)]
 pub use self::update::HandleUpdate;
 
 #[doc(hidden)]
@@ -83,4 +96,6 @@ pub use self::delete::handle_delete;
 #[doc(hidden)]
 pub use self::insert::{handle_batch_insert, handle_insert};
 #[doc(hidden)]
+pub use self::payload::resolve_payload;
+#[doc(hidden)]
 pub use self::update::handle_update;
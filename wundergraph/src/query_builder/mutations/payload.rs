@@ -0,0 +1,245 @@
+use crate::error::ConstraintKind;
+use crate::query_builder::selection::query_resolver::get_sub_field;
+use crate::scalar::WundergraphScalarValue;
+use juniper::meta::{InputObjectMeta, MetaType};
+use juniper::{
+    Arguments, ExecutionResult, Executor, FieldError, FromContext, GraphQLObject, GraphQLType,
+    Registry, Selection, Value,
+};
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+/// An error caused by the input of a mutation
+///
+/// Returned as part of a mutation payload instead of a top level
+/// GraphQL error, so clients are able to show them next to the
+/// corresponding input field.
+#[derive(Debug, Clone, PartialEq, GraphQLObject)]
+#[graphql(scalar = WundergraphScalarValue)]
+pub struct UserError {
+    /// Name of the input field that caused this error, if known
+    pub field: Option<String>,
+    /// A stable, machine readable error code
+    pub code: String,
+    /// A human readable error message
+    pub message: String,
+}
+
+/// Type info of a [`MutationPayload`](struct.MutationPayload.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadInfo {
+    name: String,
+    field: String,
+}
+
+impl PayloadInfo {
+    /// Create the type info for the payload of the given mutation field
+    /// returning the given entity
+    ///
+    /// `CreateHero` and `Hero` result in a payload named
+    /// `CreateHeroPayload` containing the fields `hero` and `errors`
+    pub fn new(mutation: &str, entity: &str) -> Self {
+        let mut chars = entity.chars();
+        let field = chars
+            .next()
+            .map(|c| c.to_lowercase().chain(chars).collect())
+            .unwrap_or_default();
+        Self {
+            name: format!("{}Payload", mutation),
+            field,
+        }
+    }
+}
+
+/// The result of a mutation field in payload mode
+///
+/// Contains the mutated entity and a list of user errors
+#[derive(Debug)]
+pub struct MutationPayload<T> {
+    value: Value<WundergraphScalarValue>,
+    errors: Vec<UserError>,
+    p: PhantomData<T>,
+}
+
+impl<T> GraphQLType<WundergraphScalarValue> for MutationPayload<T>
+where
+    T: GraphQLType<WundergraphScalarValue, TypeInfo = ()>,
+{
+    type Context = T::Context;
+    type TypeInfo = PayloadInfo;
+
+    fn name(info: &Self::TypeInfo) -> Option<&str> {
+        Some(&info.name)
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = [
+            registry
+                .field::<Option<T>>(&info.field, &())
+                .description("The mutated entity, null if the mutation failed"),
+            registry
+                .field::<Vec<UserError>>("errors", &())
+                .description("Errors caused by the mutation input"),
+        ];
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+
+    fn concrete_type_name(&self, _context: &Self::Context, info: &Self::TypeInfo) -> String {
+        info.name.clone()
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field_name: &str,
+        _arguments: &Arguments<'_, WundergraphScalarValue>,
+        executor: &Executor<'_, Self::Context, WundergraphScalarValue>,
+    ) -> ExecutionResult<WundergraphScalarValue> {
+        match field_name {
+            "errors" => executor.resolve_with_ctx(&(), &self.errors),
+            // The entity is already resolved while executing the mutation
+            f if f == info.field => Ok(self.value.clone()),
+            e => Err(FieldError::new("Unknown field:", Value::scalar(e))),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn resolve_payload<I, T, Ctx, F>(
+    mutation: &str,
+    entity: &str,
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    failed: Option<&Cell<bool>>,
+    mutate: F,
+) -> ExecutionResult<WundergraphScalarValue>
+where
+    I: GraphQLType<WundergraphScalarValue, TypeInfo = ()>,
+    T: GraphQLType<WundergraphScalarValue, TypeInfo = ()>,
+    T::Context: FromContext<Ctx>,
+    F: FnOnce(
+        Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> ExecutionResult<WundergraphScalarValue>,
+{
+    let info = PayloadInfo::new(mutation, entity);
+    let (name, alias, location, entity_selection) = get_sub_field(&info.field, selection);
+    let entity_executor = executor.field_sub_executor(alias, name, location, entity_selection);
+    let (value, errors) = match mutate(entity_selection, &entity_executor) {
+        Ok(value) => (value, Vec::new()),
+        Err(e) => (Value::null(), user_errors::<I, _>(e, executor)?),
+    };
+    if !errors.is_empty() {
+        if let Some(failed) = failed {
+            failed.set(true);
+        }
+    }
+    let payload = MutationPayload::<T> {
+        value,
+        errors,
+        p: PhantomData,
+    };
+    executor.resolve_with_ctx(&info, &payload)
+}
+
+/// Convert a failed mutation into a list of user errors
+///
+/// Only constraint violations are caused by the input, all other errors
+/// are returned unchanged. The violated columns are taken from the
+/// `columns` extension, containing the columns of the
+/// [`ConstraintViolation`](../../error/struct.ConstraintViolation.html).
+/// Therefore the configured redaction policy also applies here, errors
+/// without known columns are not assigned to an input field.
+fn user_errors<I, Ctx>(
+    error: FieldError<WundergraphScalarValue>,
+    executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+) -> Result<Vec<UserError>, FieldError<WundergraphScalarValue>>
+where
+    I: GraphQLType<WundergraphScalarValue, TypeInfo = ()>,
+{
+    let code = string_extension(&error, "code")
+        .filter(|c| is_violation(c))
+        .map(str::to_owned);
+    let code = match code {
+        Some(code) => code,
+        None => return Err(error),
+    };
+
+    let input_type = I::name(&()).and_then(|n| executor.schema().concrete_type_by_name(n));
+    let input_fields = match input_type {
+        Some(MetaType::InputObject(InputObjectMeta { input_fields, .. })) => input_fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>(),
+        _ => Vec::new(),
+    };
+    let columns = match extension(&error, "columns") {
+        Some(Value::List(columns)) => columns
+            .iter()
+            .filter_map(|c| match c {
+                Value::Scalar(WundergraphScalarValue::String(c)) => Some(c.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+        _ => Vec::new(),
+    };
+    let fields = input_fields
+        .iter()
+        .filter(|f| columns.iter().any(|c| juniper::to_camel_case(c) == **f))
+        .collect::<Vec<_>>();
+
+    let message = error.message();
+    if fields.is_empty() {
+        return Ok(vec![UserError {
+            field: None,
+            code,
+            message: message.to_owned(),
+        }]);
+    }
+    Ok(fields
+        .into_iter()
+        .map(|f| UserError {
+            field: Some((*f).to_owned()),
+            code: code.clone(),
+            message: message.to_owned(),
+        })
+        .collect())
+}
+
+fn extension<'a>(
+    error: &'a FieldError<WundergraphScalarValue>,
+    name: &str,
+) -> Option<&'a Value<WundergraphScalarValue>> {
+    error
+        .extensions()
+        .as_object_value()
+        .and_then(|e| e.get_field_value(name))
+}
+
+fn string_extension<'a>(
+    error: &'a FieldError<WundergraphScalarValue>,
+    name: &str,
+) -> Option<&'a str> {
+    match extension(error, name) {
+        Some(Value::Scalar(WundergraphScalarValue::String(s))) => Some(s),
+        _ => None,
+    }
+}
+
+fn is_violation(code: &str) -> bool {
+    [
+        ConstraintKind::Unique,
+        ConstraintKind::ForeignKey,
+        ConstraintKind::Other,
+    ]
+    .iter()
+    .any(|k| k.code() == code)
+}
@@ -1,4 +1,5 @@
 use crate::context::WundergraphContext;
+use crate::error::resolve_with;
 use crate::query_builder::selection::fields::WundergraphFieldList;
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::offset::ApplyOffset;
@@ -106,26 +107,28 @@ where
     ) -> ExecutionResult<WundergraphScalarValue> {
         let ctx = executor.context();
         let conn = ctx.get_connection();
-        conn.transaction(|| -> ExecutionResult<WundergraphScalarValue> {
-            let look_ahead = executor.look_ahead();
-            // this is safe becuse we do not leak change_set out of this function
-            // this is required because otherwise rustc fails to project the temporary
-            // lifetime
-            let change_set: &'static U = unsafe { &*(change_set as *const U) };
-            let u = ::diesel::update(change_set).set(change_set);
-            #[cfg(feature = "debug")]
-            {
-                log::debug!("{}", ::diesel::debug_query(&u));
-            }
-            u.execute(conn)?;
-            let f = FilterDsl::filter(
-                L::build_query(&[], &look_ahead)?,
-                T::PrimaryKey::default().eq_all(change_set.id()),
-            );
-            // We use identifiable so there should only be one element affected by this query
-            let q = LimitDsl::limit(f, 1);
-            let items = L::load(&look_ahead, selection, executor, q)?;
-            Ok(items.into_iter().next().unwrap_or(Value::Null))
+        resolve_with(ctx, || {
+            conn.transaction(|| {
+                let look_ahead = executor.look_ahead();
+                // this is safe becuse we do not leak change_set out of this function
+                // this is required because otherwise rustc fails to project the temporary
+                // lifetime
+                let change_set: &'static U = unsafe { &*(change_set as *const U) };
+                let u = ::diesel::update(change_set).set(change_set);
+                #[cfg(feature = "debug")]
+                {
+                    log::debug!("{}", ::diesel::debug_query(&u));
+                }
+                u.execute(conn)?;
+                let f = FilterDsl::filter(
+                    L::build_query(&[], &look_ahead)?,
+                    T::PrimaryKey::default().eq_all(change_set.id()),
+                );
+                // We use identifiable so there should only be one element affected by this query
+                let q = LimitDsl::limit(f, 1);
+                let items = L::load(&look_ahead, selection, executor, q)?;
+                Ok(items.into_iter().next().unwrap_or(Value::Null))
+            })
         })
     }
 }
@@ -1,15 +1,26 @@
 use super::WundergraphFieldList;
+use crate::context::WundergraphContext;
 use crate::error::Result;
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::offset::ApplyOffset;
 use crate::query_builder::selection::query_resolver::get_sub_field;
-use crate::query_builder::selection::LoadingHandler;
+use crate::query_builder::selection::{LoadingHandler, SqlTypeOfPlaceholder};
 use crate::query_builder::types::HasMany;
 use crate::scalar::WundergraphScalarValue;
 use diesel::associations::HasTable;
 use diesel::backend::Backend;
-use diesel::expression::NonAggregate;
-use diesel::query_builder::QueryFragment;
-use diesel::{QuerySource, SelectableExpression};
+use diesel::dsl::SqlTypeOf;
+use diesel::expression::bound::Bound;
+use diesel::expression::nullable::Nullable as NullableExpression;
+use diesel::expression::{AsExpression, NonAggregate};
+use diesel::query_builder::{BoxedSelectStatement, QueryFragment};
+use diesel::query_dsl::methods::BoxedDsl;
+use diesel::serialize::ToSql;
+use diesel::sql_types::{HasSqlType, NotNull};
+use diesel::{
+    AppearsOnTable, Connection, ExpressionMethods, QuerySource, Queryable, SelectableExpression,
+    Table,
+};
 use juniper::{Executor, LookAheadMethods, Selection};
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -321,7 +332,10 @@ where
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<HashMap<Option<Self::Key>, Vec<juniper::Value<WundergraphScalarValue>>>> {
         let (keys, vals): (Vec<_>, Vec<_>) = res.into_iter().unzip();
-        let vals = <<Self as LoadingHandler<DB, Ctx>>::FieldList as WundergraphFieldList<
+        #[cfg(feature = "relay")]
+        let ids = <Self as LoadingHandler<DB, Ctx>>::global_ids(&vals);
+        #[allow(unused_mut)]
+        let mut vals = <<Self as LoadingHandler<DB, Ctx>>::FieldList as WundergraphFieldList<
             DB,
             <Self as LoadingHandler<DB, Ctx>>::PrimaryKeyIndex,
             <Self as HasTable>::Table,
@@ -334,6 +348,14 @@ where
             <Self as LoadingHandler<DB, Ctx>>::FIELD_NAMES,
             executor,
         )?;
+        #[cfg(feature = "relay")]
+        crate::relay::add_global_ids(
+            &mut vals,
+            ids,
+            <Self as LoadingHandler<DB, Ctx>>::TYPE_NAME,
+            look_ahead,
+            selection,
+        );
         Ok(keys
             .into_iter()
             .zip(vals.into_iter())
@@ -344,6 +366,155 @@ where
     }
 }
 
+/// A helper trait used to resolve `HasOne` fields referencing a given entity
+///
+/// **This traits needs to implemented for concrete types, because otherwise rustc
+///  is not able to proof that certain traits are require implemented because of
+///  potential circular dependencies, for example for entities referencing
+///  themselves**
+///
+/// # Type parameters:
+/// * `Self`: Type referenced by the `HasOne` field
+/// * `Key`: Primary key type of `Self`
+/// * `DB`: Backend type from diesel, so one of `Pg` or `Sqlite`
+/// * `Ctx`: The used wundergraph context type
+///
+/// # Deriving
+/// An implementation of this trait is automatically generated by
+/// [`#[derive(WundergraphEntity)]`](../derive.WundergraphEntity.html)
+///
+/// # Manual implementation
+///
+/// A manual implementation only needs to forward to
+/// [`load_by_keys`](#method.load_by_keys):
+///
+/// ```ignore
+/// impl<Ctx> WundergraphHasOneTarget<i32, Pg, Ctx> for Species
+/// where
+///     Ctx: WundergraphContext + 'static,
+///     <Ctx as WundergraphContext>::Connection: Connection<Backend = Pg>,
+/// {
+///     fn resolve(
+///         global_args: &[LookAheadArgument<WundergraphScalarValue>],
+///         look_ahead: &LookAheadSelection<'_, WundergraphScalarValue>,
+///         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+///         keys: &[Option<i32>],
+///         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+///     ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>> {
+///         Self::load_by_keys(global_args, look_ahead, selection, keys, executor)
+///     }
+/// }
+/// ```
+pub trait WundergraphHasOneTarget<Key, DB, Ctx>: LoadingHandler<DB, Ctx>
+where
+    DB: Backend + ApplyOffset + 'static,
+    Self::Table: 'static,
+    <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    DB::QueryBuilder: Default,
+{
+    /// Load the entities referenced by the given keys
+    ///
+    /// The returned list is aligned with the given keys, containing
+    /// `null` for each key without a matching entity
+    fn resolve(
+        global_args: &[juniper::LookAheadArgument<WundergraphScalarValue>],
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        keys: &[Option<Key>],
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>;
+
+    /// Common part of the implementation that could be implemented in a
+    /// generic way, loading all referenced entities using a single query
+    fn load_by_keys(
+        global_args: &[juniper::LookAheadArgument<WundergraphScalarValue>],
+        look_ahead: &juniper::LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        keys: &[Option<Key>],
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>
+    where
+        DB: HasSqlType<
+                SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+            > + HasSqlType<SqlTypeOf<NullableExpression<<Self::Table as Table>::PrimaryKey>>>,
+        Option<Key>: Queryable<SqlTypeOf<NullableExpression<<Self::Table as Table>::PrimaryKey>>, DB>
+            + ToSql<SqlTypeOf<NullableExpression<<Self::Table as Table>::PrimaryKey>>, DB>,
+        Key: Clone + Eq + Hash,
+        Self::Table: BoxedDsl<
+            'static,
+            DB,
+            Output = BoxedSelectStatement<
+                'static,
+                SqlTypeOf<<Self::Table as Table>::AllColumns>,
+                Self::Table,
+                DB,
+            >,
+        >,
+        NullableExpression<<Self::Table as Table>::PrimaryKey>: ExpressionMethods,
+        <Self::Filter as BuildFilter<DB>>::Ret: AppearsOnTable<Self::Table>,
+        for<'b> &'b Option<Key>: AsExpression<
+            SqlTypeOf<NullableExpression<<Self::Table as Table>::PrimaryKey>>,
+            Expression = Bound<
+                SqlTypeOf<NullableExpression<<Self::Table as Table>::PrimaryKey>>,
+                &'b Option<Key>,
+            >,
+        >,
+        <Self::Table as Table>::PrimaryKey: QueryFragment<DB> + Default,
+        SqlTypeOf<<Self::Table as Table>::PrimaryKey>: NotNull,
+        Ctx: WundergraphContext,
+        Ctx::Connection: Connection<Backend = DB>,
+    {
+        use diesel::{NullableExpressionMethods, QueryDsl, RunQueryDsl};
+
+        let conn = executor.context().get_connection();
+        let q = Self::build_query(global_args, look_ahead)?
+            .filter(
+                <Self::Table as Table>::PrimaryKey::default()
+                    .nullable()
+                    .eq_any(keys),
+            )
+            .select((
+                <Self::Table as Table>::PrimaryKey::default().nullable(),
+                Self::get_select(look_ahead)?,
+            ));
+        #[cfg(feature = "debug")]
+        {
+            log::debug!("{:?}", diesel::debug_query(&q));
+        }
+
+        let items = q.load::<(
+            Option<Key>,
+            <Self::FieldList as WundergraphFieldList<_, _, _, Ctx>>::PlaceHolder,
+        )>(conn)?;
+
+        let (loaded_keys, placeholder): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+
+        #[cfg(feature = "relay")]
+        let ids = Self::global_ids(&placeholder);
+        #[allow(unused_mut)]
+        let mut values = Self::FieldList::resolve(
+            placeholder,
+            global_args,
+            look_ahead,
+            selection,
+            Self::FIELD_NAMES,
+            executor,
+        )?;
+        #[cfg(feature = "relay")]
+        crate::relay::add_global_ids(&mut values, ids, Self::TYPE_NAME, look_ahead, selection);
+
+        let map = loaded_keys
+            .into_iter()
+            .zip(values.into_iter())
+            .collect::<HashMap<_, _>>();
+
+        Ok(keys
+            .iter()
+            .map(|key| map.get(key).cloned().unwrap_or(juniper::Value::Null))
+            .collect())
+    }
+}
+
 impl<T, K, Other, DB, Ctx, FK> WundergraphResolveAssociation<K, Other, DB, Ctx> for HasMany<T, FK>
 where
     DB: Backend + ApplyOffset + 'static,
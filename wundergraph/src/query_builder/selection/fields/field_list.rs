@@ -22,6 +22,8 @@ pub trait WundergraphFieldList<DB: Backend, Key, Table, Ctx> {
     /// Normally a tuple with `TABLE_FIELD_COUNT` entries representing
     /// the (diesel) sql type of the executed query
     type SqlType: 'static;
+    /// The type of the primary key value of a loaded entity
+    type PrimaryKeyValue: Eq + Hash + Clone;
 
     /// Number of fields representing a database column
     const TABLE_FIELD_COUNT: usize;
@@ -40,6 +42,10 @@ pub trait WundergraphFieldList<DB: Backend, Key, Table, Ctx> {
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
     ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>;
 
+    /// Extract the primary key values from the results of an already
+    /// executed sql query
+    fn primary_key_values(placeholder: &[Self::PlaceHolder]) -> Vec<Option<Self::PrimaryKeyValue>>;
+
     #[doc(hidden)]
     fn map_table_field<F: Fn(usize) -> R, R>(local_index: usize, callback: F) -> Option<R>;
     #[doc(hidden)]
@@ -73,6 +79,7 @@ macro_rules! wundergraph_impl_field_list {
             {
                 type PlaceHolder = <<($($T,)*) as FieldListExtractor>::Out as WundergraphValue>::PlaceHolder;
                 type SqlType = <<($($T,)*) as FieldListExtractor>::Out as WundergraphValue>::SqlType;
+                type PrimaryKeyValue = <<<<($($T,)*) as FieldListExtractor>::Out as WundergraphValue>::PlaceHolder as TupleIndex<Key>>::Value as PlaceHolderMarker>::InnerType;
 
                 const TABLE_FIELD_COUNT: usize = <($($T,)*) as FieldListExtractor>::FIELD_COUNT;
                 const NON_TABLE_FIELD_COUNT: usize = <($($T,)*) as NonTableFieldExtractor>::FIELD_COUNT;
@@ -119,6 +126,13 @@ macro_rules! wundergraph_impl_field_list {
                      Ok(extern_values.merge_with_object_list(objs))
                 }
 
+                fn primary_key_values(placeholder: &[Self::PlaceHolder]) -> Vec<Option<Self::PrimaryKeyValue>> {
+                    placeholder.iter()
+                        .map(TupleIndex::<Key>::get)
+                        .map(<_ as PlaceHolderMarker>::into_inner)
+                        .collect()
+                }
+
                 #[inline(always)]
                 fn map_table_field<Func: Fn(usize) -> Ret, Ret>(local_index: usize, callback: Func) -> Option<Ret> {
                     <($($T,)*) as FieldListExtractor>::map(local_index, callback)
@@ -10,7 +10,7 @@ pub use self::helper::{
 };
 
 #[doc(inline)]
-pub use self::associations::WundergraphBelongsTo;
+pub use self::associations::{WundergraphBelongsTo, WundergraphHasOneTarget};
 #[doc(inline)]
 pub use self::field_list::WundergraphFieldList;
 #[doc(inline)]
@@ -0,0 +1,45 @@
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate};
+use diesel::pg::Pg;
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::sql_types::Integer;
+
+/// The number of elements in the first dimension of a postgres array
+///
+/// In contrast to a plain `array_length` call this returns `0` for
+/// empty arrays instead of `NULL`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArrayLength<C>(C);
+
+impl<C> Expression for ArrayLength<C>
+where
+    C: Expression,
+{
+    type SqlType = Integer;
+}
+
+impl<C> QueryFragment<Pg> for ArrayLength<C>
+where
+    C: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, Pg>) -> QueryResult<()> {
+        pass.push_sql("COALESCE(array_length(");
+        self.0.walk_ast(pass.reborrow())?;
+        pass.push_sql(", 1), 0)");
+        Ok(())
+    }
+}
+
+impl<C> QueryId for ArrayLength<C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<C> NonAggregate for ArrayLength<C> where C: NonAggregate {}
+
+impl<C, QS> AppearsOnTable<QS> for ArrayLength<C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
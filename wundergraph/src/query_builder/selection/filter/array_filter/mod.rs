@@ -0,0 +1,243 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::error::Result;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::operator_from_look_ahead;
+use crate::scalar::WundergraphScalarValue;
+use diesel::expression::{AsExpression, NonAggregate};
+use diesel::pg::expression::operators::{Contains, IsContainedBy, OverlapsWith};
+use diesel::pg::Pg;
+use diesel::query_builder::QueryFragment;
+use diesel::sql_types::Bool;
+use diesel::{AppearsOnTable, Column, ExpressionMethods};
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry, ToInputValue};
+use std::marker::PhantomData;
+
+mod expression;
+
+use self::expression::ArrayLength;
+
+/// Additional filter operations for postgres array columns
+///
+/// * `contains`: The column contains all given elements (`@>`)
+/// * `is_contained_by`: All elements of the column are part of the given
+///    elements (`<@`)
+/// * `overlaps_with`: The column and the given elements have at least one
+///    element in common (`&&`)
+/// * `any_eq`: At least one element of the column is equal to the given value
+/// * `length_eq`, `length_gt`, `length_lt`: Compare the number of elements
+///    in the column with the given value
+#[derive(Debug)]
+pub struct ArrayFilter<V, C> {
+    contains: Option<Vec<V>>,
+    is_contained_by: Option<Vec<V>>,
+    overlaps_with: Option<Vec<V>>,
+    any_eq: Option<V>,
+    length_eq: Option<i32>,
+    length_gt: Option<i32>,
+    length_lt: Option<i32>,
+    p: PhantomData<C>,
+}
+
+impl<V, C> Clone for ArrayFilter<V, C>
+where
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            contains: self.contains.clone(),
+            is_contained_by: self.is_contained_by.clone(),
+            overlaps_with: self.overlaps_with.clone(),
+            any_eq: self.any_eq.clone(),
+            length_eq: self.length_eq,
+            length_gt: self.length_gt,
+            length_lt: self.length_lt,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<V, C> Nameable for ArrayFilter<V, C> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<V, C> BuildFilter<Pg> for ArrayFilter<V, C>
+where
+    C: Column + AppearsOnTable<C::Table> + NonAggregate + QueryFragment<Pg> + Default + 'static,
+    C::Table: 'static,
+    Vec<V>: AsExpression<C::SqlType>,
+    <Vec<V> as AsExpression<C::SqlType>>::Expression:
+        NonAggregate + AppearsOnTable<C::Table> + QueryFragment<Pg> + 'static,
+{
+    type Ret = Box<dyn BoxableFilter<C::Table, Pg, SqlType = Bool>>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let Self {
+            contains,
+            is_contained_by,
+            overlaps_with,
+            any_eq,
+            length_eq,
+            length_gt,
+            length_lt,
+            ..
+        } = self;
+        let mut combinator = AndCollector::default();
+        // Those are the operators behind diesels `PgArrayExpressionMethods`,
+        // which are only implemented for not nullable array columns
+        let value = <Vec<V> as AsExpression<C::SqlType>>::as_expression;
+        combinator.append_filter(
+            contains.map(|v| Box::new(Contains::new(C::default(), value(v))) as Self::Ret),
+        );
+        combinator.append_filter(
+            is_contained_by
+                .map(|v| Box::new(IsContainedBy::new(C::default(), value(v))) as Self::Ret),
+        );
+        combinator.append_filter(
+            overlaps_with.map(|v| Box::new(OverlapsWith::new(C::default(), value(v))) as Self::Ret),
+        );
+        // `col @> ARRAY[v]` is equivalent to `v = ANY(col)` but
+        // is able to use an index on the column
+        combinator.append_filter(
+            any_eq.map(|v| Box::new(Contains::new(C::default(), value(vec![v]))) as Self::Ret),
+        );
+        combinator.append_filter(
+            length_eq.map(|l| Box::new(ArrayLength::<C>::default().eq(l)) as Self::Ret),
+        );
+        combinator.append_filter(
+            length_gt.map(|l| Box::new(ArrayLength::<C>::default().gt(l)) as Self::Ret),
+        );
+        combinator.append_filter(
+            length_lt.map(|l| Box::new(ArrayLength::<C>::default().lt(l)) as Self::Ret),
+        );
+        combinator.into_filter()
+    }
+}
+
+impl<V, C> InnerFilter for ArrayFilter<V, C>
+where
+    V: GraphQLType<WundergraphScalarValue, TypeInfo = ()>
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FromLookAheadValue,
+{
+    type Context = ();
+
+    const FIELD_COUNT: usize = 7;
+
+    #[allow(clippy::similar_names)]
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let contains = obj.get("contains").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let is_contained_by = obj.get("is_contained_by").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let overlaps_with = obj.get("overlaps_with").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let any_eq = obj.get("any_eq").map_or_else(
+            || Option::from_input_value(&InputValue::Null),
+            |v| Option::from_input_value(*v),
+        )?;
+        let length_eq = obj.get("length_eq").map_or_else(
+            || {
+                let v: &InputValue<WundergraphScalarValue> = &InputValue::Null;
+                Option::from_input_value(v)
+            },
+            |v| Option::from_input_value(*v),
+        )?;
+        let length_gt = obj.get("length_gt").map_or_else(
+            || {
+                let v: &InputValue<WundergraphScalarValue> = &InputValue::Null;
+                Option::from_input_value(v)
+            },
+            |v| Option::from_input_value(*v),
+        )?;
+        let length_lt = obj.get("length_lt").map_or_else(
+            || {
+                let v: &InputValue<WundergraphScalarValue> = &InputValue::Null;
+                Option::from_input_value(v)
+            },
+            |v| Option::from_input_value(*v),
+        )?;
+        Some(Self {
+            contains,
+            is_contained_by,
+            overlaps_with,
+            any_eq,
+            length_eq,
+            length_gt,
+            length_lt,
+            p: PhantomData,
+        })
+    }
+
+    fn from_inner_look_ahead(
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        Ok(Self {
+            contains: operator_from_look_ahead(obj, "contains")?,
+            is_contained_by: operator_from_look_ahead(obj, "is_contained_by")?,
+            overlaps_with: operator_from_look_ahead(obj, "overlaps_with")?,
+            any_eq: operator_from_look_ahead(obj, "any_eq")?,
+            length_eq: operator_from_look_ahead(obj, "length_eq")?,
+            length_gt: operator_from_look_ahead(obj, "length_gt")?,
+            length_lt: operator_from_look_ahead(obj, "length_lt")?,
+            p: PhantomData,
+        })
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("contains", self.contains.to_input_value());
+        map.insert("is_contained_by", self.is_contained_by.to_input_value());
+        map.insert("overlaps_with", self.overlaps_with.to_input_value());
+        map.insert("any_eq", self.any_eq.to_input_value());
+        map.insert("length_eq", self.length_eq.to_input_value());
+        map.insert("length_gt", self.length_gt.to_input_value());
+        map.insert("length_lt", self.length_lt.to_input_value());
+    }
+
+    #[allow(clippy::similar_names)]
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let contains =
+            registry.arg_with_default::<Option<Vec<V>>>("contains", &None, &Default::default());
+        let is_contained_by = registry.arg_with_default::<Option<Vec<V>>>(
+            "is_contained_by",
+            &None,
+            &Default::default(),
+        );
+        let overlaps_with = registry.arg_with_default::<Option<Vec<V>>>(
+            "overlaps_with",
+            &None,
+            &Default::default(),
+        );
+        let any_eq = registry.arg_with_default::<Option<V>>("any_eq", &None, &Default::default());
+        let length_eq = registry.arg_with_default::<Option<i32>>("length_eq", &None, &());
+        let length_gt = registry.arg_with_default::<Option<i32>>("length_gt", &None, &());
+        let length_lt = registry.arg_with_default::<Option<i32>>("length_lt", &None, &());
+        vec![
+            contains,
+            is_contained_by,
+            overlaps_with,
+            any_eq,
+            length_eq,
+            length_gt,
+            length_lt,
+        ]
+    }
+}
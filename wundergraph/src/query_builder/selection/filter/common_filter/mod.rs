@@ -2,7 +2,9 @@ use super::build_filter::BuildFilter;
 use super::collector::{AndCollector, FilterCollector};
 use super::filter_value::FilterValue;
 use super::inner_filter::InnerFilter;
+use super::operator_from_look_ahead;
 use crate::diesel_ext::BoxableFilter;
+use crate::error::Result;
 use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
@@ -99,33 +101,20 @@ where
         })
     }
 
-    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
-        let eq = obj
-            .iter()
-            .find(|o| o.0 == "eq")
-            .and_then(|o| V::RawValue::from_look_ahead(&o.1));
-        let eq = Eq::new(eq);
+    fn from_inner_look_ahead(
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        let eq = Eq::new(operator_from_look_ahead(obj, "eq")?);
+        let neq = NotEq::new(operator_from_look_ahead(obj, "not_eq")?);
+        let eq_any = EqAny::new(operator_from_look_ahead(obj, "eq_any")?);
+        let additional = V::AdditionalFilter::from_inner_look_ahead(obj)?;
 
-        let neq = obj
-            .iter()
-            .find(|o| o.0 == "not_eq")
-            .and_then(|o| V::RawValue::from_look_ahead(&o.1));
-        let neq = NotEq::new(neq);
-
-        let eq_any = obj
-            .iter()
-            .find(|o| o.0 == "eq_any")
-            .and_then(|o| Vec::from_look_ahead(&o.1));
-        let eq_any = EqAny::new(eq_any);
-
-        let additional = V::AdditionalFilter::from_inner_look_ahead(obj);
-
-        Self {
+        Ok(Self {
             eq,
             neq,
             eq_any,
             additional,
-        }
+        })
     }
 
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
@@ -220,7 +209,7 @@ where
 {
     fn from_look_ahead(a: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
         if let LookAheadValue::Object(ref obj) = *a {
-            Some(Self::from_inner_look_ahead(obj))
+            Self::from_inner_look_ahead(obj).ok()
         } else {
             None
         }
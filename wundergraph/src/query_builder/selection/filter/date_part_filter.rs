@@ -0,0 +1,314 @@
+use crate::diesel_ext::BoxableFilter;
+use crate::error::Result;
+use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::query_builder::selection::filter::build_filter::BuildFilter;
+use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
+use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::operator_from_look_ahead;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::expression::{AppearsOnTable, Expression, NonAggregate};
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::result::QueryResult;
+use diesel::serialize::ToSql;
+use diesel::sql_types::{Bool, HasSqlType, Integer};
+use diesel::{Column, ExpressionMethods};
+use indexmap::IndexMap;
+use juniper::meta::Argument;
+use juniper::{
+    FromInputValue, GraphQLInputObject, InputValue, LookAheadValue, Registry, ToInputValue,
+};
+use std::marker::PhantomData;
+
+type BoxedDatePartFilter<T, DB> = Box<dyn BoxableFilter<T, DB, SqlType = Bool>>;
+
+/// Comparisons that could be applied to a single part of a date
+#[derive(Debug, Clone, Copy, GraphQLInputObject)]
+#[graphql(scalar = WundergraphScalarValue)]
+pub struct DatePartComparison {
+    eq: Option<i32>,
+    #[graphql(name = "not_eq")]
+    not_eq: Option<i32>,
+    gt: Option<i32>,
+    ge: Option<i32>,
+    lt: Option<i32>,
+    le: Option<i32>,
+}
+
+impl FromLookAheadValue for DatePartComparison {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Object(ref obj) = *v {
+            let get = |name: &str| {
+                obj.iter()
+                    .find(|o| o.0 == name)
+                    .and_then(|o| i32::from_look_ahead(&o.1))
+            };
+            Some(Self {
+                eq: get("eq"),
+                not_eq: get("not_eq"),
+                gt: get("gt"),
+                ge: get("ge"),
+                lt: get("lt"),
+                le: get("le"),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl DatePartComparison {
+    fn append_filters<'a, C, DB>(
+        self,
+        part: DatePart<C>,
+        combinator: &mut AndCollector<'a, C::Table, DB>,
+    ) where
+        DB: Backend + HasSqlType<Integer> + 'static,
+        i32: ToSql<Integer, DB>,
+        C: Column + AppearsOnTable<C::Table> + NonAggregate + Copy + 'static,
+        C::Table: 'static,
+        DatePart<C>: QueryFragment<DB>,
+    {
+        combinator.append_filter(
+            self.eq
+                .map(|v| Box::new(part.eq(v)) as BoxedDatePartFilter<C::Table, DB>),
+        );
+        combinator.append_filter(
+            self.not_eq
+                .map(|v| Box::new(part.ne(v)) as BoxedDatePartFilter<C::Table, DB>),
+        );
+        combinator.append_filter(
+            self.gt
+                .map(|v| Box::new(part.gt(v)) as BoxedDatePartFilter<C::Table, DB>),
+        );
+        combinator.append_filter(
+            self.ge
+                .map(|v| Box::new(part.ge(v)) as BoxedDatePartFilter<C::Table, DB>),
+        );
+        combinator.append_filter(
+            self.lt
+                .map(|v| Box::new(part.lt(v)) as BoxedDatePartFilter<C::Table, DB>),
+        );
+        combinator.append_filter(
+            self.le
+                .map(|v| Box::new(part.le(v)) as BoxedDatePartFilter<C::Table, DB>),
+        );
+    }
+}
+
+/// Filters for the single parts of a date or timestamp
+///
+/// Weekdays are counted from `0` (sunday) to `6` (saturday)
+#[derive(Debug, Clone, Copy, GraphQLInputObject)]
+#[graphql(scalar = WundergraphScalarValue)]
+pub struct DateParts {
+    year: Option<DatePartComparison>,
+    month: Option<DatePartComparison>,
+    day: Option<DatePartComparison>,
+    hour: Option<DatePartComparison>,
+    weekday: Option<DatePartComparison>,
+}
+
+impl FromLookAheadValue for DateParts {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Object(ref obj) = *v {
+            let get = |name: &str| {
+                obj.iter()
+                    .find(|o| o.0 == name)
+                    .and_then(|o| DatePartComparison::from_look_ahead(&o.1))
+            };
+            Some(Self {
+                year: get("year"),
+                month: get("month"),
+                day: get("day"),
+                hour: get("hour"),
+                weekday: get("weekday"),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Additional filter for date and time columns, that allows
+/// to filter by single parts of a date like year or month
+#[derive(Debug)]
+pub struct DatePartFilter<C> {
+    date_part: Option<DateParts>,
+    p: PhantomData<C>,
+}
+
+impl<C> Clone for DatePartFilter<C> {
+    fn clone(&self) -> Self {
+        Self {
+            date_part: self.date_part,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<C> Nameable for DatePartFilter<C> {
+    fn name() -> String {
+        String::new()
+    }
+}
+
+impl<C, DB> BuildFilter<DB> for DatePartFilter<C>
+where
+    DB: Backend + HasSqlType<Integer> + 'static,
+    i32: ToSql<Integer, DB>,
+    C: Column + AppearsOnTable<C::Table> + NonAggregate + Default + Copy + 'static,
+    C::Table: 'static,
+    DatePart<C>: QueryFragment<DB>,
+{
+    type Ret = BoxedDatePartFilter<C::Table, DB>;
+
+    fn into_filter(self) -> Option<Self::Ret> {
+        let parts = self.date_part?;
+        let mut combinator = AndCollector::default();
+        let comparisons = [
+            (DatePartKind::Year, parts.year),
+            (DatePartKind::Month, parts.month),
+            (DatePartKind::Day, parts.day),
+            (DatePartKind::Hour, parts.hour),
+            (DatePartKind::Weekday, parts.weekday),
+        ];
+        for (kind, comparison) in &comparisons {
+            if let Some(comparison) = comparison {
+                comparison.append_filters(
+                    DatePart {
+                        column: C::default(),
+                        kind: *kind,
+                    },
+                    &mut combinator,
+                );
+            }
+        }
+        combinator.into_filter()
+    }
+}
+
+impl<C> InnerFilter for DatePartFilter<C> {
+    type Context = ();
+
+    const FIELD_COUNT: usize = 1;
+
+    fn from_inner_input_value(
+        obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
+    ) -> Option<Self> {
+        let date_part = obj.get("date_part").map_or_else(
+            || {
+                let v: &InputValue<WundergraphScalarValue> = &InputValue::Null;
+                Option::from_input_value(v)
+            },
+            |v| Option::from_input_value(*v),
+        )?;
+        Some(Self {
+            date_part,
+            p: PhantomData,
+        })
+    }
+
+    fn from_inner_look_ahead(
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        let date_part = operator_from_look_ahead(obj, "date_part")?;
+        Ok(Self {
+            date_part,
+            p: PhantomData,
+        })
+    }
+
+    fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
+        map.insert("date_part", self.date_part.to_input_value());
+    }
+
+    fn register_fields<'r>(
+        _info: &NameBuilder<Self>,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> Vec<Argument<'r, WundergraphScalarValue>> {
+        let date_part = registry.arg_with_default::<Option<DateParts>>("date_part", &None, &());
+        vec![date_part]
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DatePartKind {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Weekday,
+}
+
+/// Extracts a single part of a date as integer
+///
+/// This maps to `EXTRACT` on postgres and to `strftime` on sqlite
+#[derive(Debug, Clone, Copy)]
+pub struct DatePart<C> {
+    column: C,
+    kind: DatePartKind,
+}
+
+impl<C> Expression for DatePart<C>
+where
+    C: Expression,
+{
+    type SqlType = Integer;
+}
+
+#[cfg(feature = "postgres")]
+impl<C> QueryFragment<diesel::pg::Pg> for DatePart<C>
+where
+    C: QueryFragment<diesel::pg::Pg>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::pg::Pg>) -> QueryResult<()> {
+        pass.push_sql("CAST(EXTRACT(");
+        pass.push_sql(match self.kind {
+            DatePartKind::Year => "YEAR",
+            DatePartKind::Month => "MONTH",
+            DatePartKind::Day => "DAY",
+            DatePartKind::Hour => "HOUR",
+            DatePartKind::Weekday => "DOW",
+        });
+        pass.push_sql(" FROM ");
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql(") AS INTEGER)");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<C> QueryFragment<diesel::sqlite::Sqlite> for DatePart<C>
+where
+    C: QueryFragment<diesel::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut pass: AstPass<'_, diesel::sqlite::Sqlite>) -> QueryResult<()> {
+        pass.push_sql("CAST(strftime(");
+        pass.push_sql(match self.kind {
+            DatePartKind::Year => "'%Y'",
+            DatePartKind::Month => "'%m'",
+            DatePartKind::Day => "'%d'",
+            DatePartKind::Hour => "'%H'",
+            DatePartKind::Weekday => "'%w'",
+        });
+        pass.push_sql(", ");
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql(") AS INTEGER)");
+        Ok(())
+    }
+}
+
+impl<C> QueryId for DatePart<C> {
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<C> NonAggregate for DatePart<C> where C: NonAggregate {}
+
+impl<C, QS> AppearsOnTable<QS> for DatePart<C>
+where
+    C: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
@@ -4,8 +4,9 @@ use super::filter_value::FilterValue;
 use super::inner_filter::InnerFilter;
 use super::nullable_filter::IsNull;
 use super::reference_filter::ReferenceFilter;
-use super::Filter;
+use super::{field_filter_from_look_ahead, Filter};
 use crate::diesel_ext::BoxableFilter;
+use crate::error::Result;
 use crate::helper::tuple::ConcatTuples;
 use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
 use crate::query_builder::selection::fields::WundergraphBelongsTo;
@@ -146,6 +147,18 @@ impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for i64 {
     type Filter = FilterOption<Self, C>;
 }
 
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for u16 {
+    type Filter = FilterOption<Self, C>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for u32 {
+    type Filter = FilterOption<Self, C>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for u64 {
+    type Filter = FilterOption<Self, C>;
+}
+
 impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for bool {
     type Filter = FilterOption<Self, C>;
 }
@@ -321,8 +334,8 @@ where
 ///
 ///      fn from_inner_look_ahead(
 ///          objs: &[(&str, LookAheadValue<WundergraphScalarValue>)]
-///      ) -> Filter<Pg, Ctx> {
-///          FilterBuildHelper::<Filter<Pg, Ctx>, Hero, Pg, Ctx>::from_inner_look_ahead(objs).0
+///      ) -> Result<Filter<Pg, Ctx>> {
+///          Ok(FilterBuildHelper::<Filter<Pg, Ctx>, Hero, Pg, Ctx>::from_inner_look_ahead(objs)?.0)
 ///      }
 ///
 ///      fn from_inner_input_value(
@@ -364,7 +377,9 @@ where
     fn into_filter(f: F) -> Option<Self::Ret>;
 
     /// Build the filter from a set of juniper look ahead values
-    fn from_inner_look_ahead(objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> F;
+    fn from_inner_look_ahead(
+        objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<F>;
     /// Build the filter from a set of juniper input values
     fn from_inner_input_value(
         obj: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
@@ -440,10 +455,12 @@ where
         })
     }
 
-    fn from_inner_look_ahead(objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
-        Self {
-            filter: L::Table::from_inner_look_ahead(objs),
-        }
+    fn from_inner_look_ahead(
+        objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        Ok(Self {
+            filter: L::Table::from_inner_look_ahead(objs)?,
+        })
     }
 
     fn to_inner_input_value(&self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
@@ -490,7 +507,7 @@ macro_rules! __impl_build_filter_for_tuples {
                 Loading: LoadingHandler<Back, Ctx>,
                 <Loading::Table as QuerySource>::FromClause: QueryFragment<Back>,
                 Back::QueryBuilder: Default,
-                $($T: GraphQLType<WundergraphScalarValue, TypeInfo = NameBuilder<$T>> + ToInputValue<WundergraphScalarValue> + FromInputValue<WundergraphScalarValue> + Nameable + FromLookAheadValue,)*
+                $($T: GraphQLType<WundergraphScalarValue, TypeInfo = NameBuilder<$T>> + ToInputValue<WundergraphScalarValue> + FromInputValue<WundergraphScalarValue> + Nameable + InnerFilter,)*
             {
                 type Context = ();
 
@@ -515,19 +532,19 @@ macro_rules! __impl_build_filter_for_tuples {
 
                 fn from_inner_look_ahead(
                     objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]
-                ) -> Self {
+                ) -> Result<Self> {
                     let mut values = ($(Option::<$T>::default(),)*);
                     for (name, value) in objs {
                         match name {
                             $(
                                 n if *n == Loading::FIELD_NAMES[$idx] => {
-                                    values.$idx = <$T as FromLookAheadValue>::from_look_ahead(value);
+                                    values.$idx = field_filter_from_look_ahead(value, n)?;
                                 }
                             )*
                             _  => {}
                         }
                     }
-                    FilterBuildHelper(values, PhantomData)
+                    Ok(FilterBuildHelper(values, PhantomData))
                 }
 
                 fn to_inner_input_value(
@@ -1,4 +1,6 @@
 use crate::juniper_ext::FromLookAheadValue;
+#[cfg(feature = "postgres")]
+use crate::query_builder::selection::filter::array_filter::ArrayFilter;
 use crate::query_builder::selection::filter::nullable_filter::NullableFilter;
 use crate::query_builder::selection::filter::string_filter::StringFilter;
 use crate::scalar::WundergraphScalarValue;
@@ -41,6 +43,24 @@ impl<C> FilterValue<C> for i64 {
     type AdditionalFilter = ();
 }
 
+// Unsigned values are stored as signed values at database side
+// (see the corresponding `WundergraphValue` impls), so
+// filters are using the signed types as well
+impl<C> FilterValue<C> for u16 {
+    type RawValue = i32;
+    type AdditionalFilter = ();
+}
+
+impl<C> FilterValue<C> for u32 {
+    type RawValue = i64;
+    type AdditionalFilter = ();
+}
+
+impl<C> FilterValue<C> for u64 {
+    type RawValue = i64;
+    type AdditionalFilter = ();
+}
+
 impl<C> FilterValue<C> for String {
     type RawValue = Self;
     type AdditionalFilter = StringFilter<C>;
@@ -61,6 +81,20 @@ impl<C> FilterValue<C> for f64 {
     type AdditionalFilter = ();
 }
 
+#[cfg(feature = "postgres")]
+impl<C, V> FilterValue<C> for Vec<V>
+where
+    V: FromLookAheadValue
+        + FromInputValue<WundergraphScalarValue>
+        + ToInputValue<WundergraphScalarValue>
+        + FilterValue<C>
+        + Clone,
+{
+    type RawValue = Self;
+    type AdditionalFilter = ArrayFilter<V, C>;
+}
+
+#[cfg(not(feature = "postgres"))]
 impl<C, V> FilterValue<C> for Vec<V>
 where
     V: FromLookAheadValue
@@ -81,6 +115,6 @@ where
         + ToInputValue<WundergraphScalarValue>
         + FilterValue<C>,
 {
-    type RawValue = V;
+    type RawValue = V::RawValue;
     type AdditionalFilter = NullableFilter<V, C>;
 }
@@ -1,3 +1,4 @@
+use crate::error::Result;
 use crate::juniper_ext::{NameBuilder, Nameable};
 use crate::scalar::WundergraphScalarValue;
 use indexmap::IndexMap;
@@ -20,7 +21,11 @@ pub trait InnerFilter: Sized + Nameable {
         v: IndexMap<&str, &InputValue<WundergraphScalarValue>>,
     ) -> Option<Self>;
     /// Create the given filter from a graphql lookahead value
-    fn from_inner_look_ahead(v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self;
+    ///
+    /// Returns an error if the value of a operator could not be parsed
+    fn from_inner_look_ahead(
+        v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self>;
     /// Covert the given filter into a graphql value
     fn to_inner_input_value(&self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>);
     /// Register all fields of the the filter in a given graphql schema
@@ -43,7 +48,11 @@ impl InnerFilter for () {
         Some(())
     }
 
-    fn from_inner_look_ahead(_v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {}
+    fn from_inner_look_ahead(
+        _v: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        Ok(())
+    }
     fn to_inner_input_value(&self, _v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {}
     fn register_fields<'r>(
         _info: &NameBuilder<Self>,
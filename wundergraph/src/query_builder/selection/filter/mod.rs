@@ -2,6 +2,7 @@
 //! filter entities. The main entry point is the [`Filter`](struct.Filter.html) struct
 
 use crate::diesel_ext::BoxableFilter;
+use crate::error::{Result, WundergraphError};
 use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
@@ -20,9 +21,13 @@ use juniper::Registry;
 use juniper::ToInputValue;
 use std::marker::PhantomData;
 
+#[cfg(feature = "postgres")]
+mod array_filter;
 pub(crate) mod build_filter;
 pub mod collector;
 mod common_filter;
+#[cfg(feature = "chrono")]
+pub(crate) mod date_part_filter;
 pub(crate) mod filter_helper;
 pub(crate) mod filter_value;
 pub(crate) mod inner_filter;
@@ -150,7 +155,7 @@ where
 {
     fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
         if let LookAheadValue::Object(ref obj) = *v {
-            Some(Self::from_inner_look_ahead(obj))
+            Self::from_inner_look_ahead(obj).ok()
         } else {
             None
         }
@@ -218,6 +223,83 @@ where
     }
 }
 
+/// Parse the value of a single filter operator
+///
+/// Returns `None` if the operator is not set. A value that could not be
+/// parsed results in an `InvalidFilter` error, the name of the filtered
+/// field is added by [`field_filter_from_look_ahead`](fn.field_filter_from_look_ahead.html)
+#[doc(hidden)]
+pub fn operator_from_look_ahead<T>(
+    obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    operator: &str,
+) -> Result<Option<T>>
+where
+    T: FromLookAheadValue,
+{
+    match obj.iter().find(|o| o.0 == operator) {
+        None | Some((_, LookAheadValue::Null)) => Ok(None),
+        Some((_, value)) => {
+            T::from_look_ahead(value)
+                .map(Some)
+                .ok_or_else(|| WundergraphError::InvalidFilter {
+                    field: String::new(),
+                    operator: operator.to_owned(),
+                })
+        }
+    }
+}
+
+/// Build the filter for a single field of an entity
+///
+/// Returns `None` if no filter is given for this field
+#[doc(hidden)]
+pub fn field_filter_from_look_ahead<T>(
+    value: &LookAheadValue<'_, WundergraphScalarValue>,
+    field: &str,
+) -> Result<Option<T>>
+where
+    T: InnerFilter,
+{
+    match *value {
+        LookAheadValue::Null => Ok(None),
+        LookAheadValue::Object(ref obj) => {
+            T::from_inner_look_ahead(obj)
+                .map(Some)
+                .map_err(|e| match e {
+                    // Errors from a nested filter already contain the inner field
+                    WundergraphError::InvalidFilter {
+                        field: ref f,
+                        ref operator,
+                    } if f.is_empty() => WundergraphError::InvalidFilter {
+                        field: field.to_owned(),
+                        operator: operator.clone(),
+                    },
+                    e => e,
+                })
+        }
+        _ => Err(WundergraphError::CouldNotBuildFilterArgument),
+    }
+}
+
+fn filter_list_from_look_ahead<F, T>(
+    objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    name: &str,
+) -> Result<Option<Vec<Filter<F, T>>>>
+where
+    F: InnerFilter,
+{
+    match objs.iter().find(|o| o.0 == name).map(|o| &o.1) {
+        None | Some(LookAheadValue::Null) => Ok(None),
+        // A single filter is coerced into a list containing this filter
+        Some(LookAheadValue::List(ref filters)) => filters
+            .iter()
+            .filter_map(|f| field_filter_from_look_ahead(f, name).transpose())
+            .collect::<Result<_>>()
+            .map(Some),
+        Some(filter) => Ok(field_filter_from_look_ahead(filter, name)?.map(|f| vec![f])),
+    }
+}
+
 impl<F, T> InnerFilter for Filter<F, T>
 where
     F: InnerFilter,
@@ -251,31 +333,24 @@ where
         })
     }
 
-    fn from_inner_look_ahead(objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
-        let and = objs
-            .iter()
-            .find(|o| o.0 == "and")
-            .and_then(|o| Vec::from_look_ahead(&o.1));
-
-        let or = objs
-            .iter()
-            .find(|o| o.0 == "or")
-            .and_then(|o| Vec::from_look_ahead(&o.1));
-
-        let not = objs
-            .iter()
-            .find(|o| o.0 == "not")
-            .and_then(|o| Box::from_look_ahead(&o.1));
-
-        let inner = F::from_inner_look_ahead(objs);
+    fn from_inner_look_ahead(
+        objs: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        let and = filter_list_from_look_ahead(objs, "and")?;
+        let or = filter_list_from_look_ahead(objs, "or")?;
+        let not = match objs.iter().find(|o| o.0 == "not") {
+            Some(o) => field_filter_from_look_ahead(&o.1, "not")?.map(|f| Box::new(Not(f))),
+            None => None,
+        };
+        let inner = F::from_inner_look_ahead(objs)?;
 
-        Self {
+        Ok(Self {
             and,
             or,
             not,
             inner,
             p: PhantomData,
-        }
+        })
     }
 
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
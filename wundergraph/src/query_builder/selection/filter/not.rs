@@ -9,7 +9,7 @@ use juniper::{FromInputValue, GraphQLType, InputValue, LookAheadValue, Registry,
 
 /// A filter node representing a negation operation
 #[derive(Debug)]
-pub struct Not<I>(I);
+pub struct Not<I>(pub(super) I);
 
 impl<DB, I> BuildFilter<DB> for Not<I>
 where
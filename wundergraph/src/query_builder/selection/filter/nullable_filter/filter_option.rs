@@ -1,9 +1,11 @@
 use crate::diesel_ext::BoxableFilter;
-use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::error::Result;
+use crate::juniper_ext::{NameBuilder, Nameable};
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
 use crate::query_builder::selection::filter::filter_value::FilterValue;
 use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::operator_from_look_ahead;
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
 use diesel::expression::{AsExpression, NonAggregate};
@@ -99,17 +101,15 @@ where
         })
     }
 
-    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
-        let is_null = obj
-            .iter()
-            .find(|o| o.0 == "is_null")
-            .and_then(|o| bool::from_look_ahead(&o.1))
-            .map(IsNull::new);
-        let additional = V::AdditionalFilter::from_inner_look_ahead(obj);
-        Self {
+    fn from_inner_look_ahead(
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        let is_null = operator_from_look_ahead(obj, "is_null")?.map(IsNull::new);
+        let additional = V::AdditionalFilter::from_inner_look_ahead(obj)?;
+        Ok(Self {
             is_null,
             additional,
-        }
+        })
     }
 
     fn to_inner_input_value(&self, _v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {}
@@ -1,7 +1,9 @@
 use crate::diesel_ext::BoxableFilter;
-use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::error::Result;
+use crate::juniper_ext::{NameBuilder, Nameable};
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::operator_from_look_ahead;
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
 use diesel::expression::{operators, NonAggregate};
@@ -76,11 +78,10 @@ impl<C> InnerFilter for Option<IsNull<C>> {
         }
     }
 
-    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
-        obj.iter()
-            .find(|o| o.0 == "is_null")
-            .and_then(|o| bool::from_look_ahead(&o.1))
-            .map(IsNull::new)
+    fn from_inner_look_ahead(
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        Ok(operator_from_look_ahead(obj, "is_null")?.map(IsNull::new))
     }
 
     fn to_inner_input_value(&self, v: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
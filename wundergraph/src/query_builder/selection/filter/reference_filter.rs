@@ -1,4 +1,5 @@
 use crate::diesel_ext::BoxableFilter;
+use crate::error::Result;
 use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::filter::collector::{AndCollector, FilterCollector};
@@ -150,13 +151,7 @@ where
 {
     fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
         if let LookAheadValue::Object(ref obj) = *v {
-            let inner = Box::new(I::from_inner_look_ahead(obj));
-            let additional = A::from_inner_look_ahead(obj);
-            Some(Self {
-                inner,
-                additional,
-                p: PhantomData,
-            })
+            Self::from_inner_look_ahead(obj).ok()
         } else {
             None
         }
@@ -211,14 +206,16 @@ where
         })
     }
 
-    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
-        let inner = I::from_inner_look_ahead(obj);
-        let additional = A::from_inner_look_ahead(obj);
-        Self {
+    fn from_inner_look_ahead(
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        let inner = I::from_inner_look_ahead(obj)?;
+        let additional = A::from_inner_look_ahead(obj)?;
+        Ok(Self {
             inner: Box::new(inner),
             additional,
             p: PhantomData,
-        }
+        })
     }
 
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
@@ -1,7 +1,9 @@
 use crate::diesel_ext::BoxableFilter;
-use crate::juniper_ext::{FromLookAheadValue, NameBuilder, Nameable};
+use crate::error::Result;
+use crate::juniper_ext::{NameBuilder, Nameable};
 use crate::query_builder::selection::filter::build_filter::BuildFilter;
 use crate::query_builder::selection::filter::inner_filter::InnerFilter;
+use crate::query_builder::selection::filter::operator_from_look_ahead;
 use crate::scalar::WundergraphScalarValue;
 use diesel::backend::Backend;
 use diesel::sql_types::Bool;
@@ -63,14 +65,13 @@ impl<C> InnerFilter for StringFilter<C> {
         Some(Self { like })
     }
 
-    fn from_inner_look_ahead(obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)]) -> Self {
-        let like = obj
-            .iter()
-            .find(|o| o.0 == "like")
-            .and_then(|o| String::from_look_ahead(&o.1));
-        Self {
+    fn from_inner_look_ahead(
+        obj: &[(&str, LookAheadValue<'_, WundergraphScalarValue>)],
+    ) -> Result<Self> {
+        let like = operator_from_look_ahead(obj, "like")?;
+        Ok(Self {
             like: Like::new(like),
-        }
+        })
     }
 
     fn to_inner_input_value(&self, map: &mut IndexMap<&str, InputValue<WundergraphScalarValue>>) {
@@ -24,9 +24,10 @@
 //! See the documentation of the corresponding traits on details about the
 //! actual implementation
 use crate::context::WundergraphContext;
+use crate::diesel_ext::BoxableFilter;
 use crate::error::{Result, WundergraphError};
 use crate::helper::tuple::IsPrimaryKeyIndex;
-use crate::helper::{PrimaryKeyArgument, UnRef};
+use crate::helper::{PrimaryKeyArgument, PrimaryKeyInputObject, UnRef};
 use crate::juniper_ext::FromLookAheadValue;
 use crate::query_builder::selection::order::BuildOrder;
 use crate::query_builder::selection::select::BuildSelect;
@@ -39,14 +40,15 @@ use diesel::query_builder::{BoxedSelectStatement, QueryFragment};
 use diesel::query_dsl::methods::BoxedDsl;
 use diesel::query_dsl::methods::FilterDsl;
 use diesel::query_dsl::methods::{LimitDsl, SelectDsl};
-use diesel::sql_types::HasSqlType;
+use diesel::sql_types::{Bool, HasSqlType};
 use diesel::BoxableExpression;
 use diesel::EqAll;
 use diesel::Identifiable;
 use diesel::QuerySource;
 use diesel::{AppearsOnTable, Connection, QueryDsl, Table};
 use juniper::LookAheadValue;
-use juniper::{Executor, LookAheadArgument, LookAheadSelection, Selection};
+use juniper::{Executor, LookAheadArgument, LookAheadSelection, Selection, ID};
+use std::hash::Hash;
 
 pub mod fields;
 pub mod filter;
@@ -95,6 +97,20 @@ pub type BoxedQuery<'a, L, DB, Ctx> = BoxedSelectStatement<
     DB,
 >;
 
+/// A unique key of a wundergraph entity
+///
+/// For each unique key registered via `LoadingHandler::UNIQUE_KEYS`
+/// an additional field named `{Entity}By{Key}` is added to the query
+/// object. This field accepts one argument for each key field and returns
+/// `null` if no matching entity exists.
+#[derive(Debug, Clone, Copy)]
+pub struct UniqueKey {
+    /// The name of the query field generated for this key
+    pub field_name: &'static str,
+    /// Indices of the key fields into `LoadingHandler::FIELD_NAMES`
+    pub fields: &'static [usize],
+}
+
 /// Main entry point for loading database entities as GraphQL objects
 ///
 ///
@@ -184,6 +200,8 @@ where
     const TYPE_NAME: &'static str;
     /// The graphql description of the current type
     const TYPE_DESCRIPTION: Option<&'static str> = None;
+    /// Unique keys that could be used to load a single entity
+    const UNIQUE_KEYS: &'static [UniqueKey] = &[];
 
     /// Main entry point to loading something from the database
     ///
@@ -195,6 +213,36 @@ where
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
         query: BoxedQuery<'a, Self, DB, Ctx>,
     ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>
+    where
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        let placeholder = Self::load_placeholder(select, executor, query)?;
+        Self::resolve_placeholder(placeholder, select, selection, executor)
+    }
+
+    /// Execute the final query without resolving the loaded entities
+    ///
+    /// The default implementation passes the query to the `QueryModifier`
+    fn load_placeholder<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<
+        Vec<
+            <Self::FieldList as WundergraphFieldList<
+                DB,
+                Self::PrimaryKeyIndex,
+                Self::Table,
+                Ctx,
+            >>::PlaceHolder,
+        >,
+    >
     where
         DB: HasSqlType<
             SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
@@ -205,7 +253,6 @@ where
         <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
     {
         use diesel::RunQueryDsl;
-        use juniper::LookAheadMethods;
 
         let ctx = executor.context();
         let conn = ctx.get_connection();
@@ -214,15 +261,61 @@ where
         {
             log::debug!("{:?}", diesel::debug_query(&query));
         }
-        let placeholder = <_ as RunQueryDsl<_>>::load(query, conn)?;
-        Ok(Self::FieldList::resolve(
+        Ok(<_ as RunQueryDsl<_>>::load(query, conn)?)
+    }
+
+    /// Resolve already loaded entities into graphql values
+    fn resolve_placeholder(
+        placeholder: Vec<
+            <Self::FieldList as WundergraphFieldList<
+                DB,
+                Self::PrimaryKeyIndex,
+                Self::Table,
+                Ctx,
+            >>::PlaceHolder,
+        >,
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+    ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>
+    where
+        Ctx: WundergraphContext,
+        Ctx::Connection: Connection<Backend = DB>,
+    {
+        use juniper::LookAheadMethods;
+
+        #[cfg(feature = "relay")]
+        let ids = Self::global_ids(&placeholder);
+        #[allow(unused_mut)]
+        let mut values = Self::FieldList::resolve(
             placeholder,
             select.arguments(),
             select,
             selection,
             Self::FIELD_NAMES,
             executor,
-        )?)
+        )?;
+        #[cfg(feature = "relay")]
+        crate::relay::add_global_ids(&mut values, ids, Self::TYPE_NAME, select, selection);
+        Ok(values)
+    }
+
+    /// Global ids of already loaded entities
+    ///
+    /// Used to resolve the `id` field of the relay `Node` interface. The
+    /// derived implementation encodes the type name together with the
+    /// primary key of each entity, the default implementation returns
+    /// no ids at all.
+    #[cfg(feature = "relay")]
+    fn global_ids(
+        placeholder: &[<Self::FieldList as WundergraphFieldList<
+            DB,
+            Self::PrimaryKeyIndex,
+            Self::Table,
+            Ctx,
+        >>::PlaceHolder],
+    ) -> Vec<Option<String>> {
+        vec![None; placeholder.len()]
     }
 
     /// Load a single entity by a given primary key
@@ -232,7 +325,7 @@ where
         select: &LookAheadSelection<'_, WundergraphScalarValue>,
         selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
         executor: &Executor<'_, Ctx, WundergraphScalarValue>,
-        mut query: BoxedQuery<'a, Self, DB, Ctx>,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
     ) -> Result<Option<juniper::Value<WundergraphScalarValue>>>
     where
         Self: 'static,
@@ -263,15 +356,231 @@ where
             <&'static Self as Identifiable>::Id,
             >::from_look_ahead(v.value())
             .ok_or(WundergraphError::NoPrimaryKeyArgumentFound)?;
+        Self::load_by_primary_key_values(key.values, select, selection, executor, query)
+    }
+
+    /// Load a single entity by given primary key values
+    ///
+    /// The default implementation calls `load` internally
+    fn load_by_primary_key_values<'a>(
+        key: <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        mut query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<Option<juniper::Value<WundergraphScalarValue>>>
+    where
+        Self: 'static,
+        &'static Self: Identifiable,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        <&'static Self as Identifiable>::Id: UnRef<'static>,
+        <Self::Table as Table>::PrimaryKey:
+            EqAll<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed> + Default,
+        <<Self::Table as Table>::PrimaryKey as EqAll<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >>::Output: AppearsOnTable<Self::Table> + NonAggregate + QueryFragment<DB>,
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
         query = <_ as QueryDsl>::filter(
             query,
-            <Self::Table as Table>::PrimaryKey::default().eq_all(key.values),
+            <Self::Table as Table>::PrimaryKey::default().eq_all(key),
         );
         query = <_ as QueryDsl>::limit(query, 1);
         let res = Self::load(select, selection, executor, query)?;
         Ok(res.into_iter().next())
     }
 
+    /// Load a list of entities by a given list of ids
+    ///
+    /// Each id is the value of a single column primary key. All entities are
+    /// loaded using a single query. The returned list is aligned with the
+    /// list of given ids, containing `null` for each id without a matching
+    /// entity
+    fn load_by_primary_keys<'a>(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>
+    where
+        Self: 'static,
+        &'static Self: Identifiable,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        <&'static Self as Identifiable>::Id: UnRef<'static>,
+        <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed: Clone + Eq + Hash,
+        Self::FieldList: WundergraphFieldList<
+            DB,
+            Self::PrimaryKeyIndex,
+            Self::Table,
+            Ctx,
+            PrimaryKeyValue = <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >,
+        <Self::Table as Table>::PrimaryKey:
+            EqAll<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed> + Default,
+        <<Self::Table as Table>::PrimaryKey as EqAll<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >>::Output: AppearsOnTable<Self::Table> + NonAggregate + QueryFragment<DB>,
+        <Self::Table as Table>::PrimaryKey: PrimaryKeyInputObject<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+            (),
+        >,
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        use juniper::LookAheadMethods;
+
+        let keys = match select.argument("ids").map(LookAheadArgument::value) {
+            Some(LookAheadValue::List(ids)) => ids
+                .iter()
+                .map(|id| {
+                    let id = ID::from_look_ahead(id)?;
+                    <<Self::Table as Table>::PrimaryKey as PrimaryKeyInputObject<_, ()>>::from_id(
+                        &id,
+                    )
+                })
+                .collect::<Option<Vec<_>>>(),
+            _ => None,
+        }
+        .ok_or(WundergraphError::NoPrimaryKeyArgumentFound)?;
+        Self::load_by_primary_key_list(keys, select, selection, executor, query)
+    }
+
+    /// Load a list of entities by a given list of primary key values
+    ///
+    /// All entities are loaded using a single query. The returned list is
+    /// aligned with the list of given primary keys, containing `null` for
+    /// each key without a matching entity
+    fn load_by_primary_key_list<'a>(
+        keys: Vec<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed>,
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        mut query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<Vec<juniper::Value<WundergraphScalarValue>>>
+    where
+        Self: 'static,
+        &'static Self: Identifiable,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        <&'static Self as Identifiable>::Id: UnRef<'static>,
+        <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed: Clone + Eq + Hash,
+        Self::FieldList: WundergraphFieldList<
+            DB,
+            Self::PrimaryKeyIndex,
+            Self::Table,
+            Ctx,
+            PrimaryKeyValue = <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >,
+        <Self::Table as Table>::PrimaryKey:
+            EqAll<<<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed> + Default,
+        <<Self::Table as Table>::PrimaryKey as EqAll<
+            <<&'static Self as Identifiable>::Id as UnRef<'static>>::UnRefed,
+        >>::Output: AppearsOnTable<Self::Table>
+            + NonAggregate
+            + QueryFragment<DB>
+            + diesel::Expression<SqlType = diesel::sql_types::Bool>
+            + 'a,
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        use diesel::BoolExpressionMethods;
+        use std::collections::HashMap;
+
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        // The primary key is required to match the loaded entities
+        // with the requested keys
+        query = <_ as SelectDsl<_>>::select(query, Self::get_select_with_primary_key(select)?);
+        // All keys are combined into a single grouped condition,
+        // otherwise they would bypass an already applied filter
+        let mut key_filter: Option<Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool> + 'a>> =
+            None;
+        for key in &keys {
+            let f = <Self::Table as Table>::PrimaryKey::default().eq_all(key.clone());
+            key_filter = Some(match key_filter {
+                Some(c) => Box::new(c.or(f)) as Box<_>,
+                None => Box::new(f) as Box<_>,
+            });
+        }
+        if let Some(key_filter) = key_filter {
+            query = <_ as QueryDsl>::filter(query, key_filter);
+        }
+
+        let placeholder = Self::load_placeholder(select, executor, query)?;
+        let loaded_keys = Self::FieldList::primary_key_values(&placeholder);
+        let values = Self::resolve_placeholder(placeholder, select, selection, executor)?;
+        let values = loaded_keys
+            .into_iter()
+            .zip(values)
+            .filter_map(|(key, value)| key.map(|key| (key, value)))
+            .collect::<HashMap<_, _>>();
+        Ok(keys
+            .iter()
+            .map(|key| values.get(key).cloned().unwrap_or(juniper::Value::Null))
+            .collect())
+    }
+
+    /// Get the unique key belonging to the given query field name
+    fn unique_key(field_name: &str) -> Option<&'static UniqueKey> {
+        Self::UNIQUE_KEYS
+            .iter()
+            .find(|key| key.field_name == field_name)
+    }
+
+    /// Build a filter matching the values of the given unique key
+    ///
+    /// The values of the key fields are expected as arguments named like the
+    /// corresponding graphql fields. The key columns are compared directly,
+    /// so this does not depend on `Self::Filter`. The default implementation
+    /// does not know any key columns and always returns an error.
+    fn unique_key_filter(
+        _key: &UniqueKey,
+        _select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    ) -> Result<Box<dyn BoxableFilter<Self::Table, DB, SqlType = Bool>>> {
+        Err(WundergraphError::NoUniqueKeyArgumentFound)
+    }
+
+    /// Load a single entity by a given unique key
+    ///
+    /// The default implementation calls `unique_key_filter` and `load`
+    /// internally
+    fn load_by_unique_key<'a>(
+        key: &UniqueKey,
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+        selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+        executor: &Executor<'_, Ctx, WundergraphScalarValue>,
+        mut query: BoxedQuery<'a, Self, DB, Ctx>,
+    ) -> Result<Option<juniper::Value<WundergraphScalarValue>>>
+    where
+        Self::Table: 'static,
+        Ctx: WundergraphContext + QueryModifier<Self, DB>,
+        Ctx::Connection: Connection<Backend = DB>,
+        DB: HasSqlType<
+            SqlTypeOfPlaceholder<Self::FieldList, DB, Self::PrimaryKeyIndex, Self::Table, Ctx>,
+        >,
+        DB::QueryBuilder: Default,
+        <Self::Table as QuerySource>::FromClause: QueryFragment<DB>,
+    {
+        let filter = Self::unique_key_filter(key, select)?;
+        query = <_ as QueryDsl>::filter(query, filter);
+        query = <_ as QueryDsl>::limit(query, 1);
+        let res = Self::load(select, selection, executor, query)?;
+        Ok(res.into_iter().next())
+    }
+
     /// Build a sql query to load this entity from a given graphql request
     ///
     /// The default implementation calls `get_select`, `apply_filter`,
@@ -324,6 +633,11 @@ where
         >,
     > {
         use juniper::LookAheadMethods;
+        // Global ids are derived from the primary key
+        #[cfg(feature = "relay")]
+        let selects_global_id = select.has_child(crate::relay::GLOBAL_ID_FIELD);
+        #[cfg(not(feature = "relay"))]
+        let selects_global_id = false;
         <Self::Columns as BuildSelect<Self::Table, DB, _>>::build_select(
             select,
             |local_index| {
@@ -331,12 +645,47 @@ where
                     .expect("Field is there")
             },
             Self::PrimaryKeyIndex::is_index,
-            (0..Self::FieldList::NON_TABLE_FIELD_COUNT).any(|i| {
-                Self::FieldList::map_non_table_field(i, |global| {
-                    select.has_child(Self::FIELD_NAMES[global])
-                })
-                .unwrap_or(false)
-            }),
+            selects_global_id
+                || (0..Self::FieldList::NON_TABLE_FIELD_COUNT).any(|i| {
+                    Self::FieldList::map_non_table_field(i, |global| {
+                        select.has_child(Self::FIELD_NAMES[global])
+                    })
+                    .unwrap_or(false)
+                }),
+        )
+    }
+
+    /// Construct a select clause like `get_select`, but always select
+    /// the primary key
+    ///
+    /// Used when loaded entities need to be matched with the requested
+    /// primary keys, even if the primary key is not part of the selection
+    #[doc(hidden)]
+    fn get_select_with_primary_key(
+        select: &LookAheadSelection<'_, WundergraphScalarValue>,
+    ) -> Result<
+        Box<
+            dyn BoxableExpression<
+                Self::Table,
+                DB,
+                SqlType = SqlTypeOfPlaceholder<
+                    Self::FieldList,
+                    DB,
+                    Self::PrimaryKeyIndex,
+                    Self::Table,
+                    Ctx,
+                >,
+            >,
+        >,
+    > {
+        <Self::Columns as BuildSelect<Self::Table, DB, _>>::build_select(
+            select,
+            |local_index| {
+                Self::FieldList::map_table_field(local_index, |global| Self::FIELD_NAMES[global])
+                    .expect("Field is there")
+            },
+            Self::PrimaryKeyIndex::is_index,
+            true,
         )
     }
 
@@ -351,12 +700,15 @@ where
     {
         use juniper::LookAheadMethods;
         if let Some(filter) = select.argument("filter") {
-            if let Some(filter) =
-                <Filter<Self::Filter, Self::Table> as FromLookAheadValue>::from_look_ahead(
-                    filter.value(),
-                )
-                .and_then(<_ as BuildFilter<DB>>::into_filter)
-            {
+            let filter = match *filter.value() {
+                LookAheadValue::Object(ref obj) => {
+                    <Filter<Self::Filter, Self::Table> as InnerFilter>::from_inner_look_ahead(obj)?
+                }
+                LookAheadValue::Null => return Ok(query),
+                _ => return Err(WundergraphError::CouldNotBuildFilterArgument),
+            };
+            // An empty filter does not result in a filter expression
+            if let Some(filter) = <_ as BuildFilter<DB>>::into_filter(filter) {
                 Ok(<_ as FilterDsl<_>>::filter(query, filter))
             } else {
                 Ok(query)
@@ -74,7 +74,7 @@ macro_rules! wundergraph_value_impl {
 
                     self.into_iter().zip(objs.iter_mut()).map(|(placeholder, obj)|{
                         $(
-                            if let Some(look_ahead) = look_ahead.select_child(get_name($idx)) {
+                            if let Some(look_ahead) = select_field(look_ahead, get_name($idx)) {
                                 let (name, alias, pos, selection) = get_sub_field(get_name($idx), selection);
                                 let executor = executor.field_sub_executor(alias, name, pos, selection);
                                 if let Some(value) = resolver.$idx.resolve_value(
@@ -90,7 +90,7 @@ macro_rules! wundergraph_value_impl {
                         Ok(())
                     }).collect::<Result<Vec<_>>>()?;
                     $(
-                        if let Some(look_ahead) = look_ahead.select_child(get_name($idx)) {
+                        if let Some(look_ahead) = select_field(look_ahead, get_name($idx)) {
                             let (name, alias, pos, selection) = get_sub_field(get_name($idx), selection);
                             let executor = executor.field_sub_executor(alias, name, pos, selection);
                             let vals = resolver.$idx.finalize(global_args, look_ahead, selection, &executor)?;
@@ -128,6 +128,28 @@ macro_rules! wundergraph_value_impl {
 
 __diesel_for_each_tuple!(wundergraph_value_impl);
 
+/// Select a field from the look ahead, skipping fields shadowed by
+/// the relay global id field
+#[cfg(feature = "relay")]
+fn select_field<'a, 'b>(
+    look_ahead: &'b juniper::LookAheadSelection<'a, WundergraphScalarValue>,
+    name: &str,
+) -> Option<&'b juniper::LookAheadSelection<'a, WundergraphScalarValue>> {
+    if name == crate::relay::GLOBAL_ID_FIELD {
+        None
+    } else {
+        look_ahead.select_child(name)
+    }
+}
+
+#[cfg(not(feature = "relay"))]
+fn select_field<'a, 'b>(
+    look_ahead: &'b juniper::LookAheadSelection<'a, WundergraphScalarValue>,
+    name: &str,
+) -> Option<&'b juniper::LookAheadSelection<'a, WundergraphScalarValue>> {
+    look_ahead.select_child(name)
+}
+
 pub(crate) fn get_sub_field<'a>(
     field_name: &'a str,
     selection: Option<&'a [Selection<'a, WundergraphScalarValue>]>,
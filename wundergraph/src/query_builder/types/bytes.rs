@@ -0,0 +1,119 @@
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::FilterOption;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Binary, Nullable};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{graphql_scalar, InputValue, LookAheadValue, ParseScalarResult, Value};
+use std::io::Write;
+
+use super::{PlaceHolder, WundergraphValue};
+
+/// A wrapper type for binary data like `bytea` (postgres) or
+/// `BLOB` (sqlite) columns
+///
+/// Plain `Vec<T>` values are mapped to arrays at database side, so binary
+/// columns need a dedicated type. At graphql side values of this type are
+/// represented as base64 encoded strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, AsExpression, FromSqlRow)]
+#[sql_type = "Binary"]
+pub struct Bytes(pub Vec<u8>);
+
+impl From<Vec<u8>> for Bytes {
+    fn from(v: Vec<u8>) -> Self {
+        Self(v)
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(b: Bytes) -> Self {
+        b.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<DB> ToSql<Binary, DB> for Bytes
+where
+    DB: Backend,
+    Vec<u8>: ToSql<Binary, DB>,
+{
+    fn to_sql<W: Write>(&self, out: &mut Output<'_, W, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<Binary, DB> for Bytes
+where
+    DB: Backend,
+    Vec<u8>: FromSql<Binary, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        Vec::<u8>::from_sql(bytes).map(Self)
+    }
+}
+
+graphql_scalar!(Bytes as "Bytes" where Scalar = WundergraphScalarValue {
+    description: "Binary data, serialized as base64 encoded string"
+
+    resolve(&self) -> Value {
+        Value::scalar(base64::encode(&self.0))
+    }
+
+    from_input_value(v: &InputValue) -> Option<Bytes> {
+        match *v {
+            InputValue::Scalar(WundergraphScalarValue::String(ref s)) => {
+                base64::decode(s).ok().map(Bytes)
+            }
+            _ => None,
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        if let ScalarToken::String(v) = value {
+            base64::decode(v)
+                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+                .map(|_| v.into())
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+});
+
+impl Nameable for Bytes {
+    fn name() -> String {
+        String::from("Bytes")
+    }
+}
+
+impl FromLookAheadValue for Bytes {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) = *v {
+            base64::decode(s).ok().map(Self)
+        } else {
+            None
+        }
+    }
+}
+
+impl WundergraphValue for Bytes {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Binary>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for Bytes {
+    type Filter = FilterOption<Self, C>;
+}
+
+impl<C> FilterValue<C> for Bytes {
+    type RawValue = Self;
+    type AdditionalFilter = ();
+}
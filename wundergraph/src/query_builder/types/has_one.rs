@@ -14,12 +14,15 @@ use std::hash::{Hash, Hasher};
 
 /// Type used to indicate that a given field references a single
 /// other entity by id
+///
+/// The referenced entity may be the entity containing the field itself.
 #[derive(Debug, Clone)]
 pub enum HasOne<K, O> {
     #[doc(hidden)]
     Id(K),
     #[doc(hidden)]
-    Item(O),
+    // Boxed to allow an entity to reference itself
+    Item(Box<O>),
 }
 
 impl<R, T> PartialEq for HasOne<R, T>
@@ -78,9 +81,8 @@ where
 {
     fn into(self) -> Option<&'a K> {
         match *self {
-            HasOne::Id(Some(ref k)) => Some(k),
-            HasOne::Item(Some(ref i)) => Some(i.id()),
-            HasOne::Id(None) | HasOne::Item(None) => None,
+            HasOne::Id(ref k) => k.as_ref(),
+            HasOne::Item(ref i) => (**i).as_ref().map(Identifiable::id),
         }
     }
 }
@@ -226,10 +228,17 @@ where
     ) -> Option<HasOne<R, T>> {
         Self::from_look_ahead(look_ahead).map(HasOne::Id)
     }
+    fn from_id(id: &str) -> Option<HasOne<R, T>> {
+        <C as PrimaryKeyInputObject<R, I>>::from_id(id).map(HasOne::Id)
+    }
     fn to_input_value(values: &HasOne<R, T>) -> InputValue<WundergraphScalarValue> {
         match *values {
             HasOne::Id(ref id) => C::to_input_value(id),
             HasOne::Item(ref i) => C::to_input_value(i.id()),
         }
     }
+
+    fn key_names() -> Vec<&'static str> {
+        <C as PrimaryKeyInputObject<R, I>>::key_names()
+    }
 }
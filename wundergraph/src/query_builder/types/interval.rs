@@ -0,0 +1,212 @@
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::FilterOption;
+use crate::scalar::WundergraphScalarValue;
+use diesel::pg::data_types::PgInterval;
+use diesel::sql_types::{Interval, Nullable};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{graphql_scalar, InputValue, LookAheadValue, ParseScalarResult, Value};
+
+use super::{PlaceHolder, WundergraphValue};
+
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+const MICROSECONDS_PER_MINUTE: i64 = 60 * MICROSECONDS_PER_SECOND;
+const MICROSECONDS_PER_HOUR: i64 = 60 * MICROSECONDS_PER_MINUTE;
+
+// Intervals are transferred as ISO 8601 durations like `P1Y2M3DT4H5M6.5S`
+graphql_scalar!(PgInterval as "Interval" where Scalar = WundergraphScalarValue {
+    description: "A time interval, serialized as ISO 8601 duration"
+
+    resolve(&self) -> Value {
+        Value::scalar(format_interval(self))
+    }
+
+    from_input_value(v: &InputValue) -> Option<PgInterval> {
+        match *v {
+            InputValue::Scalar(WundergraphScalarValue::String(ref s)) => parse_interval(s),
+            _ => None,
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        if let ScalarToken::String(v) = value {
+            parse_interval(v)
+                .ok_or_else(|| ParseError::UnexpectedToken(Token::Scalar(value)))
+                .map(|_| v.into())
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+});
+
+fn format_interval(interval: &PgInterval) -> String {
+    if interval.months == 0 && interval.days == 0 && interval.microseconds == 0 {
+        return String::from("PT0S");
+    }
+    let mut ret = String::from("P");
+    let years = interval.months / 12;
+    let months = interval.months % 12;
+    if years != 0 {
+        ret.push_str(&format!("{}Y", years));
+    }
+    if months != 0 {
+        ret.push_str(&format!("{}M", months));
+    }
+    if interval.days != 0 {
+        ret.push_str(&format!("{}D", interval.days));
+    }
+    if interval.microseconds != 0 {
+        ret.push('T');
+        let sign = if interval.microseconds < 0 { "-" } else { "" };
+        let micros = i128::from(interval.microseconds).abs();
+        let per_hour = i128::from(MICROSECONDS_PER_HOUR);
+        let per_minute = i128::from(MICROSECONDS_PER_MINUTE);
+        let per_second = i128::from(MICROSECONDS_PER_SECOND);
+        let hours = micros / per_hour;
+        let minutes = micros % per_hour / per_minute;
+        let seconds = micros % per_minute / per_second;
+        let fraction = micros % per_second;
+        if hours != 0 {
+            ret.push_str(&format!("{}{}H", sign, hours));
+        }
+        if minutes != 0 {
+            ret.push_str(&format!("{}{}M", sign, minutes));
+        }
+        if fraction != 0 {
+            let fraction = format!("{:06}", fraction);
+            ret.push_str(&format!(
+                "{}{}.{}S",
+                sign,
+                seconds,
+                fraction.trim_end_matches('0')
+            ));
+        } else if seconds != 0 {
+            ret.push_str(&format!("{}{}S", sign, seconds));
+        }
+    }
+    ret
+}
+
+fn parse_interval(s: &str) -> Option<PgInterval> {
+    if !s.starts_with('P') {
+        return None;
+    }
+    let s = &s[1..];
+    let (date, time) = match s.find('T') {
+        Some(idx) => (&s[..idx], Some(&s[idx + 1..])),
+        None => (s, None),
+    };
+    let date = split_components(date)?;
+    let time = match time {
+        Some(time) => split_components(time)?,
+        None => Vec::new(),
+    };
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+
+    let mut interval = PgInterval::new(0, 0, 0);
+    for (value, designator) in date {
+        let value = value.parse::<i32>().ok()?;
+        match designator {
+            'Y' => interval.months = interval.months.checked_add(value.checked_mul(12)?)?,
+            'M' => interval.months = interval.months.checked_add(value)?,
+            'W' => interval.days = interval.days.checked_add(value.checked_mul(7)?)?,
+            'D' => interval.days = interval.days.checked_add(value)?,
+            _ => return None,
+        }
+    }
+    for (value, designator) in time {
+        let micros = match designator {
+            'H' => value
+                .parse::<i64>()
+                .ok()?
+                .checked_mul(MICROSECONDS_PER_HOUR)?,
+            'M' => value
+                .parse::<i64>()
+                .ok()?
+                .checked_mul(MICROSECONDS_PER_MINUTE)?,
+            'S' => parse_seconds(value)?,
+            _ => return None,
+        };
+        interval.microseconds = interval.microseconds.checked_add(micros)?;
+    }
+    Some(interval)
+}
+
+/// Splits something like `1Y2M` into `[("1", 'Y'), ("2", 'M')]`
+fn split_components(s: &str) -> Option<Vec<(&str, char)>> {
+    let mut ret = Vec::new();
+    let mut start = 0;
+    for (idx, c) in s.char_indices() {
+        if c.is_ascii_alphabetic() {
+            if idx == start {
+                return None;
+            }
+            ret.push((&s[start..idx], c));
+            start = idx + 1;
+        }
+    }
+    if start == s.len() {
+        Some(ret)
+    } else {
+        None
+    }
+}
+
+/// Parses seconds with an optional fraction of up to 6 digits
+/// into microseconds
+fn parse_seconds(s: &str) -> Option<i64> {
+    let (negative, s) = if s.starts_with('-') {
+        (true, &s[1..])
+    } else {
+        (false, s)
+    };
+    if s.starts_with('-') || s.starts_with('+') {
+        return None;
+    }
+    let mut parts = s.splitn(2, '.');
+    let seconds = parts.next()?.parse::<i64>().ok()?;
+    let fraction = match parts.next() {
+        None => 0,
+        Some(f) if !f.is_empty() && f.len() <= 6 && f.bytes().all(|b| b.is_ascii_digit()) => {
+            f.parse::<i64>().ok()? * 10_i64.pow(6 - f.len() as u32)
+        }
+        Some(_) => return None,
+    };
+    let micros = seconds
+        .checked_mul(MICROSECONDS_PER_SECOND)?
+        .checked_add(fraction)?;
+    Some(if negative { -micros } else { micros })
+}
+
+impl Nameable for PgInterval {
+    fn name() -> String {
+        String::from("Interval")
+    }
+}
+
+impl FromLookAheadValue for PgInterval {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) = *v {
+            parse_interval(s)
+        } else {
+            None
+        }
+    }
+}
+
+impl WundergraphValue for PgInterval {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Interval>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for PgInterval {
+    type Filter = FilterOption<Self, C>;
+}
+
+impl<C> FilterValue<C> for PgInterval {
+    type RawValue = Self;
+    type AdditionalFilter = ();
+}
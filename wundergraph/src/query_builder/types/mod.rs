@@ -1,12 +1,16 @@
 //! This module contains several helper types used constructing the final
 //! graphql model
 
+mod bytes;
 pub(crate) mod field_value_resolver;
 mod has_many;
 mod has_one;
+#[cfg(feature = "postgres")]
+mod interval;
 pub(crate) mod placeholder;
 mod wundergraph_value;
 
+pub use self::bytes::Bytes;
 pub use self::field_value_resolver::ResolveWundergraphFieldValue;
 pub use self::has_many::HasMany;
 pub use self::has_one::HasOne;
@@ -1,6 +1,10 @@
+use super::placeholder::PlaceHolderMarker;
 use super::{HasOne, PlaceHolder};
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
 use diesel::sql_types::{BigInt, Bool, Float4, Float8, Integer, Nullable, SmallInt, Text};
 use diesel::Identifiable;
+use std::convert::TryFrom;
 use std::hash::Hash;
 
 pub use wundergraph_derive::WundergraphValue;
@@ -173,6 +177,74 @@ impl WundergraphValue for i64 {
     type SqlType = Nullable<BigInt>;
 }
 
+// Neither postgres nor sqlite know about unsigned integer types,
+// so unsigned values are stored in the next larger signed type
+// (or in `BigInt` for `u64`, which limits those to `i64::MAX`)
+impl WundergraphValue for u16 {
+    type PlaceHolder = PlaceHolder<UnsignedValue<Self>>;
+    type SqlType = Nullable<Integer>;
+}
+
+impl WundergraphValue for u32 {
+    type PlaceHolder = PlaceHolder<UnsignedValue<Self>>;
+    type SqlType = Nullable<BigInt>;
+}
+
+impl WundergraphValue for u64 {
+    type PlaceHolder = PlaceHolder<UnsignedValue<Self>>;
+    type SqlType = Nullable<BigInt>;
+}
+
+/// Helper type used to load unsigned integers from signed database columns
+///
+/// Loading fails if the value stored in the database does not fit into
+/// the unsigned type.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnsignedValue<T>(T);
+
+macro_rules! unsigned_value_impl {
+    ($($T: ty => ($ST: ty, $Signed: ty),)*) => {
+        $(
+            impl<DB> FromSql<$ST, DB> for UnsignedValue<$T>
+            where
+                DB: Backend,
+                $Signed: FromSql<$ST, DB>,
+            {
+                fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+                    let v = <$Signed as FromSql<$ST, DB>>::from_sql(bytes)?;
+                    Ok(Self(<$T>::try_from(v)?))
+                }
+            }
+
+            impl Into<Option<$T>> for PlaceHolder<UnsignedValue<$T>> {
+                fn into(self) -> Option<$T> {
+                    self.into_inner().map(|v| v.0)
+                }
+            }
+
+            impl Into<Option<Option<$T>>> for PlaceHolder<UnsignedValue<$T>> {
+                fn into(self) -> Option<Option<$T>> {
+                    Some(self.into())
+                }
+            }
+
+            impl<'a> Into<Option<&'a $T>> for &'a PlaceHolder<UnsignedValue<$T>> {
+                fn into(self) -> Option<&'a $T> {
+                    let v: Option<&'a UnsignedValue<$T>> = self.into();
+                    v.map(|v| &v.0)
+                }
+            }
+        )*
+    }
+}
+
+unsigned_value_impl! {
+    u16 => (Integer, i32),
+    u32 => (BigInt, i64),
+    u64 => (BigInt, i64),
+}
+
 impl WundergraphValue for bool {
     type PlaceHolder = PlaceHolder<Self>;
     type SqlType = Nullable<Bool>;
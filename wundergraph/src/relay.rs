@@ -0,0 +1,109 @@
+//! This module contains helper types and functions to support the
+//! [relay global object identification](https://relay.dev/graphql/objectidentification.htm)
+//! specification
+//!
+//! If the `relay` feature is enabled each wundergraph entity implements
+//! a `Node` interface providing a globally unique `nodeId: ID!` field. Such
+//! an id encodes the name of the GraphQL type and the primary key of the
+//! entity. The global id uses its own field name, so entity fields named
+//! `id` keep exposing the plain primary key. Relay clients need to be
+//! configured to use `nodeId` as id field of the `Node` interface.
+//!
+//! Additionally `query_object!` generates a `node(id: ID!): Node` and a
+//! `nodes(ids: [ID!]!): [Node]!` field, loading entities by those ids.
+
+use crate::query_builder::selection::query_resolver::get_sub_field;
+use crate::scalar::WundergraphScalarValue;
+use juniper::meta::MetaType;
+use juniper::{
+    GraphQLType, InputValue, LookAheadMethods, LookAheadSelection, LookAheadValue, Registry,
+    Selection, Value, ID,
+};
+
+/// The name of the global id field
+pub const GLOBAL_ID_FIELD: &str = "nodeId";
+
+/// The relay `Node` interface implemented by all wundergraph entities
+#[derive(Debug, Clone, Copy)]
+pub struct Node;
+
+impl GraphQLType<WundergraphScalarValue> for Node {
+    type Context = ();
+    type TypeInfo = ();
+
+    fn name(_info: &Self::TypeInfo) -> Option<&str> {
+        Some("Node")
+    }
+
+    fn meta<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, WundergraphScalarValue>,
+    ) -> MetaType<'r, WundergraphScalarValue>
+    where
+        WundergraphScalarValue: 'r,
+    {
+        let fields = [registry.field::<ID>(GLOBAL_ID_FIELD, &())];
+        registry
+            .build_interface_type::<Self>(info, &fields)
+            .description("An object with a globally unique id")
+            .into_meta()
+    }
+}
+
+/// Encode a global id from a given GraphQL type name and
+/// a primary key, given as input object
+pub fn encode_global_id(type_name: &str, key: &InputValue<WundergraphScalarValue>) -> String {
+    let key = serde_json::to_string(key).expect("Input values are always serializable");
+    base64::encode(&format!("{}:{}", type_name, key))
+}
+
+/// Decode a global id into the GraphQL type name and the
+/// primary key, given as input object
+///
+/// Returns `None` if the given id is not a valid global id
+pub fn decode_global_id(id: &str) -> Option<(String, InputValue<WundergraphScalarValue>)> {
+    let id = String::from_utf8(base64::decode(id).ok()?).ok()?;
+    let mut parts = id.splitn(2, ':');
+    let type_name = parts.next()?;
+    let key = serde_json::from_str(parts.next()?).ok()?;
+    Some((type_name.to_owned(), key))
+}
+
+/// Add the global id and the type name to already resolved entities
+/// if they are requested
+pub(crate) fn add_global_ids(
+    values: &mut [Value<WundergraphScalarValue>],
+    ids: Vec<Option<String>>,
+    type_name: &str,
+    look_ahead: &LookAheadSelection<'_, WundergraphScalarValue>,
+    selection: Option<&'_ [Selection<'_, WundergraphScalarValue>]>,
+) {
+    let id_alias = look_ahead
+        .select_child(GLOBAL_ID_FIELD)
+        .map(|_| get_sub_field(GLOBAL_ID_FIELD, selection).1);
+    let type_name_alias = look_ahead
+        .select_child("__typename")
+        .map(|_| get_sub_field("__typename", selection).1);
+    for (value, id) in values.iter_mut().zip(ids) {
+        if let Value::Object(ref mut obj) = *value {
+            if let (Some(alias), Some(id)) = (id_alias, id) {
+                obj.add_field(alias, Value::scalar(id));
+            }
+            if let Some(alias) = type_name_alias {
+                obj.add_field(alias, Value::scalar(type_name.to_owned()));
+            }
+        }
+    }
+}
+
+/// Extract a global id from a given look ahead argument value
+#[doc(hidden)]
+pub fn global_id_from_look_ahead<'a>(
+    value: &'a LookAheadValue<'_, WundergraphScalarValue>,
+) -> Option<&'a str> {
+    if let LookAheadValue::Scalar(WundergraphScalarValue::String(ref id)) = *value {
+        Some(id)
+    } else {
+        None
+    }
+}
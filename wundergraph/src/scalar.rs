@@ -9,6 +9,7 @@ use juniper::{
     graphql_scalar, GraphQLScalarValue, InputValue, ParseScalarResult, ScalarValue, Value,
 };
 use serde::de;
+use std::convert::TryFrom;
 use std::fmt;
 
 /// This enum is used as scalar value representation for juniper.
@@ -239,3 +240,87 @@ graphql_scalar!(f32 as "SmallFloat" where Scalar = WundergraphScalarValue {
         }
     }
     });
+
+graphql_scalar!(u16 as "UnsignedSmallInt" where Scalar = WundergraphScalarValue {
+    resolve(&self) -> Value {
+        Value::scalar(i32::from(*self))
+    }
+
+    from_input_value(v: &InputValue) -> Option<u16> {
+        match *v {
+            InputValue::Scalar(WundergraphScalarValue::SmallInt(i)) => u16::try_from(i).ok(),
+            InputValue::Scalar(WundergraphScalarValue::Int(i)) => u16::try_from(i).ok(),
+            _ => None,
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        if let ScalarToken::Int(v) = value {
+            v.parse::<u16>()
+                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+                .map(|i| WundergraphScalarValue::Int(i32::from(i)))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+});
+
+graphql_scalar!(u32 as "UnsignedInt" where Scalar = WundergraphScalarValue {
+    resolve(&self) -> Value {
+        Value::scalar(i64::from(*self))
+    }
+
+    from_input_value(v: &InputValue) -> Option<u32> {
+        match *v {
+            InputValue::Scalar(WundergraphScalarValue::SmallInt(i)) => u32::try_from(i).ok(),
+            InputValue::Scalar(WundergraphScalarValue::Int(i)) => u32::try_from(i).ok(),
+            InputValue::Scalar(WundergraphScalarValue::BigInt(i)) => u32::try_from(i).ok(),
+            _ => None,
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        if let ScalarToken::Int(v) = value {
+            v.parse::<u32>()
+                .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+                .map(|i| WundergraphScalarValue::BigInt(i64::from(i)))
+        } else {
+            Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+        }
+    }
+});
+
+// Values of u64 may exceed the range of graphql integers
+// and javascript numbers, therefore they are transferred as strings.
+// The database columns storing them are signed, so only
+// values up to `i64::MAX` are supported
+graphql_scalar!(u64 as "UnsignedBigInt" where Scalar = WundergraphScalarValue {
+    description: "A 64 bit unsigned integer, serialized as string. \
+                  Values are stored in a signed 64 bit column, \
+                  so only values up to 9223372036854775807 are supported"
+
+    resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    from_input_value(v: &InputValue) -> Option<u64> {
+        match *v {
+            InputValue::Scalar(WundergraphScalarValue::String(ref s)) => s.parse().ok(),
+            InputValue::Scalar(WundergraphScalarValue::SmallInt(i)) => u64::try_from(i).ok(),
+            InputValue::Scalar(WundergraphScalarValue::Int(i)) => u64::try_from(i).ok(),
+            InputValue::Scalar(WundergraphScalarValue::BigInt(i)) => u64::try_from(i).ok(),
+            _ => None,
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        match value {
+            ScalarToken::String(v) | ScalarToken::Int(v) => {
+                v.parse::<u64>()
+                    .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+                    .map(|i| WundergraphScalarValue::String(i.to_string()))
+            }
+            ScalarToken::Float(_) => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+        }
+    }
+});
@@ -0,0 +1,275 @@
+//! This module contains helper types and functions to provide GraphQL
+//! subscriptions for changes of wundergraph entities
+//!
+//! Subscriptions are fed by change events, reporting the table name and the
+//! primary key of each inserted, updated or deleted row. Those events are
+//! provided by a [`ChangeSource`](trait.ChangeSource.html):
+//!
+//! * [`PgNotifySource`](struct.PgNotifySource.html) (requires the `pg_notify`
+//!   feature) listens for notifications send by triggers calling `pg_notify`.
+//!   Use [`pg_notify_trigger`](fn.pg_notify_trigger.html) to create those
+//!   triggers.
+//! * [`PollingSource`](struct.PollingSource.html) polls a change log table
+//!   filled by triggers. This works for any backend supported by diesel,
+//!   for SQLite use [`SQLITE_CHANGE_LOG_TABLE`](constant.SQLITE_CHANGE_LOG_TABLE.html)
+//!   and [`sqlite_change_log_triggers`](fn.sqlite_change_log_triggers.html) to
+//!   setup the table and the corresponding triggers.
+//!
+//! The subscription object is declared using `subscription_object!`, providing
+//! a `{Entity}Changed(filter: ...)` field for each registered entity. A
+//! [`SubscriptionStream`](struct.SubscriptionStream.html) executes a given
+//! GraphQL document once for each change event. Each execution reloads the
+//! changed row through `LoadingHandler`, so the row is `null` if it does not
+//! match the given filter or if it was deleted. Events for entities not
+//! requested by the document are skipped.
+//!
+//! There is no incremental evaluation: the whole document is parsed,
+//! validated and executed again for each relevant event, including all
+//! nested fields. Fields for entities other than the changed one resolve to
+//! `null`. Keep subscription documents small, as each change of a
+//! subscribed table costs at least one database query per requested entity
+//! and nesting level.
+//!
+//! Juniper does not support a dedicated subscription root type, therefore
+//! the subscription object is used as query root of a separate schema and
+//! documents are written as queries:
+//!
+//! ```graphql
+//! {
+//!     HeroChanged(filter: {species: {name: {eq: "Human"}}}) {
+//!         heroName
+//!     }
+//! }
+//! ```
+
+use crate::error::{Result, WundergraphError};
+use crate::scalar::WundergraphScalarValue;
+use juniper::{EmptyMutation, ExecutionError, GraphQLType, InputValue, RootNode, Value, Variables};
+use std::cell::Cell;
+use std::fmt::{self, Debug};
+
+#[cfg(feature = "pg_notify")]
+mod pg_notify;
+mod polling;
+
+#[cfg(feature = "pg_notify")]
+pub use self::pg_notify::{pg_notify_trigger, PgNotifySource, CHANGE_CHANNEL};
+pub use self::polling::{sqlite_change_log_triggers, PollingSource, SQLITE_CHANGE_LOG_TABLE};
+
+/// Quote an identifier used in the statements creating change triggers
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Quote a string literal used in the statements creating change triggers
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// A schema using a subscription object, declared via
+/// `subscription_object!`, as query root
+pub type SubscriptionSchema<Q, Ctx> =
+    RootNode<'static, Q, EmptyMutation<SubscriptionContext<Ctx>>, WundergraphScalarValue>;
+
+/// A change of a single row
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeEvent {
+    /// The name of the changed table
+    pub table: String,
+    /// The primary key of the changed row, given as
+    /// object mapping column names to values
+    pub key: InputValue<WundergraphScalarValue>,
+}
+
+impl ChangeEvent {
+    /// Parse a change event from a json payload like
+    /// `{"table": "heros", "key": {"id": 1}}`
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        let invalid = || WundergraphError::InvalidChangeEvent {
+            payload: payload.to_owned(),
+        };
+        let value = serde_json::from_str::<InputValue<WundergraphScalarValue>>(payload)
+            .map_err(|_| invalid())?;
+        let table = value
+            .to_object_value()
+            .and_then(|o| {
+                o.get("table")
+                    .and_then(|t| t.as_scalar_value::<String>())
+                    .cloned()
+            })
+            .ok_or_else(invalid)?;
+        let key = match value {
+            InputValue::Object(o) => o
+                .into_iter()
+                .find(|(name, _)| name.item == "key")
+                .map(|(_, key)| key.item),
+            _ => None,
+        }
+        .ok_or_else(invalid)?;
+        Ok(Self { table, key })
+    }
+}
+
+/// A source of change events
+pub trait ChangeSource {
+    /// Block until the next change event is available
+    ///
+    /// Returns `None` if the source is closed
+    fn next_event(&mut self) -> Result<Option<ChangeEvent>>;
+}
+
+impl<I> ChangeSource for I
+where
+    I: Iterator<Item = ChangeEvent>,
+{
+    fn next_event(&mut self) -> Result<Option<ChangeEvent>> {
+        Ok(self.next())
+    }
+}
+
+/// The context used to execute a subscription document for a given
+/// change event
+pub struct SubscriptionContext<Ctx> {
+    context: Ctx,
+    event: ChangeEvent,
+    matched: Cell<bool>,
+}
+
+impl<Ctx> SubscriptionContext<Ctx> {
+    /// Create a new subscription context wrapping the given
+    /// wundergraph context
+    pub fn new(context: Ctx, event: ChangeEvent) -> Self {
+        Self {
+            context,
+            event,
+            matched: Cell::new(false),
+        }
+    }
+
+    /// The wrapped wundergraph context
+    pub fn context(&self) -> &Ctx {
+        &self.context
+    }
+
+    /// The change event the subscription document is executed for
+    pub fn event(&self) -> &ChangeEvent {
+        &self.event
+    }
+
+    /// Mark the current event as relevant for the executed document
+    #[doc(hidden)]
+    pub fn mark_matched(&self) {
+        self.matched.set(true);
+    }
+
+    /// Check if any field of the executed document requested
+    /// the current event
+    pub fn matched(&self) -> bool {
+        self.matched.get()
+    }
+}
+
+impl<Ctx> Debug for SubscriptionContext<Ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubscriptionContext")
+            .field("event", &self.event)
+            .field("matched", &self.matched)
+            .finish()
+    }
+}
+
+impl<Ctx> juniper::Context for SubscriptionContext<Ctx> {}
+
+/// An iterator executing a subscription document once for each
+/// relevant change event
+///
+/// Each item contains the result of a single execution, as returned
+/// by `juniper::execute`. The complete document is executed again for
+/// each event. A new context is requested from the given context factory
+/// for each execution.
+pub struct SubscriptionStream<'a, Q, Ctx, S, F>
+where
+    Q: GraphQLType<WundergraphScalarValue, Context = SubscriptionContext<Ctx>>,
+{
+    schema: &'a SubscriptionSchema<Q, Ctx>,
+    document: &'a str,
+    operation_name: Option<&'a str>,
+    variables: Variables<WundergraphScalarValue>,
+    source: S,
+    context: F,
+}
+
+impl<'a, Q, Ctx, S, F> SubscriptionStream<'a, Q, Ctx, S, F>
+where
+    Q: GraphQLType<WundergraphScalarValue, Context = SubscriptionContext<Ctx>>,
+    S: ChangeSource,
+    F: FnMut() -> Ctx,
+{
+    /// Create a new subscription stream for the given document
+    pub fn new(
+        schema: &'a SubscriptionSchema<Q, Ctx>,
+        document: &'a str,
+        operation_name: Option<&'a str>,
+        variables: Variables<WundergraphScalarValue>,
+        source: S,
+        context: F,
+    ) -> Self {
+        Self {
+            schema,
+            document,
+            operation_name,
+            variables,
+            source,
+            context,
+        }
+    }
+}
+
+impl<'a, Q, Ctx, S, F> Debug for SubscriptionStream<'a, Q, Ctx, S, F>
+where
+    Q: GraphQLType<WundergraphScalarValue, Context = SubscriptionContext<Ctx>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubscriptionStream")
+            .field("document", &self.document)
+            .field("operation_name", &self.operation_name)
+            .field("variables", &self.variables)
+            .finish()
+    }
+}
+
+impl<'a, Q, Ctx, S, F> Iterator for SubscriptionStream<'a, Q, Ctx, S, F>
+where
+    Q: GraphQLType<WundergraphScalarValue, Context = SubscriptionContext<Ctx>>,
+    S: ChangeSource,
+    F: FnMut() -> Ctx,
+{
+    type Item = Result<(
+        Value<WundergraphScalarValue>,
+        Vec<ExecutionError<WundergraphScalarValue>>,
+    )>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let event = match self.source.next_event() {
+                Ok(Some(event)) => event,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            };
+            let ctx = SubscriptionContext::new((self.context)(), event);
+            let res = juniper::execute(
+                self.document,
+                self.operation_name,
+                self.schema,
+                &self.variables,
+                &ctx,
+            )
+            .map_err(|e| WundergraphError::InvalidSubscription {
+                message: format!("{:?}", e),
+            });
+            if res.is_err() || ctx.matched() {
+                return Some(res);
+            }
+        }
+    }
+}
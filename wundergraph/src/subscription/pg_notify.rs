@@ -0,0 +1,91 @@
+use super::{quote_identifier, quote_literal, ChangeEvent, ChangeSource};
+use crate::error::Result;
+use fallible_iterator::FallibleIterator;
+use postgres_internal::{Client, NoTls};
+use std::fmt::{self, Debug};
+
+/// The notification channel used by triggers created
+/// via `pg_notify_trigger`
+pub const CHANGE_CHANNEL: &str = "wundergraph_changes";
+
+/// Create a trigger sending a notification for each change of the given
+/// table on PostgreSQL
+///
+/// The primary key of each changed row is send using the given key columns.
+/// Table and column names are quoted, so they need to match the names used
+/// in the database exactly. The table name must not contain a schema.
+pub fn pg_notify_trigger(table: &str, key_columns: &[&str]) -> String {
+    let key = key_columns
+        .iter()
+        .map(|column| {
+            format!(
+                "{}, changed.{}",
+                quote_literal(column),
+                quote_identifier(column)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "CREATE OR REPLACE FUNCTION {function}() RETURNS trigger AS $$
+DECLARE
+    changed RECORD;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        changed := OLD;
+    ELSE
+        changed := NEW;
+    END IF;
+    PERFORM pg_notify(
+        '{channel}',
+        json_build_object('table', TG_TABLE_NAME, 'key', json_build_object({key}))::text
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER {function}
+AFTER INSERT OR UPDATE OR DELETE ON {table}
+FOR EACH ROW EXECUTE PROCEDURE {function}();",
+        function = quote_identifier(&format!("wundergraph_notify_{}", table)),
+        table = quote_identifier(table),
+        channel = CHANGE_CHANNEL,
+        key = key,
+    )
+}
+
+/// A change source listening for notifications send by triggers
+/// created via `pg_notify_trigger`
+///
+/// Notifications are only delivered for committed transactions.
+pub struct PgNotifySource {
+    client: Client,
+}
+
+impl PgNotifySource {
+    /// Connect to the given database and start listening for changes
+    pub fn connect(database_url: &str) -> Result<Self> {
+        let mut client = Client::connect(database_url, NoTls)?;
+        client.batch_execute(&format!("LISTEN {}", CHANGE_CHANNEL))?;
+        Ok(Self { client })
+    }
+}
+
+impl ChangeSource for PgNotifySource {
+    fn next_event(&mut self) -> Result<Option<ChangeEvent>> {
+        let mut notifications = self.client.notifications();
+        let notification = notifications.blocking_iter().next()?;
+        match notification {
+            Some(notification) => ChangeEvent::from_payload(notification.payload()).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Debug for PgNotifySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgNotifySource")
+            .field("channel", &CHANGE_CHANNEL)
+            .finish()
+    }
+}
@@ -0,0 +1,166 @@
+use super::{quote_identifier, quote_literal, ChangeEvent, ChangeSource};
+use crate::error::Result;
+use diesel::dsl::{Desc, Filter, Gt, Limit, Order};
+use diesel::prelude::*;
+use diesel::query_dsl::LoadQuery;
+use std::collections::VecDeque;
+use std::fmt::{self, Debug};
+use std::thread;
+use std::time::Duration;
+
+table! {
+    /// The change log table polled by `PollingSource`
+    wundergraph_changes (id) {
+        id -> BigInt,
+        table_name -> Text,
+        payload -> Text,
+    }
+}
+
+/// Statement creating the change log table on SQLite
+pub const SQLITE_CHANGE_LOG_TABLE: &str = "CREATE TABLE IF NOT EXISTS wundergraph_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    table_name TEXT NOT NULL,
+    payload TEXT NOT NULL
+)";
+
+/// Create triggers recording all changes of the given table in the change
+/// log table on SQLite
+///
+/// The primary key of each changed row is recorded using the given
+/// key columns. Table and column names are quoted, so they need to match
+/// the names used in the database exactly.
+pub fn sqlite_change_log_triggers(table: &str, key_columns: &[&str]) -> String {
+    let payload = |row: &str| {
+        let key = key_columns
+            .iter()
+            .map(|column| {
+                format!(
+                    "{name} || CASE WHEN typeof({row}.{column}) IN ('integer', 'real') \
+                     THEN {row}.{column} \
+                     ELSE '\"' || replace(replace({row}.{column}, '\\', '\\\\'), '\"', '\\\"') || '\"' END",
+                    name = quote_literal(&format!("{}:", json_string(column))),
+                    column = quote_identifier(column),
+                    row = row,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" || ',' || ");
+        format!(
+            "{prefix} || {key} || '}}}}'",
+            prefix = quote_literal(&format!("{{\"table\":{},\"key\":{{", json_string(table))),
+            key = key
+        )
+    };
+    [("insert", "NEW"), ("update", "NEW"), ("delete", "OLD")]
+        .iter()
+        .map(|(operation, row)| {
+            format!(
+                "CREATE TRIGGER {name} \
+                 AFTER {op} ON {table} FOR EACH ROW BEGIN \
+                 INSERT INTO wundergraph_changes(table_name, payload) VALUES ({table_name}, {payload}); \
+                 END;",
+                name = quote_identifier(&format!("wundergraph_changes_{}_{}", table, operation)),
+                table = quote_identifier(table),
+                table_name = quote_literal(table),
+                op = operation.to_uppercase(),
+                payload = payload(row),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Encode the given value as JSON string
+fn json_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// A change source polling the change log table
+///
+/// Only changes recorded after creating the source are reported. Already
+/// reported entries are not removed from the change log table, so it
+/// should be cleaned up periodically.
+pub struct PollingSource<Conn> {
+    conn: Conn,
+    interval: Duration,
+    last_id: i64,
+    pending: VecDeque<ChangeEvent>,
+}
+
+type ChangeQuery = Order<
+    Filter<wundergraph_changes::table, Gt<wundergraph_changes::id, i64>>,
+    wundergraph_changes::id,
+>;
+type LatestChangeQuery = Limit<Order<wundergraph_changes::table, Desc<wundergraph_changes::id>>>;
+
+impl<Conn> PollingSource<Conn>
+where
+    Conn: Connection,
+    ChangeQuery: LoadQuery<Conn, (i64, String, String)>,
+    LatestChangeQuery: LoadQuery<Conn, (i64, String, String)>,
+{
+    /// Create a new polling source, checking for new changes
+    /// using the given interval
+    pub fn new(conn: Conn, interval: Duration) -> Result<Self> {
+        // Skip all changes recorded before
+        let last_id = wundergraph_changes::table
+            .order(wundergraph_changes::id.desc())
+            .limit(1)
+            .load::<(i64, String, String)>(&conn)?
+            .first()
+            .map_or(0, |&(id, _, _)| id);
+        Ok(Self {
+            conn,
+            interval,
+            last_id,
+            pending: VecDeque::new(),
+        })
+    }
+}
+
+impl<Conn> PollingSource<Conn>
+where
+    Conn: Connection,
+    ChangeQuery: LoadQuery<Conn, (i64, String, String)>,
+{
+    fn load_changes(&self) -> Result<Vec<(i64, String, String)>> {
+        Ok(wundergraph_changes::table
+            .filter(wundergraph_changes::id.gt(self.last_id))
+            .order(wundergraph_changes::id)
+            .load(&self.conn)?)
+    }
+}
+
+impl<Conn> ChangeSource for PollingSource<Conn>
+where
+    Conn: Connection,
+    ChangeQuery: LoadQuery<Conn, (i64, String, String)>,
+{
+    fn next_event(&mut self) -> Result<Option<ChangeEvent>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(Some(event));
+            }
+            let changes = self.load_changes()?;
+            if changes.is_empty() {
+                thread::sleep(self.interval);
+                continue;
+            }
+            for (id, _table_name, payload) in changes {
+                self.last_id = id;
+                self.pending.push_back(ChangeEvent::from_payload(&payload)?);
+            }
+        }
+    }
+}
+
+impl<Conn> Debug for PollingSource<Conn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PollingSource")
+            .field("interval", &self.interval)
+            .field("last_id", &self.last_id)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
@@ -0,0 +1,76 @@
+use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
+use crate::query_builder::selection::filter::filter_value::FilterValue;
+use crate::query_builder::selection::filter::FilterOption;
+use crate::query_builder::types::{PlaceHolder, WundergraphValue};
+use crate::scalar::WundergraphScalarValue;
+use bigdecimal_internal::BigDecimal;
+use diesel::sql_types::{Nullable, Numeric};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{graphql_scalar, InputValue, LookAheadValue, ParseScalarResult, Value};
+
+// Decimal values are transferred as strings, because neither graphql nor
+// json numbers are able to represent arbitrary precision values.
+graphql_scalar!(BigDecimal as "Decimal" where Scalar = WundergraphScalarValue {
+    description: "An arbitrary precision decimal number, serialized as string"
+
+    resolve(&self) -> Value {
+        Value::scalar(self.to_string())
+    }
+
+    from_input_value(v: &InputValue) -> Option<BigDecimal> {
+        match *v {
+            InputValue::Scalar(WundergraphScalarValue::String(ref s)) => s.parse().ok(),
+            InputValue::Scalar(WundergraphScalarValue::SmallInt(i)) => Some(BigDecimal::from(i64::from(i))),
+            InputValue::Scalar(WundergraphScalarValue::Int(i)) => Some(BigDecimal::from(i64::from(i))),
+            InputValue::Scalar(WundergraphScalarValue::BigInt(i)) => Some(BigDecimal::from(i)),
+            _ => None,
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        match value {
+            ScalarToken::String(v) | ScalarToken::Int(v) | ScalarToken::Float(v) => {
+                v.parse::<BigDecimal>()
+                    .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+                    .map(|d| WundergraphScalarValue::String(d.to_string()))
+            }
+        }
+    }
+});
+
+impl Nameable for BigDecimal {
+    fn name() -> String {
+        String::from("Decimal")
+    }
+}
+
+impl FromLookAheadValue for BigDecimal {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        match *v {
+            LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) => s.parse().ok(),
+            LookAheadValue::Scalar(WundergraphScalarValue::SmallInt(ref i)) => {
+                Some(Self::from(i64::from(*i)))
+            }
+            LookAheadValue::Scalar(WundergraphScalarValue::Int(ref i)) => {
+                Some(Self::from(i64::from(*i)))
+            }
+            LookAheadValue::Scalar(WundergraphScalarValue::BigInt(ref i)) => Some(Self::from(*i)),
+            _ => None,
+        }
+    }
+}
+
+impl WundergraphValue for BigDecimal {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Numeric>;
+}
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for BigDecimal {
+    type Filter = FilterOption<Self, C>;
+}
+
+impl<C> FilterValue<C> for BigDecimal {
+    type RawValue = Self;
+    type AdditionalFilter = ();
+}
@@ -1,12 +1,25 @@
 use crate::juniper_ext::{FromLookAheadValue, Nameable};
+use crate::query_builder::selection::filter::date_part_filter::DatePartFilter;
 use crate::query_builder::selection::filter::filter_helper::AsColumnFilter;
 use crate::query_builder::selection::filter::filter_value::FilterValue;
 use crate::query_builder::selection::filter::FilterOption;
+#[cfg(feature = "postgres")]
+use crate::query_builder::types::placeholder::PlaceHolderMarker;
 use crate::query_builder::types::{PlaceHolder, WundergraphValue};
 use crate::scalar::WundergraphScalarValue;
-use chrono_internal::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
-use diesel::sql_types::{Date, Nullable, Timestamp};
-use juniper::{FromInputValue, LookAheadValue, ToInputValue};
+use chrono_internal::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+#[cfg(feature = "postgres")]
+use diesel::backend::Backend;
+#[cfg(feature = "postgres")]
+use diesel::deserialize::{self, FromSql};
+#[cfg(feature = "postgres")]
+use diesel::pg::Pg;
+use diesel::sql_types::{Date, Nullable, Time, Timestamp};
+use juniper::parser::{ParseError, ScalarToken, Token};
+use juniper::{
+    graphql_scalar, FromInputValue, InputValue, LookAheadValue, ParseScalarResult, ToInputValue,
+    Value,
+};
 
 impl From<NaiveDateTime> for WundergraphScalarValue {
     fn from(n: NaiveDateTime) -> Self {
@@ -28,14 +41,51 @@ where
         String::from("DateTime")
     }
 }
+
 impl Nameable for NaiveDate {
     fn name() -> String {
         String::from("Date")
     }
 }
 
+// Juniper only provides a `NaiveTime` scalar behind a feature flag
+// that is not generic over the scalar value, so we provide our own one
+graphql_scalar!(NaiveTime as "NaiveTime" where Scalar = WundergraphScalarValue {
+    description: "A time without timezone, serialized as `HH:MM:SS` \
+                  followed by optional fractional seconds"
+
+    resolve(&self) -> Value {
+        Value::scalar(self.format(TIME_FORMAT).to_string())
+    }
+
+    from_input_value(v: &InputValue) -> Option<NaiveTime> {
+        match *v {
+            InputValue::Scalar(WundergraphScalarValue::String(ref s)) => {
+                NaiveTime::parse_from_str(s, TIME_FORMAT).ok()
+            }
+            _ => None,
+        }
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, WundergraphScalarValue> {
+        match value {
+            ScalarToken::String(v) if NaiveTime::parse_from_str(v, TIME_FORMAT).is_ok() => {
+                Ok(WundergraphScalarValue::String(v.to_owned()))
+            }
+            _ => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+        }
+    }
+});
+
+impl Nameable for NaiveTime {
+    fn name() -> String {
+        String::from("NaiveTime")
+    }
+}
+
 static RFC3339_PARSE_FORMAT: &str = "%+";
 static RFC3339_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
+static TIME_FORMAT: &str = "%H:%M:%S%.f";
 
 impl FromLookAheadValue for NaiveDateTime {
     fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
@@ -77,6 +127,16 @@ impl FromLookAheadValue for NaiveDate {
     }
 }
 
+impl FromLookAheadValue for NaiveTime {
+    fn from_look_ahead(v: &LookAheadValue<'_, WundergraphScalarValue>) -> Option<Self> {
+        if let LookAheadValue::Scalar(WundergraphScalarValue::String(ref s)) = *v {
+            Self::parse_from_str(s, TIME_FORMAT).ok()
+        } else {
+            None
+        }
+    }
+}
+
 impl WundergraphValue for NaiveDateTime {
     type PlaceHolder = PlaceHolder<Self>;
     type SqlType = Nullable<Timestamp>;
@@ -88,14 +148,58 @@ impl WundergraphValue for DateTime<Utc> {
     type SqlType = Nullable<diesel::sql_types::Timestamptz>;
 }
 
+#[cfg(feature = "postgres")]
+impl WundergraphValue for DateTime<FixedOffset> {
+    type PlaceHolder = PlaceHolder<FixedOffsetDateTime>;
+    type SqlType = Nullable<diesel::sql_types::Timestamptz>;
+}
+
+/// Helper type used to load a `timestamptz` value as `DateTime<FixedOffset>`
+///
+/// Diesel only provides a `FromSql` impl for `DateTime<Utc>`, so we
+/// load the value as such. Postgres does not store the offset of a
+/// `timestamptz` value, therefore loaded values always use an offset
+/// of `+00:00`, independently of the offset used while inserting them.
+#[cfg(feature = "postgres")]
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedOffsetDateTime(DateTime<FixedOffset>);
+
+#[cfg(feature = "postgres")]
+impl FromSql<diesel::sql_types::Timestamptz, Pg> for FixedOffsetDateTime {
+    fn from_sql(bytes: Option<&<Pg as Backend>::RawValue>) -> deserialize::Result<Self> {
+        let d = <DateTime<Utc> as FromSql<diesel::sql_types::Timestamptz, Pg>>::from_sql(bytes)?;
+        Ok(Self(d.into()))
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl Into<Option<DateTime<FixedOffset>>> for PlaceHolder<FixedOffsetDateTime> {
+    fn into(self) -> Option<DateTime<FixedOffset>> {
+        self.into_inner().map(|d| d.0)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl Into<Option<Option<DateTime<FixedOffset>>>> for PlaceHolder<FixedOffsetDateTime> {
+    fn into(self) -> Option<Option<DateTime<FixedOffset>>> {
+        Some(self.into())
+    }
+}
+
 impl WundergraphValue for NaiveDate {
     type PlaceHolder = PlaceHolder<Self>;
     type SqlType = Nullable<Date>;
 }
 
+impl WundergraphValue for NaiveTime {
+    type PlaceHolder = PlaceHolder<Self>;
+    type SqlType = Nullable<Time>;
+}
+
 impl<C> FilterValue<C> for NaiveDateTime {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = DatePartFilter<C>;
 }
 
 impl<O, C> FilterValue<C> for DateTime<O>
@@ -106,10 +210,15 @@ where
         + FromLookAheadValue,
 {
     type RawValue = Self;
-    type AdditionalFilter = ();
+    type AdditionalFilter = DatePartFilter<C>;
 }
 
 impl<C> FilterValue<C> for NaiveDate {
+    type RawValue = Self;
+    type AdditionalFilter = DatePartFilter<C>;
+}
+
+impl<C> FilterValue<C> for NaiveTime {
     type RawValue = Self;
     type AdditionalFilter = ();
 }
@@ -122,6 +231,14 @@ impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for DateTime<Utc> {
     type Filter = FilterOption<Self, C>;
 }
 
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for DateTime<FixedOffset> {
+    type Filter = FilterOption<Self, C>;
+}
+
 impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for NaiveDate {
     type Filter = FilterOption<Self, C>;
 }
+
+impl<C, DB, Ctx> AsColumnFilter<C, DB, Ctx> for NaiveTime {
+    type Filter = FilterOption<Self, C>;
+}
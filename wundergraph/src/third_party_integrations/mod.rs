@@ -1,3 +1,5 @@
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "chrono")]
 mod chrono;
 #[cfg(feature = "uuid")]
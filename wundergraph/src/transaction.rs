@@ -0,0 +1,108 @@
+//! This module contains helpers to resolve GraphQL queries inside of a
+//! single database transaction
+//!
+//! A GraphQL query is resolved using several independent select statements,
+//! one for each level of the query. Concurrent writes could therefore lead to
+//! nested parts of a response that do not agree with each other. If
+//! [`WundergraphContext::read_snapshot`](../trait.WundergraphContext.html#method.read_snapshot)
+//! returns `true` each top level field of a query is resolved inside of a
+//! read only transaction, so all levels below that field see the same
+//! snapshot of the database. This happens independently of how the query
+//! is executed (`juniper::execute`, `GraphQLRequest::execute`, …).
+
+use crate::context::WundergraphContext;
+use crate::error::field_error;
+use crate::scalar::WundergraphScalarValue;
+use diesel::backend::UsesAnsiSavepointSyntax;
+use diesel::connection::AnsiTransactionManager;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::Connection;
+use juniper::ExecutionResult;
+
+/// A connection that is able to run a closure inside of a read only
+/// transaction, seeing a consistent snapshot of the database
+pub trait ReadSnapshot: Connection {
+    /// Run the given closure inside of a snapshot transaction
+    ///
+    /// If the connection is already inside of a transaction the
+    /// closure is run inside a nested transaction instead.
+    fn read_snapshot<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+        E: From<diesel::result::Error>;
+}
+
+#[cfg(feature = "postgres")]
+impl ReadSnapshot for diesel::PgConnection {
+    fn read_snapshot<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+        E: From<diesel::result::Error>,
+    {
+        use diesel::connection::TransactionManager;
+
+        let depth = <AnsiTransactionManager as TransactionManager<Self>>::get_transaction_depth(
+            self.transaction_manager(),
+        );
+        if depth > 0 {
+            // The isolation level could only be set for the outermost transaction
+            self.transaction(f)
+        } else {
+            self.build_transaction()
+                .repeatable_read()
+                .read_only()
+                .run(f)
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl ReadSnapshot for diesel::SqliteConnection {
+    fn read_snapshot<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+        E: From<diesel::result::Error>,
+    {
+        // A plain `BEGIN` starts a deferred transaction on sqlite,
+        // holding a read snapshot from the first select onwards
+        self.transaction(f)
+    }
+}
+
+impl<C> ReadSnapshot for PooledConnection<ConnectionManager<C>>
+where
+    C: ReadSnapshot<TransactionManager = AnsiTransactionManager> + Send + 'static,
+    C::Backend: UsesAnsiSavepointSyntax,
+{
+    fn read_snapshot<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+        E: From<diesel::result::Error>,
+    {
+        <C as ReadSnapshot>::read_snapshot(&**self, f)
+    }
+}
+
+/// Resolve a top level query field inside of a read snapshot, if the
+/// context requests one
+///
+/// Used by the query object generated by
+/// [`query_object!`](../macro.query_object.html), so every way of executing
+/// a query respects
+/// [`WundergraphContext::read_snapshot`](../trait.WundergraphContext.html#method.read_snapshot).
+/// Mutations never reach the query object, so they are executed as they are.
+#[doc(hidden)]
+pub fn resolve_in_snapshot<Ctx, F>(context: &Ctx, f: F) -> ExecutionResult<WundergraphScalarValue>
+where
+    Ctx: WundergraphContext,
+    Ctx::Connection: ReadSnapshot,
+    F: FnOnce() -> ExecutionResult<WundergraphScalarValue>,
+{
+    if !context.read_snapshot() {
+        return f();
+    }
+    <_ as ReadSnapshot>::read_snapshot(context.get_connection(), || {
+        Ok::<_, diesel::result::Error>(f())
+    })
+    .unwrap_or_else(|e| Err(field_error(e, context)))
+}
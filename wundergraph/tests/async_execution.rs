@@ -0,0 +1,106 @@
+use crate::helper::*;
+use futures::executor::{block_on, ThreadPool};
+use juniper::http::GraphQLRequest;
+use std::sync::Arc;
+use wundergraph::async_execution::execute_async;
+use wundergraph_example::MyContext;
+
+fn thread_pool() -> ThreadPool {
+    ThreadPool::builder()
+        .pool_size(1)
+        .create()
+        .expect("Failed to create thread pool")
+}
+
+#[test]
+fn execute_query_on_thread_pool() {
+    let (schema, pool) = get_example_schema();
+    let schema = Arc::new(schema);
+    let thread_pool = thread_pool();
+    let ctx = MyContext::new(pool.get().unwrap());
+    let request = GraphQLRequest::new(
+        String::from(
+            "
+{
+    Heros(order: [{column: id, direction: ASC}], limit: 2) {
+        heroName
+    }
+}
+",
+        ),
+        None,
+        None,
+    );
+
+    let res = block_on(execute_async(&thread_pool, schema, request, ctx));
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.into_body(), @r###"{
+  "data": {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker"
+      },
+      {
+        "heroName": "Darth Vader"
+      }
+    ]
+  }
+}"###
+    );
+}
+
+#[test]
+fn invalid_query_on_thread_pool() {
+    let (schema, pool) = get_example_schema();
+    let schema = Arc::new(schema);
+    let thread_pool = thread_pool();
+    let ctx = MyContext::new(pool.get().unwrap());
+    let request = GraphQLRequest::new(String::from("{ Heros { unknownField } }"), None, None);
+
+    let res = block_on(execute_async(&thread_pool, schema, request, ctx));
+
+    assert!(!res.is_ok());
+    assert!(res.body()["errors"].is_array());
+}
+
+#[test]
+fn execute_query_in_read_snapshot_on_thread_pool() {
+    let (schema, pool) = get_example_schema();
+    let schema = Arc::new(schema);
+    let thread_pool = thread_pool();
+    let ctx = MyContext::new(pool.get().unwrap()).with_read_snapshot();
+    let request = GraphQLRequest::new(
+        String::from(
+            "
+{
+    Hero(primaryKey: {id: 1}) {
+        heroName
+        species {
+            name
+        }
+    }
+}
+",
+        ),
+        None,
+        None,
+    );
+
+    let res = block_on(execute_async(&thread_pool, schema, request, ctx));
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.into_body(), @r###"{
+  "data": {
+    "Hero": {
+      "heroName": "Luke Skywalker",
+      "species": {
+        "name": "Human"
+      }
+    }
+  }
+}"###
+    );
+}
@@ -0,0 +1,907 @@
+use crate::helper::*;
+use wundergraph_example::MyContext;
+
+#[cfg(feature = "postgres")]
+#[test]
+fn decimal_round_trip() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    // More significant digits than a f64 is able to represent exactly
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateStarships(NewStarships: [
+        {name: "Executor", length: "12345678901234567890.123456789012345678901234567890"},
+        {name: "X-Wing", length: 13},
+        {name: "TIE Fighter", length: 7.24},
+    ]) {
+        name
+        length
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateStarships": [
+      {
+        "length": "12345678901234567890.123456789012345678901234567890",
+        "name": "Executor"
+      },
+      {
+        "length": "13",
+        "name": "X-Wing"
+      },
+      {
+        "length": "7.24",
+        "name": "TIE Fighter"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Starships(filter: {length: {eq_any: ["34.75", 13, 7.24]}}) {
+        name
+        length
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Starships": [
+      {
+        "length": "34.75",
+        "name": "Millennium Falcon"
+      },
+      {
+        "length": "13",
+        "name": "X-Wing"
+      },
+      {
+        "length": "7.24",
+        "name": "TIE Fighter"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+query($length: Decimal!) {
+    Starships(filter: {length: {eq: $length}}) {
+        name
+    }
+}
+"#,
+        &[(
+            "length",
+            serde_json::json!("12345678901234567890.123456789012345678901234567890"),
+        )],
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Starships": [
+      {
+        "name": "Executor"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn reject_invalid_decimal() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateStarship(NewStarship: {name: "Executor", length: "1.2.3"}) {
+        length
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Starships(filter: {length: {eq: "abc"}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn bytes_round_trip() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Starships(order: [{column: id, direction: ASC}]) {
+        name
+        registry
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Starships": [
+      {
+        "name": "Millennium Falcon",
+        "registry": "AAH+"
+      },
+      {
+        "name": "Death Star",
+        "registry": null
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateStarships(NewStarships: [
+        {name: "Executor", registry: "AAEC/v8="},
+        {name: "X-Wing", registry: "3q2+7w=="},
+    ]) {
+        name
+        registry
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateStarships": [
+      {
+        "name": "Executor",
+        "registry": "AAEC/v8="
+      },
+      {
+        "name": "X-Wing",
+        "registry": "3q2+7w=="
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Starships(filter: {registry: {eq: "AAEC/v8="}}) {
+        name
+        registry
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Starships": [
+      {
+        "name": "Executor",
+        "registry": "AAEC/v8="
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn reject_invalid_bytes() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    for invalid in &[r#""not base64!""#, r#""AA=EC""#, r#""A""#, "42"] {
+        let query = format!(
+            r#"
+{{
+    Starships(filter: {{registry: {{eq: {}}}}}) {{
+        name
+    }}
+}}
+"#,
+            invalid
+        );
+        let res = execute_query(&schema, &ctx, &query);
+        assert!(res.is_err());
+    }
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+query($registry: Bytes) {
+    Starships(filter: {registry: {eq: $registry}}) {
+        name
+    }
+}
+"#,
+        &[("registry", serde_json::json!("not base64!"))],
+    );
+    assert!(res.is_err());
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn array_operators() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateStarships(NewStarships: [
+        {name: "Executor"},
+        {name: "X-Wing", weapons: []},
+    ]) {
+        name
+        weapons
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateStarships": [
+      {
+        "name": "Executor",
+        "weapons": null
+      },
+      {
+        "name": "X-Wing",
+        "weapons": []
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    contains: Starships(filter: {weapons: {contains: ["laser cannon"]}}, order: [{column: id, direction: ASC}]) {
+        name
+    }
+    isContainedBy: Starships(filter: {weapons: {is_contained_by: ["laser cannon", "concussion missile", "ion cannon"]}}, order: [{column: id, direction: ASC}]) {
+        name
+    }
+    overlapsWith: Starships(filter: {weapons: {overlaps_with: ["superlaser", "ion cannon"]}}) {
+        name
+    }
+    anyEq: Starships(filter: {weapons: {any_eq: "concussion missile"}}) {
+        name
+    }
+    lengthEq: Starships(filter: {weapons: {length_eq: 0}}, order: [{column: id, direction: ASC}]) {
+        name
+    }
+    lengthGt: Starships(filter: {weapons: {length_gt: 2}}) {
+        name
+    }
+    lengthLt: Starships(filter: {weapons: {length_lt: 3}}, order: [{column: id, direction: ASC}]) {
+        name
+    }
+    combined: Starships(filter: {weapons: {overlaps_with: ["laser cannon"], length_lt: 3}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "anyEq": [
+      {
+        "name": "Millennium Falcon"
+      }
+    ],
+    "combined": [
+      {
+        "name": "Millennium Falcon"
+      }
+    ],
+    "contains": [
+      {
+        "name": "Millennium Falcon"
+      },
+      {
+        "name": "Death Star"
+      }
+    ],
+    "isContainedBy": [
+      {
+        "name": "Millennium Falcon"
+      },
+      {
+        "name": "X-Wing"
+      }
+    ],
+    "lengthEq": [
+      {
+        "name": "Executor"
+      },
+      {
+        "name": "X-Wing"
+      }
+    ],
+    "lengthGt": [
+      {
+        "name": "Death Star"
+      }
+    ],
+    "lengthLt": [
+      {
+        "name": "Millennium Falcon"
+      },
+      {
+        "name": "Executor"
+      },
+      {
+        "name": "X-Wing"
+      }
+    ],
+    "overlapsWith": [
+      {
+        "name": "Death Star"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn reject_invalid_array_filter() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Starships(filter: {weapons: {length_eq: "a"}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Starships(filter: {weapons: {contains: [1]}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn naive_time_round_trip() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateStarships(NewStarships: [
+        {name: "Executor", departure: "23:59:59.999"},
+        {name: "X-Wing", departure: "00:00:00"},
+    ]) {
+        name
+        departure
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateStarships": [
+      {
+        "departure": "23:59:59.999",
+        "name": "Executor"
+      },
+      {
+        "departure": "00:00:00",
+        "name": "X-Wing"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    all: Starships(order: [{column: id, direction: ASC}]) {
+        name
+        departure
+    }
+    eq: Starships(filter: {departure: {eq: "08:15:30.5"}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "all": [
+      {
+        "departure": "12:30:00",
+        "name": "Millennium Falcon"
+      },
+      {
+        "departure": "08:15:30.500",
+        "name": "Death Star"
+      },
+      {
+        "departure": "23:59:59.999",
+        "name": "Executor"
+      },
+      {
+        "departure": "00:00:00",
+        "name": "X-Wing"
+      }
+    ],
+    "eq": [
+      {
+        "name": "Death Star"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn reject_invalid_naive_time() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    for invalid in &[r#""25:00:00""#, r#""12:30""#, r#""noon""#, "1230"] {
+        let query = format!(
+            r#"
+{{
+    Starships(filter: {{departure: {{eq: {}}}}}) {{
+        name
+    }}
+}}
+"#,
+            invalid
+        );
+        let res = execute_query(&schema, &ctx, &query);
+        assert!(res.is_err());
+    }
+}
+
+#[test]
+fn date_part_filter() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    year: Starships(filter: {commissioned: {date_part: {year: {ge: 1980}}}}) {
+        name
+    }
+    monthAndDay: Starships(filter: {commissioned: {date_part: {month: {eq: 5}, day: {gt: 20, le: 25}}}}, order: [{column: id, direction: ASC}]) {
+        name
+    }
+    hour: Starships(filter: {commissioned: {date_part: {hour: {lt: 12}}}}) {
+        name
+    }
+    sunday: Starships(filter: {commissioned: {date_part: {weekday: {eq: 0}}}}) {
+        name
+    }
+    notSunday: Starships(filter: {commissioned: {date_part: {weekday: {not_eq: 0}}}}) {
+        name
+    }
+    combined: Starships(filter: {commissioned: {date_part: {year: {lt: 1980}, weekday: {eq: 3}}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "combined": [
+      {
+        "name": "Millennium Falcon"
+      }
+    ],
+    "hour": [
+      {
+        "name": "Millennium Falcon"
+      }
+    ],
+    "monthAndDay": [
+      {
+        "name": "Millennium Falcon"
+      },
+      {
+        "name": "Death Star"
+      }
+    ],
+    "notSunday": [
+      {
+        "name": "Millennium Falcon"
+      }
+    ],
+    "sunday": [
+      {
+        "name": "Death Star"
+      }
+    ],
+    "year": [
+      {
+        "name": "Death Star"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    Starships(filter: {commissioned: {date_part: {year: {eq: "1977"}}}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_err());
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn interval_round_trip() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateStarships(NewStarships: [
+        {name: "Executor", travelTime: "P1Y2M3DT4H5M6.5S"},
+        {name: "X-Wing", travelTime: "PT-90M"},
+        {name: "TIE Fighter", travelTime: "PT0.000001S"},
+    ]) {
+        name
+        travel_time
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateStarships": [
+      {
+        "name": "Executor",
+        "travel_time": "P1Y2M3DT4H5M6.5S"
+      },
+      {
+        "name": "X-Wing",
+        "travel_time": "PT-1H-30M"
+      },
+      {
+        "name": "TIE Fighter",
+        "travel_time": "PT0.000001S"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    all: Starships(order: [{column: id, direction: ASC}]) {
+        name
+        travel_time
+    }
+    eq: Starships(filter: {travel_time: {eq: "P2W"}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "all": [
+      {
+        "name": "Millennium Falcon",
+        "travel_time": "P3DT4H"
+      },
+      {
+        "name": "Death Star",
+        "travel_time": "P14D"
+      },
+      {
+        "name": "Executor",
+        "travel_time": "P1Y2M3DT4H5M6.5S"
+      },
+      {
+        "name": "X-Wing",
+        "travel_time": "PT-1H-30M"
+      },
+      {
+        "name": "TIE Fighter",
+        "travel_time": "PT0.000001S"
+      }
+    ],
+    "eq": [
+      {
+        "name": "Death Star"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn reject_invalid_interval() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    for invalid in &[
+        r#""""#,
+        r#""P""#,
+        r#""P1H""#,
+        r#""PT1D""#,
+        r#""1Y""#,
+        r#""P1.5D""#,
+        r#""P999999999Y""#,
+        "5",
+    ] {
+        let query = format!(
+            r#"
+{{
+    Starships(filter: {{travel_time: {{eq: {}}}}}) {{
+        name
+    }}
+}}
+"#,
+            invalid
+        );
+        let res = execute_query(&schema, &ctx, &query);
+        assert!(res.is_err());
+    }
+}
+
+#[test]
+fn unsigned_round_trip() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    all: Starships(order: [{column: id, direction: ASC}]) {
+        name
+        passengers
+        crew
+        cargo_capacity
+    }
+    passengers: Starships(filter: {passengers: {eq: 65535}}) {
+        name
+    }
+    crew: Starships(filter: {crew: {eq_any: [4, 342953]}}, order: [{column: id, direction: ASC}]) {
+        name
+    }
+    cargoCapacity: Starships(filter: {cargo_capacity: {eq: "9223372036854775807"}}) {
+        name
+    }
+    cargoCapacityInt: Starships(filter: {cargo_capacity: {not_eq: 100000}}) {
+        name
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "all": [
+      {
+        "cargo_capacity": "100000",
+        "crew": 4,
+        "name": "Millennium Falcon",
+        "passengers": 6
+      },
+      {
+        "cargo_capacity": "9223372036854775807",
+        "crew": 342953,
+        "name": "Death Star",
+        "passengers": 65535
+      }
+    ],
+    "cargoCapacity": [
+      {
+        "name": "Death Star"
+      }
+    ],
+    "cargoCapacityInt": [
+      {
+        "name": "Death Star"
+      }
+    ],
+    "crew": [
+      {
+        "name": "Millennium Falcon"
+      },
+      {
+        "name": "Death Star"
+      }
+    ],
+    "passengers": [
+      {
+        "name": "Death Star"
+      }
+    ]
+  },
+  []
+]"###
+    );
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        r#"
+query($capacity: UnsignedBigInt!) {
+    Starships(filter: {cargo_capacity: {eq: $capacity}}) {
+        name
+    }
+}
+"#,
+        &[("capacity", serde_json::json!("100000"))],
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Starships": [
+      {
+        "name": "Millennium Falcon"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn reject_invalid_unsigned() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    for (field, invalid) in &[
+        ("passengers", "-1"),
+        ("passengers", "65536"),
+        ("passengers", r#""6""#),
+        ("crew", "-1"),
+        ("crew", "4294967296"),
+        ("crew", "1.5"),
+        ("cargo_capacity", r#""-1""#),
+        ("cargo_capacity", r#""18446744073709551616""#),
+        ("cargo_capacity", "1.5"),
+    ] {
+        let query = format!(
+            r#"
+{{
+    Starships(filter: {{{}: {{eq: {}}}}}) {{
+        name
+    }}
+}}
+"#,
+            field, invalid
+        );
+        let res = execute_query(&schema, &ctx, &query);
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::helper::*;
+use juniper::Value;
+use wundergraph::error::{RedactionPolicy, WundergraphError};
+use wundergraph_example::MyContext;
+
+#[test]
+fn unique_violation_is_reported_with_code_and_columns() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateAppearsIn(NewAppearsIn: {heroId: 1, episode: NEWHOPE}) {
+        episode
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert!(res[0]["CreateAppearsIn"].is_null());
+    let extensions = &res[1][0]["extensions"];
+    assert_eq!(extensions["code"], "UNIQUE_VIOLATION");
+    assert_eq!(extensions["table"], "appears_in");
+    assert_eq!(
+        extensions["columns"],
+        serde_json::json!(["hero_id", "episode"])
+    );
+    assert!(extensions.get("details").is_none());
+}
+
+#[test]
+fn context_redaction_policy_is_used() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap()).with_redaction_policy(RedactionPolicy::CodeOnly);
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateAppearsIn(NewAppearsIn: {heroId: 1, episode: NEWHOPE}) {
+        episode
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(
+        res[1][0]["extensions"],
+        serde_json::json!({"code": "UNIQUE_VIOLATION"})
+    );
+}
+
+#[test]
+fn invalid_filter_is_reported_with_field_and_operator() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    // Valid for the `UnsignedBigInt` scalar, but too large for the
+    // signed column storing the value
+    let query = r#"
+{
+    Starships(filter: {cargo_capacity: {eq: "18446744073709551615"}}) {
+        name
+    }
+}
+"#;
+    let res = execute_query(&schema, &ctx, query);
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert!(res[0].is_null());
+    assert_eq!(res[1][0]["path"], serde_json::json!(["Starships"]));
+    assert_eq!(
+        res[1][0]["extensions"],
+        serde_json::json!({
+            "code": "INVALID_FILTER",
+            "field": "cargo_capacity",
+            "operator": "eq",
+        })
+    );
+
+    let ctx = ctx.with_redaction_policy(RedactionPolicy::CodeOnly);
+    let res = execute_query(&schema, &ctx, query);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.as_json()[1][0]["extensions"],
+        serde_json::json!({"code": "INVALID_FILTER"})
+    );
+}
+
+#[test]
+fn redaction_policy_controls_exposed_details() {
+    let error = || WundergraphError::InvalidFilter {
+        field: String::from("order"),
+        operator: String::from("column"),
+    };
+
+    let error_with_details = error().to_field_error(RedactionPolicy::HideInternal);
+    let extensions = error_with_details.extensions().as_object_value().unwrap();
+    assert_eq!(
+        extensions.get_field_value("code"),
+        Some(&Value::scalar("INVALID_FILTER"))
+    );
+    assert_eq!(
+        extensions.get_field_value("field"),
+        Some(&Value::scalar("order"))
+    );
+    assert_eq!(
+        extensions.get_field_value("operator"),
+        Some(&Value::scalar("column"))
+    );
+
+    let redacted = error().to_field_error(RedactionPolicy::CodeOnly);
+    let extensions = redacted.extensions().as_object_value().unwrap();
+    assert_eq!(extensions.field_count(), 1);
+    assert_eq!(
+        extensions.get_field_value("code"),
+        Some(&Value::scalar("INVALID_FILTER"))
+    );
+}
@@ -0,0 +1,88 @@
+use crate::helper::*;
+use serde_json::json;
+use wundergraph_example::MyContext;
+
+#[test]
+fn query_service_sdl() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    _service {
+        sdl
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    let res = res.as_json();
+    let sdl = res[0]["_service"]["sdl"].as_str().unwrap();
+    assert!(sdl.contains("type Hero @key(fields: \"id\") {\n"));
+    assert!(sdl.contains("type Species @key(fields: \"id\") {\n"));
+    assert!(sdl.contains("type Query {\n"));
+    assert!(sdl.contains("type Mutation {\n"));
+    assert!(!sdl.contains("_entities"));
+    assert!(!sdl.contains("_Any"));
+}
+
+#[test]
+fn query_entities() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query_with_variables(
+        &schema,
+        &ctx,
+        "
+query($representations: [_Any!]!) {
+    _entities(representations: $representations) {
+        __typename
+        ... on Hero {
+            heroName
+        }
+        ... on Species {
+            name
+        }
+    }
+}
+",
+        &[(
+            "representations",
+            json!([
+                {"__typename": "Hero", "id": 1},
+                {"__typename": "Species", "id": 2},
+                {"__typename": "Hero", "id": 42},
+                {"__typename": "Hero", "id": 3},
+            ]),
+        )],
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "_entities": [
+      {
+        "__typename": "Hero",
+        "heroName": "Luke Skywalker"
+      },
+      {
+        "__typename": "Species",
+        "name": "Robot"
+      },
+      null,
+      {
+        "__typename": "Hero",
+        "heroName": "Han Solo"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
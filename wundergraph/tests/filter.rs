@@ -0,0 +1,49 @@
+use juniper::LookAheadValue;
+use wundergraph::error::WundergraphError;
+use wundergraph::query_builder::selection::filter::{field_filter_from_look_ahead, FilterOption};
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph_example::heros;
+
+type IdFilter = FilterOption<i32, heros::id>;
+
+#[test]
+fn valid_filter_is_built() {
+    let id = WundergraphScalarValue::Int(1);
+    let filter = LookAheadValue::Object(vec![
+        ("eq", LookAheadValue::Scalar(&id)),
+        ("not_eq", LookAheadValue::Null),
+    ]);
+    let filter = field_filter_from_look_ahead::<IdFilter>(&filter, "id");
+    assert!(filter.unwrap().is_some());
+
+    let filter = field_filter_from_look_ahead::<IdFilter>(&LookAheadValue::Null, "id");
+    assert!(filter.unwrap().is_none());
+}
+
+#[test]
+fn unknown_operators_are_ignored() {
+    let value = WundergraphScalarValue::String(String::from("abc"));
+    let filter = LookAheadValue::Object(vec![("like", LookAheadValue::Scalar(&value))]);
+    let filter = field_filter_from_look_ahead::<IdFilter>(&filter, "id");
+    assert!(filter.unwrap().is_some());
+}
+
+#[test]
+fn invalid_operator_is_reported_with_field() {
+    let id = WundergraphScalarValue::Int(1);
+    let invalid = WundergraphScalarValue::String(String::from("abc"));
+    let filter = LookAheadValue::Object(vec![
+        ("eq", LookAheadValue::Scalar(&id)),
+        (
+            "eq_any",
+            LookAheadValue::List(vec![LookAheadValue::Scalar(&invalid)]),
+        ),
+    ]);
+    match field_filter_from_look_ahead::<IdFilter>(&filter, "id") {
+        Err(WundergraphError::InvalidFilter { field, operator }) => {
+            assert_eq!(field, "id");
+            assert_eq!(operator, "eq_any");
+        }
+        _ => panic!("Expected an invalid filter error"),
+    }
+}
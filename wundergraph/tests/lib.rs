@@ -13,13 +13,26 @@ extern crate insta;
 mod helper;
 
 mod alias;
+mod column_types;
+mod errors;
+mod filter;
+#[cfg(feature = "async")]
+mod async_execution;
+#[cfg(feature = "federation")]
+mod federation;
 mod limit_offset;
 mod mutations;
 mod order;
 mod query;
 mod query_nested;
+#[cfg(feature = "relay")]
+mod relay;
 mod simple;
+#[cfg(feature = "subscriptions")]
+mod subscription;
+mod transaction;
 mod type_checking;
+mod unique_key;
 
 #[cfg(feature = "postgres")]
 type DbConnection = diesel::pg::PgConnection;
@@ -0,0 +1,139 @@
+use crate::helper::*;
+use juniper::RootNode;
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph_example::mutations::{NewAppearsIn, NewSpecies};
+use wundergraph_example::{AppearsIn, MyContext, Query, Species};
+
+wundergraph::mutation_object! {
+    #[wundergraph(atomic = true)]
+    AtomicMutation {
+        Species(insert = NewSpecies,),
+        AppearsIn(insert = NewAppearsIn,),
+    }
+}
+
+const MUTATION: &str = r#"
+mutation {
+    CreateSpecies(NewSpecies: {name: "Wookie"}) {
+        name
+    }
+    CreateAppearsIn(NewAppearsIn: {heroId: 1, episode: NEWHOPE}) {
+        episode
+    }
+}
+"#;
+
+const SPECIES: &str = "
+{
+    Speciess {
+        name
+    }
+}
+";
+
+#[test]
+fn failed_field_rolls_back_atomic_mutation() {
+    let (_, pool) = get_example_schema();
+    let schema = RootNode::<_, _, WundergraphScalarValue>::new(
+        Query::<MyContext<crate::DbConnection>>::default(),
+        AtomicMutation::<MyContext<crate::DbConnection>>::default(),
+    );
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(&schema, &ctx, MUTATION);
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert!(res[0].is_null());
+    assert_eq!(res[1].as_array().map(Vec::len), Some(2));
+
+    let res = execute_query(&schema, &ctx, SPECIES);
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Human"
+      },
+      {
+        "name": "Robot"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn successful_atomic_mutation() {
+    let (_, pool) = get_example_schema();
+    let schema = RootNode::<_, _, WundergraphScalarValue>::new(
+        Query::<MyContext<crate::DbConnection>>::default(),
+        AtomicMutation::<MyContext<crate::DbConnection>>::default(),
+    );
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateSpecies(NewSpecies: {name: "Wookie"}) {
+        name
+    }
+    CreateAppearsIn(NewAppearsIn: {heroId: 5, episode: NEWHOPE}) {
+        episode
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateAppearsIn": {
+      "episode": "NEWHOPE"
+    },
+    "CreateSpecies": {
+      "name": "Wookie"
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn failed_field_keeps_other_fields_without_atomic_mode() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(&schema, &ctx, MUTATION);
+    assert!(res.is_ok());
+    let res = res.as_json();
+    assert_eq!(res[0]["CreateSpecies"]["name"], "Wookie");
+    assert!(res[0]["CreateAppearsIn"].is_null());
+    assert_eq!(res[1].as_array().map(Vec::len), Some(1));
+
+    let res = execute_query(&schema, &ctx, SPECIES);
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Speciess": [
+      {
+        "name": "Human"
+      },
+      {
+        "name": "Robot"
+      },
+      {
+        "name": "Wookie"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
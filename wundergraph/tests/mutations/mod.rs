@@ -1,3 +1,5 @@
+mod atomic;
 mod create;
 mod delete;
+mod payload;
 mod update;
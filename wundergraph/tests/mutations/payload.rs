@@ -0,0 +1,127 @@
+use crate::helper::*;
+use juniper::RootNode;
+use wundergraph::scalar::WundergraphScalarValue;
+use wundergraph_example::mutations::{NewAppearsIn, NewSpecies, SpeciesChangeset};
+use wundergraph_example::{AppearsIn, MyContext, Query, Species};
+
+wundergraph::mutation_object! {
+    #[wundergraph(payload = true)]
+    PayloadMutation {
+        Species(insert = NewSpecies, update = SpeciesChangeset,),
+        AppearsIn(insert = NewAppearsIn,),
+    }
+}
+
+fn payload_schema() -> RootNode<
+    'static,
+    Query<MyContext<crate::DbConnection>>,
+    PayloadMutation<MyContext<crate::DbConnection>>,
+    WundergraphScalarValue,
+> {
+    RootNode::new(Query::default(), PayloadMutation::default())
+}
+
+#[test]
+fn constraint_violation_is_returned_as_user_error() {
+    let (_, pool) = get_example_schema();
+    let schema = payload_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateAppearsIn(NewAppearsIn: {heroId: 1, episode: NEWHOPE}) {
+        __typename
+        appearsIn {
+            episode
+        }
+        errors {
+            field
+            code
+            message
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateAppearsIn": {
+      "__typename": "CreateAppearsInPayload",
+      "appearsIn": null,
+      "errors": [
+        {
+          "code": "UNIQUE_VIOLATION",
+          "field": "heroId",
+          "message": "Violated unique constraint"
+        },
+        {
+          "code": "UNIQUE_VIOLATION",
+          "field": "episode",
+          "message": "Violated unique constraint"
+        }
+      ]
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn successful_mutations_return_entity_in_payload() {
+    let (_, pool) = get_example_schema();
+    let schema = payload_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+mutation {
+    CreateSpecies(NewSpecies: {name: "Wookie"}) {
+        species {
+            name
+        }
+        errors {
+            code
+        }
+    }
+    UpdateSpecies(UpdateSpecies: {id: 2, name: "Droid"}) {
+        species {
+            id
+            name
+        }
+        errors {
+            code
+        }
+    }
+}
+"#,
+    );
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "CreateSpecies": {
+      "errors": [],
+      "species": {
+        "name": "Wookie"
+      }
+    },
+    "UpdateSpecies": {
+      "errors": [],
+      "species": {
+        "id": 2,
+        "name": "Droid"
+      }
+    }
+  },
+  []
+]"###
+    );
+}
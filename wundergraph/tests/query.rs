@@ -438,3 +438,42 @@ fn filter_on_field() {
 ]"###
     );
 }
+
+#[test]
+fn query_by_primary_keys() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    HerosByIds(ids: ["3", "42", 1, "3"]) {
+        heroName
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "HerosByIds": [
+      {
+        "heroName": "Han Solo"
+      },
+      null,
+      {
+        "heroName": "Luke Skywalker"
+      },
+      {
+        "heroName": "Han Solo"
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
@@ -0,0 +1,121 @@
+use crate::helper::*;
+use wundergraph_example::MyContext;
+
+#[test]
+fn query_global_ids() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        "
+{
+    Heros(limit: 2) {
+        id
+        nodeId
+        heroName
+    }
+}
+",
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker",
+        "id": 1,
+        "nodeId": "SGVybzp7ImlkIjoxfQ=="
+      },
+      {
+        "heroName": "Darth Vader",
+        "id": 2,
+        "nodeId": "SGVybzp7ImlkIjoyfQ=="
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_node() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    node(id: "SGVybzp7ImlkIjoxfQ==") {
+        nodeId
+        ... on Hero {
+            heroName
+        }
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "node": {
+      "heroName": "Luke Skywalker",
+      "nodeId": "SGVybzp7ImlkIjoxfQ=="
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_nodes() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    nodes(ids: ["U3BlY2llczp7ImlkIjoxfQ==", "invalid", "SGVybzp7ImlkIjoyfQ=="]) {
+        nodeId
+        ... on Species {
+            name
+        }
+        ... on Hero {
+            heroName
+        }
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "nodes": [
+      {
+        "name": "Human",
+        "nodeId": "U3BlY2llczp7ImlkIjoxfQ=="
+      },
+      null,
+      {
+        "heroName": "Darth Vader",
+        "nodeId": "SGVybzp7ImlkIjoyfQ=="
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
@@ -0,0 +1,177 @@
+use crate::helper::*;
+use juniper::{EmptyMutation, FromInputValue, InputValue, Variables};
+use wundergraph::subscription::{ChangeEvent, SubscriptionSchema, SubscriptionStream};
+use wundergraph_example::{Hero, MyContext, Species};
+
+wundergraph::subscription_object! {
+    Subscription {
+        Hero,
+        Species,
+    }
+}
+
+fn hero_changed(id: i32) -> ChangeEvent {
+    ChangeEvent {
+        table: String::from("heros"),
+        key: InputValue::object(vec![("id", InputValue::scalar(id))].into_iter().collect()),
+    }
+}
+
+#[test]
+fn parse_change_event() {
+    let event = ChangeEvent::from_payload(r#"{"table": "heros", "key": {"id": 1}}"#).unwrap();
+    assert_eq!(event.table, "heros");
+    let id = event
+        .key
+        .to_object_value()
+        .and_then(|key| key.get("id").and_then(|id| i32::from_input_value(id)));
+    assert_eq!(id, Some(1));
+
+    assert!(ChangeEvent::from_payload(r#"{"key": {"id": 1}}"#).is_err());
+    assert!(ChangeEvent::from_payload("heros").is_err());
+}
+
+#[test]
+fn subscribe_to_entity_changes() {
+    let (_, pool) = get_example_schema();
+    let schema = SubscriptionSchema::new(Subscription::default(), EmptyMutation::new());
+    let events = vec![
+        hero_changed(1),
+        ChangeEvent {
+            table: String::from("species"),
+            key: InputValue::object(vec![("id", InputValue::scalar(1))].into_iter().collect()),
+        },
+        hero_changed(42),
+        hero_changed(3),
+    ];
+
+    let stream = SubscriptionStream::new(
+        &schema,
+        "
+{
+    HeroChanged {
+        heroName
+    }
+}
+",
+        None,
+        Variables::new(),
+        events.into_iter(),
+        || MyContext::new(pool.get().unwrap()),
+    );
+    let res = stream
+        .map(|r| serde_json::to_value(r.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_json_snapshot!(
+        res, @r###"[
+  [
+    {
+      "HeroChanged": {
+        "heroName": "Luke Skywalker"
+      }
+    },
+    []
+  ],
+  [
+    {
+      "HeroChanged": null
+    },
+    []
+  ],
+  [
+    {
+      "HeroChanged": {
+        "heroName": "Han Solo"
+      }
+    },
+    []
+  ]
+]"###
+    );
+}
+
+#[test]
+fn subscribe_to_filtered_entity_changes() {
+    let (_, pool) = get_example_schema();
+    let schema = SubscriptionSchema::new(Subscription::default(), EmptyMutation::new());
+    let variables = vec![(String::from("name"), InputValue::scalar("Luke Skywalker"))]
+        .into_iter()
+        .collect::<Variables<_>>();
+
+    let stream = SubscriptionStream::new(
+        &schema,
+        "
+query($name: String!) {
+    HeroChanged(filter: {heroName: {eq: $name}}) {
+        id
+        heroName
+    }
+}
+",
+        None,
+        variables,
+        vec![hero_changed(1), hero_changed(3)].into_iter(),
+        || MyContext::new(pool.get().unwrap()),
+    );
+    let res = stream
+        .map(|r| serde_json::to_value(r.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_json_snapshot!(
+        res, @r###"[
+  [
+    {
+      "HeroChanged": {
+        "heroName": "Luke Skywalker",
+        "id": 1
+      }
+    },
+    []
+  ],
+  [
+    {
+      "HeroChanged": null
+    },
+    []
+  ]
+]"###
+    );
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn poll_changes_of_table_requiring_quotes() {
+    use diesel::connection::SimpleConnection;
+    use diesel::{Connection, SqliteConnection};
+    use std::time::Duration;
+    use wundergraph::subscription::{
+        sqlite_change_log_triggers, ChangeSource, PollingSource, SQLITE_CHANGE_LOG_TABLE,
+    };
+
+    let path = std::env::temp_dir().join("wundergraph_poll_quoted_table.db");
+    let _ = std::fs::remove_file(&path);
+    let conn = SqliteConnection::establish(path.to_str().unwrap()).unwrap();
+    conn.batch_execute(SQLITE_CHANGE_LOG_TABLE).unwrap();
+    conn.batch_execute(r#"CREATE TABLE "order ""items"""("key 'id" INTEGER PRIMARY KEY)"#)
+        .unwrap();
+    conn.batch_execute(&sqlite_change_log_triggers(
+        r#"order "items""#,
+        &["key 'id"],
+    ))
+    .unwrap();
+
+    let source_conn = SqliteConnection::establish(path.to_str().unwrap()).unwrap();
+    let mut source = PollingSource::new(source_conn, Duration::from_millis(1)).unwrap();
+    conn.batch_execute(r#"INSERT INTO "order ""items"""("key 'id") VALUES (42)"#)
+        .unwrap();
+
+    let event = source.next_event().unwrap().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(event.table, r#"order "items""#);
+    let id = event
+        .key
+        .to_object_value()
+        .and_then(|key| key.get("key 'id").and_then(|id| i32::from_input_value(id)));
+    assert_eq!(id, Some(42));
+}
@@ -0,0 +1,85 @@
+use crate::helper::*;
+use juniper::{execute, Variables};
+use wundergraph_example::MyContext;
+
+#[test]
+fn query_in_read_snapshot() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap()).with_read_snapshot();
+
+    let res = execute(
+        "
+query Heros {
+    Heros(limit: 2) {
+        heroName
+        species {
+            name
+        }
+    }
+}
+",
+        Some("Heros"),
+        &schema,
+        &Variables::new(),
+        &ctx,
+    );
+
+    assert_json_snapshot!(
+        serde_json::to_value(res.unwrap()).unwrap(), @r###"[
+  {
+    "Heros": [
+      {
+        "heroName": "Luke Skywalker",
+        "species": {
+          "name": "Human"
+        }
+      },
+      {
+        "heroName": "Darth Vader",
+        "species": {
+          "name": "Human"
+        }
+      }
+    ]
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn mutation_not_in_read_snapshot() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap()).with_read_snapshot();
+
+    let res = execute(
+        r#"
+query Heros($id: Int = 1) {
+    Hero(primaryKey: {id: $id}) {
+        heroName
+    }
+}
+
+mutation NewHero {
+    CreateHero(NewHero: {name: "Obi-Wan Kenobi", species: 1}) {
+        heroName
+    }
+}
+"#,
+        Some("NewHero"),
+        &schema,
+        &Variables::new(),
+        &ctx,
+    );
+
+    assert_json_snapshot!(
+        serde_json::to_value(res.unwrap()).unwrap(), @r###"[
+  {
+    "CreateHero": {
+      "heroName": "Obi-Wan Kenobi"
+    }
+  },
+  []
+]"###
+    );
+}
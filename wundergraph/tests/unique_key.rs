@@ -0,0 +1,63 @@
+use crate::helper::*;
+use wundergraph_example::MyContext;
+
+#[test]
+fn query_by_unique_key() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    SpeciesByName(name: "Robot") {
+        id
+        name
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "SpeciesByName": {
+      "id": 2,
+      "name": "Robot"
+    }
+  },
+  []
+]"###
+    );
+}
+
+#[test]
+fn query_by_unique_key_without_match() {
+    let (schema, pool) = get_example_schema();
+    let ctx = MyContext::new(pool.get().unwrap());
+
+    let res = execute_query(
+        &schema,
+        &ctx,
+        r#"
+{
+    SpeciesByName(name: "Wookiee") {
+        id
+        name
+    }
+}
+"#,
+    );
+
+    assert!(res.is_ok());
+    assert_json_snapshot!(
+        res.as_json(), @r###"[
+  {
+    "SpeciesByName": null
+  },
+  []
+]"###
+    );
+}
@@ -0,0 +1,603 @@
+use crate::config::Config;
+use crate::database::InferConnection;
+use crate::print_schema::{self, TableFilter};
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::Path;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    braced, parenthesized, Attribute, GenericArgument, Ident, Item, ItemMod, ItemUse, Lit, Meta,
+    MetaNameValue, PathArguments, Token, Type,
+};
+
+/// The parts of a database schema relevant for the generated code
+///
+/// Tables are identified by their (optionally schema qualified) name,
+/// columns by their sql name.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SchemaSnapshot {
+    #[serde(default)]
+    tables: BTreeMap<String, TableSnapshot>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TableSnapshot {
+    primary_key: Vec<String>,
+    /// Maps column names to the diesel sql type used in `table!`
+    #[serde(default)]
+    columns: BTreeMap<String, String>,
+    /// Maps foreign key columns to the referenced table
+    #[serde(default)]
+    foreign_keys: BTreeMap<String, String>,
+}
+
+/// A single difference between the expected and the actual schema
+///
+/// "Added" refers to something that exists in the database
+/// but not in the expected schema.
+#[derive(Debug, PartialEq)]
+pub enum Difference {
+    AddedTable(String),
+    RemovedTable(String),
+    ChangedPrimaryKey {
+        table: String,
+        expected: Vec<String>,
+        actual: Vec<String>,
+    },
+    AddedColumn {
+        table: String,
+        column: String,
+        sql_type: String,
+    },
+    RemovedColumn {
+        table: String,
+        column: String,
+    },
+    ChangedColumnType {
+        table: String,
+        column: String,
+        expected: String,
+        actual: String,
+    },
+    AddedForeignKey {
+        table: String,
+        column: String,
+        references: String,
+    },
+    RemovedForeignKey {
+        table: String,
+        column: String,
+        references: String,
+    },
+    ChangedForeignKey {
+        table: String,
+        column: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Difference::AddedTable(table) => write!(f, "+ table `{}`", table),
+            Difference::RemovedTable(table) => write!(f, "- table `{}`", table),
+            Difference::ChangedPrimaryKey {
+                table,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "~ primary key of `{}`: ({}) -> ({})",
+                table,
+                expected.join(", "),
+                actual.join(", ")
+            ),
+            Difference::AddedColumn {
+                table,
+                column,
+                sql_type,
+            } => write!(f, "+ column `{}.{}` of type `{}`", table, column, sql_type),
+            Difference::RemovedColumn { table, column } => {
+                write!(f, "- column `{}.{}`", table, column)
+            }
+            Difference::ChangedColumnType {
+                table,
+                column,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "~ column `{}.{}`: `{}` -> `{}`",
+                table, column, expected, actual
+            ),
+            Difference::AddedForeignKey {
+                table,
+                column,
+                references,
+            } => write!(
+                f,
+                "+ foreign key `{}.{}` referencing `{}`",
+                table, column, references
+            ),
+            Difference::RemovedForeignKey {
+                table,
+                column,
+                references,
+            } => write!(
+                f,
+                "- foreign key `{}.{}` referencing `{}`",
+                table, column, references
+            ),
+            Difference::ChangedForeignKey {
+                table,
+                column,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "~ foreign key `{}.{}`: referencing `{}` -> `{}`",
+                table, column, expected, actual
+            ),
+        }
+    }
+}
+
+impl SchemaSnapshot {
+    /// Introspect the database the same way `print-schema` does
+    pub fn from_database(
+        connection: &InferConnection,
+        schema_name: Option<&str>,
+        table_filter: &TableFilter,
+        config: &Config,
+    ) -> Result<Self, Box<dyn Error>> {
+        let (table_data, foreign_keys) =
+            print_schema::load_schema(connection, schema_name, table_filter, config)?;
+        let mut tables = BTreeMap::new();
+        for t in &table_data {
+            let table_config = config.table(&t.name);
+            let columns = t
+                .column_data
+                .iter()
+                .map(|c| {
+                    (
+                        c.sql_name.clone(),
+                        print_schema::column_sql_type(c, table_config),
+                    )
+                })
+                .collect();
+            tables.insert(
+                t.name.to_string(),
+                TableSnapshot {
+                    primary_key: t.primary_key.clone(),
+                    columns,
+                    foreign_keys: BTreeMap::new(),
+                },
+            );
+        }
+        for fk in foreign_keys {
+            if let Some(table) = tables.get_mut(&fk.child_table.to_string()) {
+                table
+                    .foreign_keys
+                    .insert(fk.foreign_key, fk.parent_table.to_string());
+            }
+        }
+        Ok(Self { tables })
+    }
+
+    /// Load a snapshot previously written by `save`
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(path)?;
+        toml::from_str(&content)
+            .map_err(|e| format!("Failed to parse snapshot `{}`: {}", path.display(), e).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Read the schema from code generated by `print-schema`
+    ///
+    /// The path could either point to a single file or to a directory
+    /// written using `--out-dir`
+    pub fn from_generated(path: &Path) -> Result<Self, Box<dyn Error>> {
+        if path.is_dir() {
+            let mut files = fs::read_dir(path)?
+                .map(|e| e.map(|e| e.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            files.retain(|p| p.extension().map_or(false, |e| e == "rs"));
+            files.sort();
+            let mut code = String::new();
+            for file in files {
+                code += &fs::read_to_string(file)?;
+                code.push('\n');
+            }
+            Self::from_generated_code(&code)
+        } else {
+            Self::from_generated_code(&fs::read_to_string(path)?)
+        }
+    }
+
+    fn from_generated_code(code: &str) -> Result<Self, Box<dyn Error>> {
+        let file = syn::parse_file(code)?;
+        let mut tables = BTreeMap::new();
+        // Foreign keys are given by the `HasMany` fields of the
+        // referenced entity
+        let mut foreign_keys = Vec::new();
+        collect_items(&file.items, &mut tables, &mut foreign_keys)?;
+        for (child, column, parent) in foreign_keys {
+            let parent = qualified_name(&tables, &parent)?;
+            let child = qualified_name(&tables, &child)?;
+            if let Some(table) = tables.get_mut(&child) {
+                table.foreign_keys.insert(column, parent);
+            }
+        }
+        Ok(Self { tables })
+    }
+
+    /// Compare the expected schema (`self`) with the actual one
+    pub fn diff(&self, actual: &Self) -> Vec<Difference> {
+        let mut ret = Vec::new();
+        for (name, expected_table) in &self.tables {
+            let actual_table = match actual.tables.get(name) {
+                Some(t) => t,
+                None => {
+                    ret.push(Difference::RemovedTable(name.clone()));
+                    continue;
+                }
+            };
+            if expected_table.primary_key != actual_table.primary_key {
+                ret.push(Difference::ChangedPrimaryKey {
+                    table: name.clone(),
+                    expected: expected_table.primary_key.clone(),
+                    actual: actual_table.primary_key.clone(),
+                });
+            }
+            diff_columns(name, expected_table, actual_table, &mut ret);
+            diff_foreign_keys(name, expected_table, actual_table, &mut ret);
+        }
+        for name in actual.tables.keys() {
+            if !self.tables.contains_key(name) {
+                ret.push(Difference::AddedTable(name.clone()));
+            }
+        }
+        ret
+    }
+}
+
+fn diff_columns(
+    table: &str,
+    expected: &TableSnapshot,
+    actual: &TableSnapshot,
+    out: &mut Vec<Difference>,
+) {
+    for (column, expected_type) in &expected.columns {
+        match actual.columns.get(column) {
+            None => out.push(Difference::RemovedColumn {
+                table: table.to_owned(),
+                column: column.clone(),
+            }),
+            Some(actual_type) if normalize_type(actual_type) != normalize_type(expected_type) => {
+                out.push(Difference::ChangedColumnType {
+                    table: table.to_owned(),
+                    column: column.clone(),
+                    expected: expected_type.clone(),
+                    actual: actual_type.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for (column, sql_type) in &actual.columns {
+        if !expected.columns.contains_key(column) {
+            out.push(Difference::AddedColumn {
+                table: table.to_owned(),
+                column: column.clone(),
+                sql_type: sql_type.clone(),
+            });
+        }
+    }
+}
+
+fn diff_foreign_keys(
+    table: &str,
+    expected: &TableSnapshot,
+    actual: &TableSnapshot,
+    out: &mut Vec<Difference>,
+) {
+    for (column, expected_parent) in &expected.foreign_keys {
+        match actual.foreign_keys.get(column) {
+            None => out.push(Difference::RemovedForeignKey {
+                table: table.to_owned(),
+                column: column.clone(),
+                references: expected_parent.clone(),
+            }),
+            Some(actual_parent) if actual_parent != expected_parent => {
+                out.push(Difference::ChangedForeignKey {
+                    table: table.to_owned(),
+                    column: column.clone(),
+                    expected: expected_parent.clone(),
+                    actual: actual_parent.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for (column, parent) in &actual.foreign_keys {
+        if !expected.foreign_keys.contains_key(column) {
+            out.push(Difference::AddedForeignKey {
+                table: table.to_owned(),
+                column: column.clone(),
+                references: parent.clone(),
+            });
+        }
+    }
+}
+
+fn normalize_type(sql_type: &str) -> String {
+    sql_type.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Collects all `table!` definitions and the foreign keys given by `HasMany`
+/// fields from the given items, including items of inline modules
+fn collect_items(
+    items: &[Item],
+    tables: &mut BTreeMap<String, TableSnapshot>,
+    foreign_keys: &mut Vec<(String, String, String)>,
+) -> Result<(), Box<dyn Error>> {
+    for item in items {
+        match item {
+            Item::Macro(m)
+                if m.mac
+                    .path
+                    .segments
+                    .last()
+                    .map_or(false, |s| s.ident == "table") =>
+            {
+                let table = m.mac.parse_body::<TableMacro>()?;
+                tables.insert(table.name, table.snapshot);
+            }
+            Item::Struct(s) => {
+                if let Some(parent) = string_attribute(&s.attrs, "table_name")? {
+                    for field in &s.fields {
+                        if let Some((child, column)) = has_many_column(&field.ty) {
+                            foreign_keys.push((child, column, parent.clone()));
+                        }
+                    }
+                }
+            }
+            Item::Mod(ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => collect_items(items, tables, foreign_keys)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Returns the child table and the foreign key column of a
+/// `HasMany<Child, child::column>` field
+fn has_many_column(ty: &Type) -> Option<(String, String)> {
+    let segment = match ty {
+        Type::Path(p) => p.path.segments.last()?,
+        _ => return None,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if segment.ident == "HasMany" => {
+            match args.args.iter().nth(1)? {
+                GenericArgument::Type(Type::Path(p)) => {
+                    let mut segments = p.path.segments.iter().rev();
+                    let column = segments.next()?.ident.to_string();
+                    let child = segments.next()?.ident.to_string();
+                    Some((child, column))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the value of an attribute like `#[name = "value"]`
+fn string_attribute(attrs: &[Attribute], name: &str) -> syn::Result<Option<String>> {
+    for attr in attrs.iter().filter(|a| a.path.is_ident(name)) {
+        if let Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(s), ..
+        }) = attr.parse_meta()?
+        {
+            return Ok(Some(s.value()));
+        }
+    }
+    Ok(None)
+}
+
+/// The content of a `table!` block
+struct TableMacro {
+    name: String,
+    snapshot: TableSnapshot,
+}
+
+impl Parse for TableMacro {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        while input.peek(Token![use]) {
+            input.parse::<ItemUse>()?;
+        }
+        input.call(Attribute::parse_outer)?;
+        let mut name = input.call(Ident::parse_any)?.to_string();
+        if input.peek(Token![.]) {
+            input.parse::<Token![.]>()?;
+            name = format!("{}.{}", name, input.call(Ident::parse_any)?);
+        }
+        let keys;
+        parenthesized!(keys in input);
+        let primary_key =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(&keys, Ident::parse_any)?
+                .into_iter()
+                .map(|k| k.to_string())
+                .collect();
+        let body;
+        braced!(body in input);
+        let columns = Punctuated::<TableColumn, Token![,]>::parse_terminated(&body)?
+            .into_iter()
+            .map(|c| (c.sql_name, c.sql_type))
+            .collect();
+        Ok(Self {
+            name,
+            snapshot: TableSnapshot {
+                primary_key,
+                columns,
+                foreign_keys: BTreeMap::new(),
+            },
+        })
+    }
+}
+
+/// A single column definition of a `table!` block
+struct TableColumn {
+    sql_name: String,
+    sql_type: String,
+}
+
+impl Parse for TableColumn {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let name = input.call(Ident::parse_any)?;
+        input.parse::<Token![->]>()?;
+        let sql_type = input.parse::<Type>()?;
+        Ok(Self {
+            sql_name: string_attribute(&attrs, "sql_name")?.unwrap_or_else(|| name.to_string()),
+            sql_type: normalize_type(&sql_type.to_token_stream().to_string()),
+        })
+    }
+}
+
+/// Returns the key of the table with the given unqualified name
+fn qualified_name(
+    tables: &BTreeMap<String, TableSnapshot>,
+    name: &str,
+) -> Result<String, Box<dyn Error>> {
+    tables
+        .keys()
+        .find(|t| *t == name || t.rsplit('.').next() == Some(name))
+        .cloned()
+        .ok_or_else(|| format!("No `table!` definition for `{}`", name).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNAPSHOT: &str = include_str!("print_schema/snapshots/tests__infer_schema.snap");
+
+    /// The generated code without the snapshot header
+    fn generated() -> &'static str {
+        SNAPSHOT.splitn(3, "---\n").nth(2).unwrap()
+    }
+
+    #[test]
+    fn parse_generated_code() {
+        let snapshot = SchemaSnapshot::from_generated_code(generated()).unwrap();
+        assert_eq!(
+            snapshot.tables.keys().collect::<Vec<_>>(),
+            vec![
+                "infer_test.comments",
+                "infer_test.posts",
+                "infer_test.users"
+            ]
+        );
+        let posts = &snapshot.tables["infer_test.posts"];
+        assert_eq!(posts.primary_key, vec!["id"]);
+        assert_eq!(posts.columns["content"], "Nullable<Text>");
+        assert_eq!(posts.foreign_keys["author"], "infer_test.users");
+        let comments = &snapshot.tables["infer_test.comments"];
+        assert_eq!(comments.foreign_keys["post"], "infer_test.posts");
+        assert_eq!(comments.foreign_keys["commenter"], "infer_test.users");
+        assert!(snapshot.tables["infer_test.users"].foreign_keys.is_empty());
+    }
+
+    #[test]
+    fn parse_documented_tables() {
+        let code = r#"
+pub mod schema {
+    table! {
+        /// Posts -> written by users
+        posts (id) {
+            /// The id -> primary key
+            id -> Int4,
+            #[sql_name = "type"]
+            /// A column named like a keyword
+            type_ -> Nullable<Array<Text>>,
+        }
+    }
+}
+"#;
+        let snapshot = SchemaSnapshot::from_generated_code(code).unwrap();
+        let posts = &snapshot.tables["posts"];
+        assert_eq!(posts.primary_key, vec!["id"]);
+        assert_eq!(posts.columns.len(), 2);
+        assert_eq!(posts.columns["id"], "Int4");
+        assert_eq!(posts.columns["type"], "Nullable<Array<Text>>");
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let snapshot = SchemaSnapshot::from_generated_code(generated()).unwrap();
+        let serialized = toml::to_string(&snapshot).unwrap();
+        assert_eq!(
+            toml::from_str::<SchemaSnapshot>(&serialized).unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn report_differences() {
+        let expected = SchemaSnapshot::from_generated_code(generated()).unwrap();
+        let mut actual = SchemaSnapshot::from_generated_code(generated()).unwrap();
+        assert!(expected.diff(&actual).is_empty());
+
+        actual.tables.remove("infer_test.comments");
+        actual.tables.insert(
+            String::from("infer_test.tags"),
+            TableSnapshot {
+                primary_key: vec![String::from("id")],
+                ..Default::default()
+            },
+        );
+        let posts = actual.tables.get_mut("infer_test.posts").unwrap();
+        posts.columns.remove("content");
+        posts
+            .columns
+            .insert(String::from("title"), String::from("Nullable<Text>"));
+        posts
+            .columns
+            .insert(String::from("published"), String::from("Bool"));
+        posts.foreign_keys.clear();
+
+        let diff = expected
+            .diff(&actual)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            diff,
+            vec![
+                "- table `infer_test.comments`",
+                "- column `infer_test.posts.content`",
+                "~ column `infer_test.posts.title`: `Text` -> `Nullable<Text>`",
+                "+ column `infer_test.posts.published` of type `Bool`",
+                "- foreign key `infer_test.posts.author` referencing `infer_test.users`",
+                "+ table `infer_test.tags`",
+            ]
+        );
+    }
+}
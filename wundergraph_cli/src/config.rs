@@ -0,0 +1,179 @@
+use crate::infer_schema_internals::TableName;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+
+const DEFAULT_CONFIG_FILE: &str = "wundergraph.toml";
+
+/// Configuration for the generated code, loaded from a `wundergraph.toml` file
+///
+/// ```toml
+/// [tables.users]
+/// graphql_name = "Persons"
+/// doc = "All registered users"
+/// delete = false
+///
+/// [tables.users.columns.email]
+/// rust_type = "Email"
+/// graphql_name = "mail"
+/// doc = "The primary mail address"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    tables: HashMap<String, TableConfig>,
+    #[serde(skip)]
+    default_table: TableConfig,
+}
+
+/// Options for a single table
+///
+/// Tables are identified by their name, optionally prefixed by the schema
+/// name (`schema.table`)
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TableConfig {
+    /// GraphQL name of the query field for this table
+    pub graphql_name: Option<String>,
+    /// Documentation for the generated entity
+    pub doc: Option<String>,
+    /// Generate an insert mutation
+    pub insert: bool,
+    /// Generate an update mutation
+    pub update: bool,
+    /// Generate a delete mutation
+    pub delete: bool,
+    /// Generate a filter argument for the query field
+    pub filter: bool,
+    /// Generate an order argument for the query field
+    pub order: bool,
+    /// Key columns used for views, as views have no primary key
+    pub primary_key: Option<Vec<String>>,
+    columns: HashMap<String, ColumnConfig>,
+    #[serde(skip)]
+    default_column: ColumnConfig,
+}
+
+/// Options for a single column
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnConfig {
+    /// Diesel sql type used in `table!`
+    ///
+    /// Wrapped in `Nullable<_>` for nullable columns
+    pub sql_type: Option<String>,
+    /// Rust type used for the fields of the entity, insert and
+    /// update structs. This also determines the GraphQL type.
+    ///
+    /// Wrapped in `Option<_>` for nullable columns
+    pub rust_type: Option<String>,
+    /// GraphQL name of the field
+    pub graphql_name: Option<String>,
+    /// Documentation for the generated fields
+    pub doc: Option<String>,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            graphql_name: None,
+            doc: None,
+            insert: true,
+            update: true,
+            delete: true,
+            filter: true,
+            order: true,
+            primary_key: None,
+            columns: HashMap::new(),
+            default_column: ColumnConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Load the configuration from the given file
+    ///
+    /// If no file is given `wundergraph.toml` in the current directory
+    /// is used if it exists
+    pub fn load(path: Option<&Path>) -> Result<Self, Box<dyn Error>> {
+        let path = match path {
+            Some(path) => path,
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => Path::new(DEFAULT_CONFIG_FILE),
+            None => return Ok(Self::default()),
+        };
+        let content = std::fs::read_to_string(path)?;
+        toml::from_str(&content)
+            .map_err(|e| format!("Failed to parse config file `{}`: {}", path.display(), e).into())
+    }
+
+    pub fn table(&self, name: &TableName) -> &TableConfig {
+        self.tables
+            .get(&name.to_string())
+            .or_else(|| self.tables.get(&name.name))
+            .unwrap_or(&self.default_table)
+    }
+}
+
+impl TableConfig {
+    pub fn column(&self, name: &str) -> &ColumnConfig {
+        self.columns.get(name).unwrap_or(&self.default_column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [tables.users]
+            graphql_name = "Persons"
+            delete = false
+
+            [tables.users.columns.email]
+            rust_type = "Email"
+            graphql_name = "mail"
+
+            [tables."infer_test.posts"]
+            filter = false
+
+            [tables.post_stats]
+            primary_key = ["post_id"]
+            "#,
+        )
+        .unwrap();
+
+        let users = config.table(&TableName::from_name("users"));
+        assert_eq!(
+            users.graphql_name.as_ref().map(|s| s as &str),
+            Some("Persons")
+        );
+        assert!(users.insert);
+        assert!(!users.delete);
+        let email = users.column("email");
+        assert_eq!(email.rust_type.as_ref().map(|s| s as &str), Some("Email"));
+        assert_eq!(email.graphql_name.as_ref().map(|s| s as &str), Some("mail"));
+        assert!(users.column("name").rust_type.is_none());
+
+        let posts = config.table(&TableName::new("posts", "infer_test"));
+        assert!(!posts.filter);
+        assert!(posts.order);
+
+        let comments = config.table(&TableName::from_name("comments"));
+        assert!(comments.delete);
+        assert!(comments.graphql_name.is_none());
+        assert!(comments.primary_key.is_none());
+
+        let post_stats = config.table(&TableName::from_name("post_stats"));
+        assert_eq!(post_stats.primary_key, Some(vec![String::from("post_id")]));
+    }
+
+    #[test]
+    fn reject_unknown_options() {
+        assert!(toml::from_str::<Config>("[tables.users]\nremove = false").is_err());
+    }
+}
@@ -17,18 +17,104 @@ pub struct ColumnInformation {
     pub has_default: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ColumnType {
     pub rust_name: String,
     pub is_array: bool,
     pub is_nullable: bool,
     pub is_unsigned: bool,
+    /// The backend has no unsigned types, so unsigned values
+    /// are stored in a larger signed column (see `storage_type`)
+    pub is_stored_signed: bool,
+    pub enum_type: Option<EnumType>,
+}
+
+/// A custom enum type
+///
+/// Postgres enums are declared using `CREATE TYPE … AS ENUM`, while
+/// MySQL enums are declared inline for each column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumType {
+    /// Name used to derive the names of the generated types
+    pub name: String,
+    /// Name of the postgres type, `None` for MySQL enums
+    pub pg_type_name: Option<String>,
+    /// All values of the enum in declaration order
+    pub variants: Vec<String>,
+}
+
+impl EnumType {
+    /// Name of the generated rust enum
+    pub fn rust_name(&self) -> String {
+        to_camel_case(&self.name)
+    }
+
+    /// Name of the generated diesel sql type
+    pub fn sql_type_name(&self) -> String {
+        format!("{}Type", self.rust_name())
+    }
+
+    /// Names of the generated enum variants, in the same order as
+    /// `variants`
+    pub fn variant_names(&self) -> Vec<String> {
+        let mut ret = Vec::<String>::with_capacity(self.variants.len());
+        for v in &self.variants {
+            let mut name = to_camel_case(v);
+            if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+                name = format!("Value{}", name);
+            }
+            if ret.contains(&name) {
+                let mut suffix = ret.len();
+                while ret.contains(&format!("{}{}", name, suffix)) {
+                    suffix += 1;
+                }
+                name = format!("{}{}", name, suffix);
+            }
+            ret.push(name);
+        }
+        ret
+    }
+}
+
+fn to_camel_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|p| !p.is_empty())
+        .map(|p| p[..1].to_uppercase() + &p[1..])
+        .collect()
 }
 
 use std::fmt;
 
+impl ColumnType {
+    /// The signed type used to store values of an unsigned column
+    ///
+    /// Wundergraph stores unsigned integers of sqlite columns in the next
+    /// larger signed integer type (`BigInt` for unsigned `BigInt` columns),
+    /// because sqlite does not support unsigned types. Columns of other
+    /// backends are returned unchanged.
+    pub fn storage_type(&self) -> Self {
+        if !self.is_unsigned || !self.is_stored_signed {
+            return self.clone();
+        }
+        let rust_name = match &*self.rust_name {
+            "SmallInt" => "Integer",
+            "Integer" | "BigInt" => "BigInt",
+            n => n,
+        };
+        Self {
+            rust_name: rust_name.into(),
+            is_unsigned: false,
+            is_stored_signed: false,
+            ..self.clone()
+        }
+    }
+}
+
 impl fmt::Display for ColumnType {
     fn fmt(&self, out: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.is_unsigned && self.is_stored_signed {
+            return write!(out, "{}", self.storage_type());
+        }
         if self.is_nullable {
             write!(out, "Nullable<")?;
         }
@@ -57,6 +143,7 @@ pub struct ColumnDefinition {
     pub sql_name: String,
     pub ty: ColumnType,
     pub docs: String,
+    pub comment: Option<String>,
     pub rust_name: Option<String>,
     pub has_default: bool,
 }
@@ -110,11 +197,109 @@ pub struct ForeignKeyConstraint {
 }
 
 impl ForeignKeyConstraint {
-    pub fn ordered_tables(&self) -> (&TableName, &TableName) {
-        use std::cmp::{max, min};
-        (
-            min(&self.parent_table, &self.child_table),
-            max(&self.parent_table, &self.child_table),
-        )
+    /// Name of the `HasMany` field generated for this foreign key
+    /// on the parent table
+    ///
+    /// This is the name of the child table, as long as there is only one
+    /// foreign key between both tables. Otherwise the name of the foreign key
+    /// column is appended to get an unique name (`posts_by_created_by`).
+    /// The same applies to foreign keys referencing the own table.
+    pub fn has_many_field_name(&self, foreign_keys: &[ForeignKeyConstraint]) -> String {
+        let is_ambiguous = self.parent_table == self.child_table
+            || foreign_keys
+                .iter()
+                .filter(|fk| {
+                    fk.parent_table == self.parent_table && fk.child_table == self.child_table
+                })
+                .count()
+                > 1;
+        if is_ambiguous {
+            format!("{}_by_{}", self.child_table.name, self.foreign_key)
+        } else {
+            self.child_table.name.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fk(child_table: &str, parent_table: &str, foreign_key: &str) -> ForeignKeyConstraint {
+        ForeignKeyConstraint {
+            child_table: TableName::from_name(child_table),
+            parent_table: TableName::from_name(parent_table),
+            foreign_key: foreign_key.into(),
+            primary_key: "id".into(),
+        }
+    }
+
+    #[test]
+    fn unsigned_storage_types() {
+        let column = |is_stored_signed| ColumnType {
+            rust_name: String::from("Integer"),
+            is_array: false,
+            is_nullable: true,
+            is_unsigned: true,
+            is_stored_signed,
+            enum_type: None,
+        };
+        assert_eq!(column(true).to_string(), "Nullable<BigInt>");
+        assert_eq!(column(false).to_string(), "Nullable<Unsigned<Integer>>");
+        assert!(column(false).storage_type().is_unsigned);
+    }
+
+    #[test]
+    fn enum_type_names() {
+        let enum_type = EnumType {
+            name: String::from("order_status"),
+            pg_type_name: Some(String::from("order_status")),
+            variants: vec![
+                String::from("in progress"),
+                String::from("done"),
+                String::from("2fa"),
+                String::from("in-progress"),
+            ],
+        };
+        assert_eq!(enum_type.rust_name(), "OrderStatus");
+        assert_eq!(enum_type.sql_type_name(), "OrderStatusType");
+        assert_eq!(
+            enum_type.variant_names(),
+            vec!["InProgress", "Done", "Value2fa", "InProgress3"]
+        );
+
+        let enum_type = EnumType {
+            name: String::from("size"),
+            pg_type_name: Some(String::from("size")),
+            variants: vec![String::from("a"), String::from("A2"), String::from("A")],
+        };
+        assert_eq!(enum_type.variant_names(), vec!["A", "A2", "A3"]);
+    }
+
+    #[test]
+    fn has_many_field_name() {
+        let foreign_keys = vec![
+            fk("posts", "users", "created_by"),
+            fk("posts", "users", "updated_by"),
+            fk("comments", "posts", "post"),
+            fk("comments", "comments", "parent_id"),
+        ];
+
+        assert_eq!(
+            foreign_keys[0].has_many_field_name(&foreign_keys),
+            "posts_by_created_by"
+        );
+        assert_eq!(
+            foreign_keys[1].has_many_field_name(&foreign_keys),
+            "posts_by_updated_by"
+        );
+        assert_eq!(
+            foreign_keys[2].has_many_field_name(&foreign_keys),
+            "comments"
+        );
+        assert_eq!(
+            foreign_keys[3].has_many_field_name(&foreign_keys),
+            "comments_by_parent_id"
+        );
     }
 }
@@ -3,34 +3,36 @@ use super::inference::get_primary_keys;
 use super::table_data::TableName;
 use crate::database::InferConnection;
 
+/// Removes all foreign keys we cannot generate code for
+///
+/// Foreign keys are only kept if both tables are part of the generated code
+/// and the foreign key references the whole primary key of the parent table.
+/// Multiple foreign keys between the same tables and foreign keys referencing
+/// the own table are kept, the generated `HasMany` fields get an unique name
+/// in this case.
 pub fn remove_unsafe_foreign_keys_for_codegen(
     connection: &InferConnection,
     foreign_keys: &[ForeignKeyConstraint],
     safe_tables: &[TableName],
 ) -> Vec<ForeignKeyConstraint> {
-    let duplicates = foreign_keys
-        .iter()
-        .map(ForeignKeyConstraint::ordered_tables)
-        .filter(|tables| {
-            let dup_count = foreign_keys
-                .iter()
-                .filter(|fk| tables == &fk.ordered_tables())
-                .count();
-            dup_count > 1
-        })
-        .collect::<Vec<_>>();
-
     foreign_keys
         .iter()
-        .filter(|fk| fk.parent_table != fk.child_table)
         .filter(|fk| safe_tables.contains(&fk.parent_table))
         .filter(|fk| safe_tables.contains(&fk.child_table))
         .filter(|fk| {
             let pk_columns = get_primary_keys(connection, &fk.parent_table)
                 .unwrap_or_else(|_| panic!("Error loading primary keys for `{}`", fk.parent_table));
-            pk_columns.len() == 1 && pk_columns[0] == fk.primary_key
+            if pk_columns.len() == 1 && pk_columns[0] == fk.primary_key {
+                true
+            } else {
+                eprintln!(
+                    "Warning: Skipping foreign key `{}.{}`, \
+                     it does not reference the whole primary key of `{}`",
+                    fk.child_table, fk.foreign_key, fk.parent_table
+                );
+                false
+            }
         })
-        .filter(|fk| !duplicates.contains(&fk.ordered_tables()))
         .cloned()
         .collect()
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::error::Error;
 
 use diesel::result::Error::NotFound;
@@ -28,6 +29,20 @@ pub fn load_table_names(
     }
 }
 
+pub fn load_view_names(
+    connection: &InferConnection,
+    schema_name: Option<&str>,
+) -> Result<Vec<TableName>, Box<dyn Error>> {
+    match connection {
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(c) => super::sqlite::load_view_names(c, schema_name),
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(c) => super::information_schema::load_view_names(c, schema_name),
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(c) => super::information_schema::load_view_names(c, schema_name),
+    }
+}
+
 fn get_column_information(
     conn: &InferConnection,
     table: &TableName,
@@ -47,17 +62,33 @@ fn get_column_information(
     }
 }
 
+/// Custom types loaded while inferring the schema, so they are only
+/// loaded once per database schema
+#[derive(Debug, Default)]
+pub struct TypeCache {
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pg_enums: HashMap<String, HashMap<String, Vec<String>>>,
+}
+
+#[cfg_attr(
+    not(all(feature = "mysql", feature = "postgres")),
+    allow(unused_variables)
+)]
 fn determine_column_type(
     attr: &ColumnInformation,
+    table: &TableName,
     conn: &InferConnection,
+    types: &mut TypeCache,
 ) -> Result<ColumnType, Box<dyn Error>> {
     match *conn {
         #[cfg(feature = "sqlite")]
         InferConnection::Sqlite(_) => super::sqlite::determine_column_type(attr),
         #[cfg(feature = "postgres")]
-        InferConnection::Pg(_) => super::pg::determine_column_type(attr),
+        InferConnection::Pg(ref c) => {
+            super::pg::determine_column_type(attr, table, c, &mut types.pg_enums)
+        }
         #[cfg(feature = "mysql")]
-        InferConnection::Mysql(_) => super::mysql::determine_column_type(attr),
+        InferConnection::Mysql(_) => super::mysql::determine_column_type(attr, table),
     }
 }
 
@@ -109,6 +140,39 @@ pub fn load_foreign_key_constraints(
     })
 }
 
+fn get_table_comment(
+    conn: &InferConnection,
+    table: &TableName,
+) -> Result<Option<String>, Box<dyn Error>> {
+    match *conn {
+        // SQLite does not support comments
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) => Ok(None),
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(ref c) => super::pg::get_table_comment(c, table).map_err(Into::into),
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(ref c) => {
+            super::mysql::get_table_comment(c, table).map_err(Into::into)
+        }
+    }
+}
+
+fn get_column_comments(
+    conn: &InferConnection,
+    table: &TableName,
+) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    match *conn {
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) => Ok(HashMap::new()),
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(ref c) => super::pg::get_column_comments(c, table).map_err(Into::into),
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(ref c) => {
+            super::mysql::get_column_comments(c, table).map_err(Into::into)
+        }
+    }
+}
+
 macro_rules! doc_comment {
     ($($token:tt)*) => {
         format!($($token)*)
@@ -122,6 +186,49 @@ macro_rules! doc_comment {
 pub fn load_table_data(
     connection: &InferConnection,
     name: TableName,
+    types: &mut TypeCache,
+) -> Result<TableData, Box<dyn Error>> {
+    let primary_key = get_primary_keys(&connection, &name)?;
+    load_data(connection, name, primary_key, false, types)
+}
+
+/// Loads the data for a view
+///
+/// Views have no primary key, so the given key columns are used instead
+pub fn load_view_data(
+    connection: &InferConnection,
+    name: TableName,
+    key_columns: &[String],
+    types: &mut TypeCache,
+) -> Result<TableData, Box<dyn Error>> {
+    if key_columns.is_empty() {
+        return Err(format!("No key column given for view `{}`", name).into());
+    }
+    let mut data = load_data(connection, name, key_columns.to_vec(), true, types)?;
+    for key in key_columns {
+        if let Some(column) = data.column_data.iter_mut().find(|c| &c.sql_name == key) {
+            // Databases report all view columns as nullable, but the
+            // key of an entity must not be null
+            column.ty.is_nullable = false;
+        } else {
+            return Err(format!(
+                "View `{}` has no key column `{}`. Use `--view-key-column` \
+                 or the `primary_key` option in the configuration file to \
+                 specify the key column",
+                data.name, key
+            )
+            .into());
+        }
+    }
+    Ok(data)
+}
+
+fn load_data(
+    connection: &InferConnection,
+    name: TableName,
+    primary_key: Vec<String>,
+    is_view: bool,
+    types: &mut TypeCache,
 ) -> Result<TableData, Box<dyn Error>> {
     let docs = doc_comment!(
         "Representation of the `{}` table.
@@ -129,7 +236,8 @@ pub fn load_table_data(
         (Automatically generated by Diesel.)",
         name
     );
-    let primary_key = get_primary_keys(&connection, &name)?;
+    let comment = get_table_comment(connection, &name)?;
+    let mut column_comments = get_column_comments(connection, &name)?;
     let primary_key = primary_key
         .iter()
         .map(|k| {
@@ -144,7 +252,7 @@ pub fn load_table_data(
     let column_data = get_column_information(&connection, &name)?
         .into_iter()
         .map(|c| {
-            let ty = determine_column_type(&c, &connection)?;
+            let ty = determine_column_type(&c, &name, &connection, types)?;
             let rust_name = if RESERVED_NAMES.contains(&c.column_name.as_str()) {
                 Some(format!("{}_", c.column_name))
             } else {
@@ -162,6 +270,7 @@ pub fn load_table_data(
                     name,
                     ty
                 ),
+                comment: column_comments.remove(&c.column_name),
                 sql_name: c.column_name,
                 ty,
                 rust_name,
@@ -175,5 +284,7 @@ pub fn load_table_data(
         primary_key,
         column_data,
         docs,
+        comment,
+        is_view,
     })
 }
@@ -167,6 +167,31 @@ pub fn load_table_names<Conn>(
     connection: &Conn,
     schema_name: Option<&str>,
 ) -> Result<Vec<TableName>, Box<dyn Error>>
+where
+    Conn: Connection,
+    Conn::Backend: UsesInformationSchema,
+    String: FromSql<sql_types::Text, Conn::Backend>,
+{
+    load_names(connection, schema_name, "BASE TABLE")
+}
+
+pub fn load_view_names<Conn>(
+    connection: &Conn,
+    schema_name: Option<&str>,
+) -> Result<Vec<TableName>, Box<dyn Error>>
+where
+    Conn: Connection,
+    Conn::Backend: UsesInformationSchema,
+    String: FromSql<sql_types::Text, Conn::Backend>,
+{
+    load_names(connection, schema_name, "VIEW")
+}
+
+fn load_names<Conn>(
+    connection: &Conn,
+    schema_name: Option<&str>,
+    object_type: &str,
+) -> Result<Vec<TableName>, Box<dyn Error>>
 where
     Conn: Connection,
     Conn::Backend: UsesInformationSchema,
@@ -184,7 +209,7 @@ where
         .select((table_name, table_schema))
         .filter(table_schema.eq(schema_name))
         .filter(table_name.not_like("\\_\\_%"))
-        .filter(table_type.like("BASE TABLE"))
+        .filter(table_type.like(object_type))
         .order(table_name)
         .load::<TableName>(connection)?;
     for table in &mut table_names {
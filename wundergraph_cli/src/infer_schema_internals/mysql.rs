@@ -1,5 +1,6 @@
 use diesel::mysql::Mysql;
 use diesel::*;
+use std::collections::HashMap;
 use std::error::Error;
 
 use super::data_structures::*;
@@ -29,6 +30,23 @@ mod information_schema {
         }
     }
 
+    table! {
+        information_schema.tables (table_schema, table_name) {
+            table_schema -> VarChar,
+            table_name -> VarChar,
+            table_comment -> VarChar,
+        }
+    }
+
+    table! {
+        information_schema.columns (table_schema, table_name, column_name) {
+            table_schema -> VarChar,
+            table_name -> VarChar,
+            column_name -> VarChar,
+            column_comment -> VarChar,
+        }
+    }
+
     allow_tables_to_appear_in_same_query!(table_constraints, key_column_usage);
 }
 
@@ -79,7 +97,68 @@ pub fn load_foreign_key_constraints(
     Ok(constraints)
 }
 
-pub fn determine_column_type(attr: &ColumnInformation) -> Result<ColumnType, Box<Error>> {
+/// Loads the comment set via `COMMENT` on table creation
+///
+/// MySQL uses an empty string for tables without comment
+pub fn get_table_comment(
+    connection: &MysqlConnection,
+    table: &TableName,
+) -> QueryResult<Option<String>> {
+    use self::information_schema::tables;
+
+    let schema_name = match table.schema {
+        Some(ref name) => name.clone(),
+        None => Mysql::default_schema(connection)?,
+    };
+    let comment = tables::table
+        .filter(tables::table_schema.eq(schema_name))
+        .filter(tables::table_name.eq(&table.name))
+        .select(tables::table_comment)
+        .get_result::<String>(connection)?;
+    Ok(Some(comment).filter(|c| !c.is_empty()))
+}
+
+/// Loads the comments of all columns, indexed by column name
+pub fn get_column_comments(
+    connection: &MysqlConnection,
+    table: &TableName,
+) -> QueryResult<HashMap<String, String>> {
+    use self::information_schema::columns;
+
+    let schema_name = match table.schema {
+        Some(ref name) => name.clone(),
+        None => Mysql::default_schema(connection)?,
+    };
+    let comments = columns::table
+        .filter(columns::table_schema.eq(schema_name))
+        .filter(columns::table_name.eq(&table.name))
+        .filter(columns::column_comment.ne(""))
+        .select((columns::column_name, columns::column_comment))
+        .load::<(String, String)>(connection)?;
+    Ok(comments.into_iter().collect())
+}
+
+pub fn determine_column_type(
+    attr: &ColumnInformation,
+    table: &TableName,
+) -> Result<ColumnType, Box<Error>> {
+    if let Some(variants) = parse_enum_variants(&attr.type_name) {
+        // MySQL enums are defined per column, so there is no type name
+        let enum_type = EnumType {
+            name: format!("{}_{}", table.name, attr.column_name),
+            pg_type_name: None,
+            variants,
+        };
+        return Ok(ColumnType {
+            rust_name: enum_type.sql_type_name(),
+            is_array: false,
+            is_nullable: attr.nullable,
+            is_unsigned: false,
+            is_stored_signed: false,
+            enum_type: Some(enum_type),
+        });
+    }
+
     let tpe = determine_type_name(&attr.type_name)?;
     let unsigned = determine_unsigned(&attr.type_name);
 
@@ -88,9 +167,44 @@ pub fn determine_column_type(attr: &ColumnInformation) -> Result<ColumnType, Box
         is_array: false,
         is_nullable: attr.nullable,
         is_unsigned: unsigned,
+        is_stored_signed: false,
+        enum_type: None,
     })
 }
 
+/// Parses the values of a column type like `enum('a','b')`
+fn parse_enum_variants(sql_type_name: &str) -> Option<Vec<String>> {
+    if !sql_type_name.to_lowercase().starts_with("enum(") || !sql_type_name.ends_with(')') {
+        return None;
+    }
+    let values = &sql_type_name["enum(".len()..sql_type_name.len() - 1];
+    let mut ret = Vec::new();
+    let mut chars = values.chars().peekable();
+    loop {
+        if chars.next() != Some('\'') {
+            return None;
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next()? {
+                // Quotes inside of values are escaped by doubling them
+                '\'' if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    value.push('\'');
+                }
+                '\'' => break,
+                c => value.push(c),
+            }
+        }
+        ret.push(value);
+        match chars.next() {
+            Some(',') => {}
+            None => return Some(ret),
+            Some(_) => return None,
+        }
+    }
+}
+
 fn determine_type_name(sql_type_name: &str) -> Result<String, Box<Error>> {
     let result = if sql_type_name == "tinyint(1)" {
         "bool"
@@ -161,6 +275,21 @@ fn unsigned_types_are_supported() {
     assert_eq!("bigint", determine_type_name("unsigned bigint").unwrap());
 }
 
+#[test]
+fn enum_variants_are_parsed() {
+    assert_eq!(
+        Some(vec![
+            String::from("small"),
+            String::from("extra large"),
+            String::from("it's"),
+            String::from("a,b"),
+        ]),
+        parse_enum_variants("enum('small','extra large','it''s','a,b')")
+    );
+    assert_eq!(None, parse_enum_variants("varchar(255)"));
+    assert_eq!(None, parse_enum_variants("enum('broken)"));
+}
+
 #[test]
 fn types_with_space_are_not_supported() {
     assert!(determine_type_name("lol wat").is_err());
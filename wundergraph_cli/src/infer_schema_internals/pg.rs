@@ -1,9 +1,25 @@
+use diesel::dsl::sql;
+use diesel::pg::{Pg, PgConnection};
+use diesel::sql_types::{Nullable, Text};
+use diesel::{QueryResult, RunQueryDsl};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::error::Error;
 use std::io::{stderr, Write};
 
 use super::data_structures::*;
+use super::information_schema::UsesInformationSchema;
+use super::table_data::TableName;
 
-pub fn determine_column_type(attr: &ColumnInformation) -> Result<ColumnType, Box<dyn Error>> {
+/// Enum types of a schema, mapping type names to variants
+pub type EnumTypes = HashMap<String, Vec<String>>;
+
+pub fn determine_column_type(
+    attr: &ColumnInformation,
+    table: &TableName,
+    conn: &PgConnection,
+    enums: &mut HashMap<String, EnumTypes>,
+) -> Result<ColumnType, Box<dyn Error>> {
     let is_array = attr.type_name.starts_with('_');
     let tpe = if is_array {
         &attr.type_name[1..]
@@ -27,14 +43,100 @@ pub fn determine_column_type(attr: &ColumnInformation) -> Result<ColumnType, Box
         )?;
     }
 
+    let schema_name = match table.schema {
+        Some(ref name) => name.clone(),
+        None => Pg::default_schema(conn)?,
+    };
+    let schema_enums = match enums.entry(schema_name) {
+        Entry::Occupied(e) => e.into_mut(),
+        Entry::Vacant(e) => {
+            let loaded = load_enum_types(conn, e.key())?;
+            e.insert(loaded)
+        }
+    };
+    let enum_type = schema_enums.get(tpe).map(|variants| EnumType {
+        name: tpe.to_owned(),
+        pg_type_name: Some(tpe.to_owned()),
+        variants: variants.clone(),
+    });
+
     Ok(ColumnType {
-        rust_name: capitalize(tpe),
+        rust_name: enum_type
+            .as_ref()
+            .map_or_else(|| capitalize(tpe), EnumType::sql_type_name),
         is_array,
         is_nullable: attr.nullable,
         is_unsigned: false,
+        is_stored_signed: false,
+        enum_type,
     })
 }
 
+/// Loads all enum types declared in the given schema
+fn load_enum_types(conn: &PgConnection, schema_name: &str) -> QueryResult<EnumTypes> {
+    let values = sql::<(Text, Text)>(
+        "SELECT t.typname::text, e.enumlabel::text \
+         FROM pg_catalog.pg_enum e \
+         INNER JOIN pg_catalog.pg_type t ON t.oid = e.enumtypid \
+         INNER JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace \
+         WHERE n.nspname = ",
+    )
+    .bind::<Text, _>(schema_name)
+    .sql(" ORDER BY t.typname, e.enumsortorder")
+    .load::<(String, String)>(conn)?;
+    let mut enums = EnumTypes::new();
+    for (type_name, variant) in values {
+        enums
+            .entry(type_name)
+            .or_insert_with(Vec::new)
+            .push(variant);
+    }
+    Ok(enums)
+}
+
 fn capitalize(name: &str) -> String {
     name[..1].to_uppercase() + &name[1..]
 }
+
+/// Loads the comment set via `COMMENT ON TABLE`
+pub fn get_table_comment(conn: &PgConnection, table: &TableName) -> QueryResult<Option<String>> {
+    let schema_name = match table.schema {
+        Some(ref name) => name.clone(),
+        None => Pg::default_schema(conn)?,
+    };
+    sql::<Nullable<Text>>(
+        "SELECT obj_description(c.oid, 'pg_class') \
+         FROM pg_catalog.pg_class c \
+         INNER JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+         WHERE n.nspname = ",
+    )
+    .bind::<Text, _>(schema_name)
+    .sql(" AND c.relname = ")
+    .bind::<Text, _>(&table.name)
+    .get_result(conn)
+}
+
+/// Loads the comments set via `COMMENT ON COLUMN`, indexed by column name
+pub fn get_column_comments(
+    conn: &PgConnection,
+    table: &TableName,
+) -> QueryResult<HashMap<String, String>> {
+    let schema_name = match table.schema {
+        Some(ref name) => name.clone(),
+        None => Pg::default_schema(conn)?,
+    };
+    let comments = sql::<(Text, Text)>(
+        "SELECT a.attname::text, col_description(c.oid, a.attnum) \
+         FROM pg_catalog.pg_class c \
+         INNER JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+         INNER JOIN pg_catalog.pg_attribute a ON a.attrelid = c.oid \
+         WHERE a.attnum > 0 AND NOT a.attisdropped \
+         AND col_description(c.oid, a.attnum) IS NOT NULL \
+         AND n.nspname = ",
+    )
+    .bind::<Text, _>(schema_name)
+    .sql(" AND c.relname = ")
+    .bind::<Text, _>(&table.name)
+    .load::<(String, String)>(conn)?;
+    Ok(comments.into_iter().collect())
+}
@@ -40,6 +40,21 @@ table! {
 pub fn load_table_names(
     connection: &SqliteConnection,
     schema_name: Option<&str>,
+) -> Result<Vec<TableName>, Box<dyn Error>> {
+    load_names(connection, schema_name, "table")
+}
+
+pub fn load_view_names(
+    connection: &SqliteConnection,
+    schema_name: Option<&str>,
+) -> Result<Vec<TableName>, Box<dyn Error>> {
+    load_names(connection, schema_name, "view")
+}
+
+fn load_names(
+    connection: &SqliteConnection,
+    schema_name: Option<&str>,
+    object_type: &str,
 ) -> Result<Vec<TableName>, Box<dyn Error>> {
     use self::sqlite_master::dsl::*;
 
@@ -53,7 +68,7 @@ pub fn load_table_names(
         .select(name)
         .filter(name.not_like("\\_\\_%").escape('\\'))
         .filter(name.not_like("sqlite%"))
-        .filter(sql("type='table'"))
+        .filter(sql(&format!("type='{}'", object_type)))
         .order(name)
         .load::<String>(connection)?
         .into_iter()
@@ -189,10 +204,16 @@ pub fn determine_column_type(attr: &ColumnInformation) -> Result<ColumnType, Box
         rust_name: path,
         is_array: false,
         is_nullable: attr.nullable,
-        is_unsigned: false,
+        is_unsigned: is_unsigned(&type_name),
+        is_stored_signed: true,
+        enum_type: None,
     })
 }
 
+fn is_unsigned(type_name: &str) -> bool {
+    type_name.contains("int") && type_name.split_whitespace().any(|p| p == "unsigned")
+}
+
 fn is_text(type_name: &str) -> bool {
     type_name.contains("char") || type_name.contains("clob") || type_name.contains("text")
 }
@@ -269,6 +290,16 @@ fn load_table_names_excludes_views() {
     assert_eq!(vec![TableName::from_name("users")], table_names.unwrap());
 }
 
+#[test]
+fn load_view_names_only_includes_views() {
+    let conn = SqliteConnection::establish(":memory:").unwrap();
+    conn.execute("CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT)")
+        .unwrap();
+    conn.execute("CREATE VIEW answer AS SELECT 42").unwrap();
+    let view_names = load_view_names(&conn, None);
+    assert_eq!(vec![TableName::from_name("answer")], view_names.unwrap());
+}
+
 #[test]
 fn load_table_names_returns_error_when_given_schema_name() {
     let conn = SqliteConnection::establish(":memory:").unwrap();
@@ -332,3 +363,23 @@ fn load_foreign_key_constraints_loads_foreign_keys() {
     let fks = load_foreign_key_constraints(&connection, None).unwrap();
     assert_eq!(vec![fk_one, fk_two], fks);
 }
+
+#[test]
+fn unsigned_integer_columns_are_detected() {
+    let column = |type_name: &str| {
+        determine_column_type(&ColumnInformation::new("a", type_name, false, false)).unwrap()
+    };
+    let int = column("INTEGER UNSIGNED");
+    assert_eq!(int.rust_name, "Integer");
+    assert!(int.is_unsigned);
+    assert_eq!(int.to_string(), "BigInt");
+    let small = column("unsigned smallint");
+    assert_eq!(small.rust_name, "SmallInt");
+    assert!(small.is_unsigned);
+    assert_eq!(small.to_string(), "Integer");
+    let big = column("BIGINT UNSIGNED");
+    assert!(big.is_unsigned);
+    assert_eq!(big.to_string(), "BigInt");
+    assert!(!column("INTEGER").is_unsigned);
+    assert!(!column("UNSIGNED_TEXT").is_unsigned);
+}
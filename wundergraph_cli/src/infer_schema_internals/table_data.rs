@@ -82,4 +82,6 @@ pub struct TableData {
     pub primary_key: Vec<String>,
     pub column_data: Vec<ColumnDefinition>,
     pub docs: String,
+    pub comment: Option<String>,
+    pub is_view: bool,
 }
@@ -31,13 +31,21 @@
 #[macro_use]
 extern crate diesel;
 
+use std::path::PathBuf;
 use structopt::StructOpt;
 
+mod check;
+mod config;
 mod database;
 mod infer_schema_internals;
+mod new_project;
 mod print_schema;
 
+use crate::check::SchemaSnapshot;
+use crate::config::Config;
 use crate::database::InferConnection;
+use crate::new_project::NewProject;
+use crate::print_schema::TableFilter;
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "wundergraph")]
@@ -46,6 +54,88 @@ enum Wundergraph {
     PrintSchema {
         database_url: String,
         schema: Option<String>,
+        #[structopt(flatten)]
+        table_filter: TableFilter,
+        /// Path to the configuration file, defaults to `wundergraph.toml`
+        #[structopt(long = "config", parse(from_os_str))]
+        config: Option<PathBuf>,
+        /// Write the generated code as module into the given directory
+        /// instead of printing it to stdout
+        #[structopt(long = "out-dir", parse(from_os_str))]
+        out_dir: Option<PathBuf>,
+    },
+    /// Print the GraphQL schema (SDL) exposed by the code generated
+    /// by `print-schema` for the given database
+    #[structopt(name = "print-graphql-schema")]
+    PrintGraphqlSchema {
+        database_url: String,
+        schema: Option<String>,
+        #[structopt(flatten)]
+        table_filter: TableFilter,
+        /// Path to the configuration file, defaults to `wundergraph.toml`
+        #[structopt(long = "config", parse(from_os_str))]
+        config: Option<PathBuf>,
+    },
+    /// Create a new cargo project containing a graphql server
+    /// for the given database
+    #[structopt(name = "new")]
+    New {
+        #[structopt(parse(from_os_str))]
+        path: PathBuf,
+        #[structopt(long = "database-url")]
+        database_url: String,
+        #[structopt(long = "schema")]
+        schema: Option<String>,
+        /// A diesel migrations directory that is copied into the new project
+        #[structopt(long = "migrations", parse(from_os_str))]
+        migrations: Option<PathBuf>,
+        #[structopt(long = "listen-url", default_value = "127.0.0.1:8000")]
+        listen_url: String,
+        /// Depend on a local wundergraph checkout instead of the released crate
+        #[structopt(long = "wundergraph-path", parse(from_os_str))]
+        wundergraph_path: Option<PathBuf>,
+        #[structopt(flatten)]
+        table_filter: TableFilter,
+        /// Path to the configuration file, defaults to `wundergraph.toml`
+        #[structopt(long = "config", parse(from_os_str))]
+        config: Option<PathBuf>,
+    },
+    /// Check if the generated code still matches the database schema
+    ///
+    /// Exits with a non zero exit code if differences are found
+    #[structopt(name = "check")]
+    Check {
+        database_url: String,
+        schema: Option<String>,
+        #[structopt(flatten)]
+        table_filter: TableFilter,
+        /// Path to the configuration file, defaults to `wundergraph.toml`
+        #[structopt(long = "config", parse(from_os_str))]
+        config: Option<PathBuf>,
+        /// Code generated by `print-schema`, either a single file or
+        /// a directory written using `--out-dir`
+        #[structopt(
+            long = "generated",
+            parse(from_os_str),
+            conflicts_with = "snapshot",
+            required_unless_one = &["snapshot", "write-snapshot"]
+        )]
+        generated: Option<PathBuf>,
+        /// A schema snapshot written by `--write-snapshot`
+        #[structopt(
+            long = "snapshot",
+            parse(from_os_str),
+            conflicts_with = "write-snapshot"
+        )]
+        snapshot: Option<PathBuf>,
+        /// Write a snapshot of the current database schema to the given
+        /// file instead of checking it
+        #[structopt(
+            long = "write-snapshot",
+            parse(from_os_str),
+            conflicts_with = "generated"
+        )]
+        write_snapshot: Option<PathBuf>,
     },
 }
 
@@ -54,16 +144,117 @@ fn main() {
         Wundergraph::PrintSchema {
             database_url,
             schema,
+            table_filter,
+            config,
+            out_dir,
         } => {
+            let config = Config::load(config.as_ref().map(PathBuf::as_path))
+                .expect("Failed to load the configuration");
             let conn = InferConnection::establish(&database_url).unwrap_or_else(|_| {
                 panic!("Unable to connect to database with url: {}", database_url)
             });
-            print_schema::print(
+            let schema = schema.as_ref().map(|s| s as &str);
+            if let Some(out_dir) = out_dir {
+                print_schema::print_to_dir(&conn, schema, &table_filter, &config, &out_dir)
+            } else {
+                print_schema::print(
+                    &conn,
+                    schema,
+                    &table_filter,
+                    &config,
+                    &mut std::io::stdout(),
+                )
+            }
+        }
+        .expect("Failed to infer the schema"),
+        Wundergraph::PrintGraphqlSchema {
+            database_url,
+            schema,
+            table_filter,
+            config,
+        } => {
+            let config = Config::load(config.as_ref().map(PathBuf::as_path))
+                .expect("Failed to load the configuration");
+            let conn = InferConnection::establish(&database_url).unwrap_or_else(|_| {
+                panic!("Unable to connect to database with url: {}", database_url)
+            });
+            print_schema::print_graphql_schema(
                 &conn,
                 schema.as_ref().map(|s| s as &str),
+                &table_filter,
+                &config,
                 &mut std::io::stdout(),
             )
         }
         .expect("Failed to infer the schema"),
+        Wundergraph::New {
+            path,
+            database_url,
+            schema,
+            migrations,
+            listen_url,
+            wundergraph_path,
+            table_filter,
+            config,
+        } => {
+            let config = Config::load(config.as_ref().map(PathBuf::as_path))
+                .expect("Failed to load the configuration");
+            let conn = InferConnection::establish(&database_url).unwrap_or_else(|_| {
+                panic!("Unable to connect to database with url: {}", database_url)
+            });
+            new_project::create_project(
+                &conn,
+                &NewProject {
+                    path: &path,
+                    database_url: &database_url,
+                    schema_name: schema.as_ref().map(|s| s as &str),
+                    table_filter: &table_filter,
+                    config: &config,
+                    migrations: migrations.as_ref().map(PathBuf::as_path),
+                    listen_url: &listen_url,
+                    wundergraph_path: wundergraph_path.as_ref().map(PathBuf::as_path),
+                },
+            )
+        }
+        .expect("Failed to create the new project"),
+        Wundergraph::Check {
+            database_url,
+            schema,
+            table_filter,
+            config,
+            generated,
+            snapshot,
+            write_snapshot,
+        } => {
+            let config = Config::load(config.as_ref().map(PathBuf::as_path))
+                .expect("Failed to load the configuration");
+            let conn = InferConnection::establish(&database_url).unwrap_or_else(|_| {
+                panic!("Unable to connect to database with url: {}", database_url)
+            });
+            let actual = SchemaSnapshot::from_database(
+                &conn,
+                schema.as_ref().map(|s| s as &str),
+                &table_filter,
+                &config,
+            )
+            .expect("Failed to infer the schema");
+            if let Some(path) = write_snapshot {
+                actual.save(&path).expect("Failed to write the snapshot");
+                return;
+            }
+            let expected = match (generated, snapshot) {
+                (Some(path), _) => SchemaSnapshot::from_generated(&path),
+                (None, Some(path)) => SchemaSnapshot::load(&path),
+                (None, None) => unreachable!("Ensured by structopt"),
+            }
+            .expect("Failed to load the expected schema");
+            let differences = expected.diff(&actual);
+            for d in &differences {
+                println!("{}", d);
+            }
+            if !differences.is_empty() {
+                std::process::exit(1);
+            }
+        }
     }
 }
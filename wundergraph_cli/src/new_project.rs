@@ -0,0 +1,300 @@
+use crate::config::Config;
+use crate::database::InferConnection;
+use crate::print_schema::{self, ExternalTypes, TableFilter};
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+
+/// The generated code requires the wundergraph release
+/// matching this version of the cli
+const WUNDERGRAPH_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const MIGRATIONS: &str = r#"embed_migrations!();
+        embedded_migrations::run(&*conn).expect("Failed to run migrations");"#;
+
+#[derive(Debug)]
+pub struct NewProject<'a> {
+    pub path: &'a Path,
+    pub database_url: &'a str,
+    pub schema_name: Option<&'a str>,
+    pub table_filter: &'a TableFilter,
+    pub config: &'a Config,
+    pub migrations: Option<&'a Path>,
+    pub listen_url: &'a str,
+    /// Use a local wundergraph checkout instead of the released crate
+    pub wundergraph_path: Option<&'a Path>,
+}
+
+pub fn create_project(
+    connection: &InferConnection,
+    project: &NewProject<'_>,
+) -> Result<(), Box<dyn Error>> {
+    if project.path.exists() && project.path.read_dir()?.next().is_some() {
+        return Err(format!(
+            "Target directory `{}` already exists and is not empty",
+            project.path.display()
+        )
+        .into());
+    }
+    let name = project
+        .path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| format!("Invalid project path `{}`", project.path.display()))?;
+
+    let (backend, connection_type) = match connection {
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(_) => ("postgres", "PgConnection"),
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) => ("sqlite", "SqliteConnection"),
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(_) => {
+            return Err("Wundergraph does not support mysql, use postgres or sqlite instead".into())
+        }
+    };
+    let (table_data, foreign_keys) = print_schema::load_schema(
+        connection,
+        project.schema_name,
+        project.table_filter,
+        project.config,
+    )?;
+
+    let src = project.path.join("src");
+    fs::create_dir_all(&src)?;
+
+    let mut cargo_toml = File::create(project.path.join("Cargo.toml"))?;
+    write_cargo_toml(
+        &mut cargo_toml,
+        name,
+        backend,
+        project.wundergraph_path,
+        &ExternalTypes::new(&table_data),
+    )?;
+
+    let mut api = File::create(src.join("api.rs"))?;
+    print_schema::write_schema(&table_data, foreign_keys, project.config, &mut api)?;
+
+    let mut main = File::create(src.join("main.rs"))?;
+    write!(
+        main,
+        include_str!("print_schema/template_main.rs"),
+        conn = connection_type,
+        db_url = project.database_url.escape_default(),
+        migrations = MIGRATIONS,
+        listen_url = project.listen_url.escape_default(),
+        connection_customizer = "",
+        customize_pool = "",
+        pool_size = 10
+    )?;
+
+    let migrations = project.path.join("migrations");
+    if let Some(source) = project.migrations {
+        copy_dir(source, &migrations)?;
+    } else {
+        // The migrations are embedded into the binary at compile time
+        // so the directory needs to exist, even if it is empty
+        fs::create_dir_all(&migrations)?;
+        File::create(migrations.join(".gitkeep"))?;
+    }
+
+    Ok(())
+}
+
+fn write_cargo_toml<W: Write>(
+    out: &mut W,
+    name: &str,
+    backend: &str,
+    wundergraph_path: Option<&Path>,
+    types: &ExternalTypes,
+) -> Result<(), Box<dyn Error>> {
+    let mut diesel_features = vec![backend, "r2d2"];
+    let mut wundergraph_features = vec![backend];
+    if !types.chrono.is_empty() {
+        diesel_features.push("chrono");
+        wundergraph_features.push("chrono");
+    }
+    if types.uuid {
+        diesel_features.push("uuidv07");
+        wundergraph_features.push("uuid");
+    }
+    if types.bigdecimal {
+        diesel_features.push("numeric");
+        wundergraph_features.push("bigdecimal");
+    }
+    let features = |features: &[&str]| {
+        features
+            .iter()
+            .map(|f| format!("\"{}\"", f))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    writeln!(out, "[package]")?;
+    writeln!(out, "name = \"{}\"", name)?;
+    writeln!(out, "version = \"0.1.0\"")?;
+    writeln!(out, "edition = \"2018\"")?;
+    writeln!(out)?;
+    writeln!(out, "[dependencies]")?;
+    writeln!(
+        out,
+        "diesel = {{ version = \"1.4\", features = [{}] }}",
+        features(&diesel_features)
+    )?;
+    writeln!(out, "diesel_migrations = \"1.4\"")?;
+    let wundergraph = match wundergraph_path {
+        Some(path) => format!("path = \"{}\"", path.display().to_string().escape_default()),
+        None => format!("version = \"{}\"", WUNDERGRAPH_VERSION),
+    };
+    writeln!(
+        out,
+        "wundergraph = {{ {}, features = [{}] }}",
+        wundergraph,
+        features(&wundergraph_features)
+    )?;
+    writeln!(out, "juniper = \"0.14\"")?;
+    writeln!(out, "failure = \"0.1\"")?;
+    writeln!(out, "actix-web = \"1\"")?;
+    writeln!(
+        out,
+        "serde = {{ version = \"1\", features = [\"derive\"] }}"
+    )?;
+    writeln!(out, "serde_json = \"1\"")?;
+    if !types.chrono.is_empty() {
+        writeln!(out, "chrono = \"0.4\"")?;
+    }
+    if types.uuid {
+        writeln!(out, "uuid = \"0.7\"")?;
+    }
+    if types.bigdecimal {
+        writeln!(out, "bigdecimal = \">= 0.0.10, < 0.2\"")?;
+    }
+    Ok(())
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(backend: &str, types: &ExternalTypes) -> String {
+        let mut out = Vec::new();
+        write_cargo_toml(&mut out, "service", backend, None, types).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn manifest_without_external_types() {
+        let manifest = manifest("sqlite", &ExternalTypes::default());
+        assert!(manifest.contains("name = \"service\"\n"));
+        assert!(manifest
+            .contains("diesel = { version = \"1.4\", features = [\"sqlite\", \"r2d2\"] }\n"));
+        assert!(manifest.contains(&format!(
+            "wundergraph = {{ version = \"{}\", features = [\"sqlite\"] }}\n",
+            WUNDERGRAPH_VERSION
+        )));
+        assert!(!manifest.contains("chrono"));
+        assert!(!manifest.contains("uuid"));
+        assert!(!manifest.contains("bigdecimal"));
+    }
+
+    #[test]
+    fn manifest_with_external_types() {
+        let types = ExternalTypes {
+            chrono: vec!["NaiveDateTime"],
+            uuid: true,
+            bigdecimal: true,
+        };
+        let manifest = manifest("postgres", &types);
+        assert!(manifest.contains(
+            "diesel = { version = \"1.4\", features = [\"postgres\", \"r2d2\", \"chrono\", \"uuidv07\", \"numeric\"] }\n"
+        ));
+        assert!(manifest.contains(&format!(
+            "wundergraph = {{ version = \"{}\", features = [\"postgres\", \"chrono\", \"uuid\", \"bigdecimal\"] }}\n",
+            WUNDERGRAPH_VERSION
+        )));
+        assert!(manifest.contains("chrono = \"0.4\"\n"));
+        assert!(manifest.contains("uuid = \"0.7\"\n"));
+        assert!(manifest.contains("bigdecimal = \">= 0.0.10, < 0.2\"\n"));
+        // The manifest has to be valid toml
+        let manifest: toml::Value = toml::from_str(&manifest).unwrap();
+        assert_eq!(manifest["package"]["name"].as_str(), Some("service"));
+    }
+
+    #[test]
+    fn manifest_with_local_wundergraph() {
+        let mut out = Vec::new();
+        write_cargo_toml(
+            &mut out,
+            "service",
+            "sqlite",
+            Some(Path::new("/src/wundergraph")),
+            &ExternalTypes::default(),
+        )
+        .unwrap();
+        let manifest = String::from_utf8(out).unwrap();
+        assert!(manifest
+            .contains("wundergraph = { path = \"/src/wundergraph\", features = [\"sqlite\"] }\n"));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn scaffolded_project_builds() {
+        use diesel::connection::SimpleConnection;
+        use diesel::prelude::Connection;
+        use std::process::Command;
+
+        let db_url = std::env::var("DATABASE_URL").unwrap();
+        let conn = diesel::pg::PgConnection::establish(&db_url).unwrap();
+        conn.begin_test_transaction().unwrap();
+        conn.batch_execute(
+            "CREATE SCHEMA new_project_test;
+             CREATE TABLE new_project_test.users(id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+             CREATE TABLE new_project_test.posts(
+                 id SERIAL PRIMARY KEY,
+                 author INTEGER REFERENCES new_project_test.users(id),
+                 title TEXT NOT NULL,
+                 created TIMESTAMP NOT NULL
+             );",
+        )
+        .unwrap();
+
+        let tmp_dir = tempdir::TempDir::new("new_project_test").unwrap();
+        let path = tmp_dir.path().join("wundergraph_new_project_test");
+        let wundergraph = Path::new(env!("CARGO_MANIFEST_DIR")).join("../wundergraph");
+        create_project(
+            &InferConnection::Pg(conn),
+            &NewProject {
+                path: &path,
+                database_url: &db_url,
+                schema_name: Some("new_project_test"),
+                table_filter: &TableFilter::default(),
+                config: &Config::default(),
+                migrations: None,
+                listen_url: "127.0.0.1:8002",
+                wundergraph_path: Some(&wundergraph),
+            },
+        )
+        .unwrap();
+
+        let status = Command::new("cargo")
+            .arg("build")
+            .current_dir(&path)
+            .status()
+            .unwrap();
+        assert!(status.success(), "Failed to build the scaffolded project");
+    }
+}
@@ -1,56 +1,315 @@
+use crate::config::Config;
 use crate::database::InferConnection;
 use crate::infer_schema_internals::*;
 use std::error::Error;
+use std::fs::{self, File};
 use std::io::Write;
+use std::path::Path;
 
 mod print_helper;
+mod sdl;
+mod table_filter;
+pub use self::print_helper::column_sql_type;
 use self::print_helper::*;
+pub use self::table_filter::TableFilter;
 
 pub fn print<W: Write>(
     connection: &InferConnection,
     schema_name: Option<&str>,
+    table_filter: &TableFilter,
+    config: &Config,
     out: &mut W,
 ) -> Result<(), Box<dyn Error>> {
-    let table_names = load_table_names(connection, schema_name)?;
-    let foreign_keys = load_foreign_key_constraints(connection, schema_name)?;
-    let foreign_keys =
-        remove_unsafe_foreign_keys_for_codegen(connection, &foreign_keys, &table_names);
+    let (table_data, foreign_keys) = load_schema(connection, schema_name, table_filter, config)?;
+    write_schema(&table_data, foreign_keys, config, out)
+}
 
-    let table_data = table_names
-        .into_iter()
-        .map(|t| load_table_data(connection, t))
-        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+/// Writes the code generated by `print` for an already loaded schema
+pub fn write_schema<W: Write>(
+    table_data: &[TableData],
+    foreign_keys: Vec<ForeignKeyConstraint>,
+    config: &Config,
+    out: &mut W,
+) -> Result<(), Box<dyn Error>> {
     let definitions = TableDefinitions {
-        tables: &table_data,
+        tables: table_data,
         include_docs: false,
         import_types: None,
+        config,
     };
     let graphql = GraphqlDefinition {
-        tables: &table_data,
+        tables: table_data,
         foreign_keys,
+        config,
     };
 
     let mutations = GraphqlMutations {
+        tables: table_data,
+        config,
+    };
+    write_imports(out, &ExternalTypes::new(table_data))?;
+    writeln!(out)?;
+    writeln!(out, "{}", definitions)?;
+    writeln!(out)?;
+    writeln!(out, "{}", graphql)?;
+    writeln!(out)?;
+    writeln!(out, "{}", mutations)?;
+    Ok(())
+}
+
+/// Writes the GraphQL schema exposed by the code generated by `print`
+/// in the GraphQL schema definition language
+pub fn print_graphql_schema<W: Write>(
+    connection: &InferConnection,
+    schema_name: Option<&str>,
+    table_filter: &TableFilter,
+    config: &Config,
+    out: &mut W,
+) -> Result<(), Box<dyn Error>> {
+    let (table_data, foreign_keys) = load_schema(connection, schema_name, table_filter, config)?;
+    let schema = self::sdl::GraphqlSchema {
         tables: &table_data,
+        foreign_keys: &foreign_keys,
+        config,
     };
+    write!(out, "{}", schema)?;
+    Ok(())
+}
+
+/// Writes the generated code as module into the given directory
+///
+/// The directory will contain a `mod.rs` file, a `schema.rs` file
+/// containing all table definitions, one module per entity containing
+/// the entity and the corresponding insert and update types, and the
+/// `query.rs` and `mutation.rs` files containing the query and mutation
+/// objects.
+pub fn print_to_dir(
+    connection: &InferConnection,
+    schema_name: Option<&str>,
+    table_filter: &TableFilter,
+    config: &Config,
+    out_dir: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let (table_data, foreign_keys) = load_schema(connection, schema_name, table_filter, config)?;
+    fs::create_dir_all(out_dir)?;
+
+    let mut schema = File::create(out_dir.join("schema.rs"))?;
+    writeln!(
+        schema,
+        "{}",
+        TableDefinitions {
+            tables: &table_data,
+            include_docs: false,
+            import_types: None,
+            config,
+        }
+    )?;
+
+    for t in &table_data {
+        let module = entity_module_name(&t.name.name);
+        let table_config = config.table(&t.name);
+        let (insert, update, _) = generated_mutations(t, table_config);
+        let mut entity = File::create(out_dir.join(format!("{}.rs", module)))?;
+        write_imports(&mut entity, &ExternalTypes::new(std::slice::from_ref(t)))?;
+        writeln!(entity, "use super::schema::{};", t.name.name)?;
+        let enum_types = enum_types(std::slice::from_ref(t));
+        if !enum_types.is_empty() {
+            let names = enum_types.iter().map(|e| e.rust_name()).collect::<Vec<_>>();
+            writeln!(entity, "use super::schema::{{{}}};", names.join(", "))?;
+        }
+        writeln!(entity)?;
+        writeln!(
+            entity,
+            "{}",
+            GraphqlData {
+                table: t,
+                foreign_keys: &foreign_keys,
+                config: table_config,
+                paths: ItemPaths::SeparateModules,
+            }
+        )?;
+        if insert {
+            writeln!(
+                entity,
+                "{}",
+                GraphqlInsertable {
+                    table: t,
+                    config: table_config,
+                }
+            )?;
+        }
+        if update {
+            writeln!(
+                entity,
+                "{}",
+                GraphqlChangeSet {
+                    table: t,
+                    config: table_config,
+                }
+            )?;
+        }
+    }
+
+    let mut query = File::create(out_dir.join("query.rs"))?;
+    for t in &table_data {
+        writeln!(
+            query,
+            "use super::{}::{};",
+            entity_module_name(&t.name.name),
+            fix_table_name(&t.name.name)
+        )?;
+    }
+    writeln!(query)?;
+    write!(
+        query,
+        "{}",
+        GraphqlQuery {
+            tables: &table_data,
+            config,
+        }
+    )?;
+
+    let mut mutation = File::create(out_dir.join("mutation.rs"))?;
+    for t in &table_data {
+        let (insert, update, delete) = generated_mutations(t, config.table(&t.name));
+        if !insert && !update && !delete {
+            continue;
+        }
+        let name = fix_table_name(&t.name.name);
+        let mut items = vec![name.clone()];
+        if insert {
+            items.push(format!("New{}", name));
+        }
+        if update {
+            items.push(format!("{}Changeset", name));
+        }
+        writeln!(
+            mutation,
+            "use super::{}::{{{}}};",
+            entity_module_name(&t.name.name),
+            items.join(", ")
+        )?;
+    }
+    writeln!(mutation)?;
+    write!(
+        mutation,
+        "{}",
+        GraphqlMutationObject {
+            tables: &table_data,
+            config,
+        }
+    )?;
+
+    let mut module = File::create(out_dir.join("mod.rs"))?;
+    writeln!(module, "pub mod schema;")?;
+    for t in &table_data {
+        writeln!(module, "pub mod {};", entity_module_name(&t.name.name))?;
+    }
+    writeln!(module, "mod mutation;")?;
+    writeln!(module, "mod query;")?;
+    writeln!(module)?;
+    writeln!(module, "pub use self::mutation::Mutation;")?;
+    writeln!(module, "pub use self::query::Query;")?;
+    Ok(())
+}
+
+pub fn load_schema(
+    connection: &InferConnection,
+    schema_name: Option<&str>,
+    table_filter: &TableFilter,
+    config: &Config,
+) -> Result<(Vec<TableData>, Vec<ForeignKeyConstraint>), Box<dyn Error>> {
+    let table_names = load_table_names(connection, schema_name)?
+        .into_iter()
+        .filter(|t| !table_filter.should_ignore_table(t))
+        .collect::<Vec<_>>();
+    let foreign_keys = load_foreign_key_constraints(connection, schema_name)?;
+    let foreign_keys =
+        remove_unsafe_foreign_keys_for_codegen(connection, &foreign_keys, &table_names);
+
+    let mut types = TypeCache::default();
+    let mut table_data = table_names
+        .into_iter()
+        .map(|t| load_table_data(connection, t, &mut types))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+    if table_filter.include_views() {
+        for view in load_view_names(connection, schema_name)? {
+            if table_filter.should_ignore_table(&view) {
+                continue;
+            }
+            let key_columns = match config.table(&view).primary_key {
+                Some(ref key_columns) => key_columns.clone(),
+                None => vec![table_filter.view_key_column().to_owned()],
+            };
+            table_data.push(load_view_data(connection, view, &key_columns, &mut types)?);
+        }
+        table_data.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    Ok((table_data, foreign_keys))
+}
+
+/// Types from external crates used by the generated code
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExternalTypes {
+    pub chrono: Vec<&'static str>,
+    pub uuid: bool,
+    pub bigdecimal: bool,
+}
+
+impl ExternalTypes {
+    pub fn new(tables: &[TableData]) -> Self {
+        let mut ret = Self::default();
+        for column in tables.iter().flat_map(|t| &t.column_data) {
+            let chrono: &[&'static str] = match &*column.ty.rust_name {
+                "Timestamptz" => &["DateTime", "Utc"],
+                "Timestamp" => &["NaiveDateTime"],
+                "Date" => &["NaiveDate"],
+                "Time" => &["NaiveTime"],
+                "Uuid" => {
+                    ret.uuid = true;
+                    &[]
+                }
+                "Numeric" => {
+                    ret.bigdecimal = true;
+                    &[]
+                }
+                _ => &[],
+            };
+            for name in chrono {
+                if !ret.chrono.contains(name) {
+                    ret.chrono.push(name);
+                }
+            }
+        }
+        ret.chrono.sort();
+        ret
+    }
+}
+
+fn write_imports<W: Write>(out: &mut W, types: &ExternalTypes) -> Result<(), Box<dyn Error>> {
+    if !types.chrono.is_empty() {
+        writeln!(out, "use chrono::{{{}}};", types.chrono.join(", "))?;
+    }
+    if types.uuid {
+        writeln!(out, "use uuid::Uuid;")?;
+    }
+    if types.bigdecimal {
+        writeln!(out, "use bigdecimal::BigDecimal;")?;
+    }
     writeln!(
         out,
         "use wundergraph::query_builder::types::{{HasMany, HasOne}};"
     )?;
     writeln!(out, "use wundergraph::scalar::WundergraphScalarValue;")?;
     writeln!(out, "use wundergraph::WundergraphEntity;")?;
-    writeln!(out)?;
-    writeln!(out, "{}", definitions)?;
-    writeln!(out)?;
-    writeln!(out, "{}", graphql)?;
-    writeln!(out)?;
-    writeln!(out, "{}", mutations)?;
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
 
     #[cfg(any(
         all(feature = "postgres", feature = "sqlite"),
@@ -59,6 +318,18 @@ mod tests {
     ))]
     compile_error!("Tests are only compatible with one backend");
 
+    /// Runs each request of the round trip server inside of a test transaction
+    const TEST_CONNECTION_CUSTOMIZER: &str = r#"#[derive(Debug)]
+struct ConnectionHandler;
+
+impl<E> diesel::r2d2::CustomizeConnection<{conn}, E> for ConnectionHandler {{
+    fn on_acquire(&self, conn: &mut {conn}) -> Result<(), E> {{
+        diesel::Connection::begin_test_transaction(conn).unwrap();
+        Ok(())
+    }}
+}}
+"#;
+
     fn get_connection() -> InferConnection {
         use diesel::prelude::Connection;
         let db_url = std::env::var("DATABASE_URL").unwrap();
@@ -143,12 +414,232 @@ mod tests {
 
         let mut out = Vec::<u8>::new();
 
-        print(&conn, Some("infer_test"), &mut out).unwrap();
+        print(
+            &conn,
+            Some("infer_test"),
+            &TableFilter::default(),
+            &Config::default(),
+            &mut out,
+        )
+        .unwrap();
+
+        let s = String::from_utf8(out).unwrap();
+        insta::assert_snapshot!(&s);
+    }
+
+    #[test]
+    fn infer_schema_modules() {
+        let conn = get_connection();
+        setup_simple_schema(&conn);
+
+        let tmp_dir = tempdir::TempDir::new("infer_schema_modules").unwrap();
+        let out_dir = tmp_dir.path().join("api");
+
+        print_to_dir(
+            &conn,
+            Some("infer_test"),
+            &TableFilter::default(),
+            &Config::default(),
+            &out_dir,
+        )
+        .unwrap();
+
+        let read = |name: &str| std::fs::read_to_string(out_dir.join(name)).unwrap();
+
+        let mut files = std::fs::read_dir(&out_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        let s = files
+            .iter()
+            .map(|name| format!("// {}\n{}", name, read(name)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        insta::assert_snapshot!(&s);
+    }
+
+    #[test]
+    fn infer_graphql_schema() {
+        let conn = get_connection();
+        setup_simple_schema(&conn);
+
+        let mut out = Vec::<u8>::new();
+        print_graphql_schema(
+            &conn,
+            Some("infer_test"),
+            &TableFilter::default(),
+            &Config::default(),
+            &mut out,
+        )
+        .unwrap();
+
+        let s = String::from_utf8(out).unwrap();
+        assert!(s.contains(
+            "type Post {\n    id: Int!\n    author: User\n    title: String!\n    \
+             content: String\n    comments: [Comment!]!\n}"
+        ));
+        assert!(s.contains("    author: UserFilter\n    title: Filter_String_\n"));
+        assert!(s.contains("    content: Filter_Nullable_String__\n"));
+        assert!(s.contains(
+            "input Filter_Nullable_String__ {\n    eq: String\n    not_eq: String\n    \
+             eq_any: [String]\n    is_null: Boolean\n    like: String\n}"
+        ));
+        assert!(s.contains("enum PostColumns {\n    id\n    author\n    title\n    content\n}"));
+        assert!(s.contains("input NewPost {\n    author: Int\n    title: String!\n"));
+        assert!(s.contains(
+            "Posts(filter: PostFilter, limit: Int, offset: Int, order: [PostOrderBy!]): [Post!]!"
+        ));
+        assert!(s.contains("PostsByIds(ids: [ID!]!): [Post]!\n"));
+        assert!(s.contains("CreatePost(NewPost: NewPost!): Post"));
+        assert!(s.contains("UpdatePost(UpdatePost: PostChangeset!): Post"));
+        assert!(s.contains("scalar BigInt\n"));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn infer_schema_with_comments() {
+        use diesel::connection::SimpleConnection;
+
+        let conn = get_connection();
+        setup_simple_schema(&conn);
+        match conn {
+            InferConnection::Pg(ref conn) => conn
+                .batch_execute(
+                    "COMMENT ON TABLE infer_test.users IS 'All registered users';\
+                     COMMENT ON COLUMN infer_test.users.name IS 'The full name\nof a user';",
+                )
+                .unwrap(),
+        }
+
+        let mut out = Vec::<u8>::new();
+        print(
+            &conn,
+            Some("infer_test"),
+            &TableFilter::default(),
+            &Config::default(),
+            &mut out,
+        )
+        .unwrap();
+
+        let s = String::from_utf8(out).unwrap();
+        insta::assert_snapshot!(&s);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn infer_schema_with_views() {
+        use diesel::connection::SimpleConnection;
+        use structopt::StructOpt;
+
+        let conn = get_connection();
+        setup_simple_schema(&conn);
+        match conn {
+            InferConnection::Pg(ref conn) => conn
+                .batch_execute(
+                    "CREATE VIEW infer_test.post_stats AS \
+                     SELECT p.id AS post_id, count(c.id) AS comment_count \
+                     FROM infer_test.posts p \
+                     LEFT JOIN infer_test.comments c ON c.post = p.id \
+                     GROUP BY p.id;",
+                )
+                .unwrap(),
+        }
+        let config: Config = toml::from_str(
+            r#"
+            [tables.post_stats]
+            primary_key = ["post_id"]
+            "#,
+        )
+        .unwrap();
+        let table_filter = TableFilter::from_iter(&["wundergraph", "--include-views"]);
+
+        let mut out = Vec::<u8>::new();
+        print(&conn, Some("infer_test"), &table_filter, &config, &mut out).unwrap();
+
+        let s = String::from_utf8(out).unwrap();
+        insta::assert_snapshot!(&s);
+
+        let mut out = Vec::<u8>::new();
+        assert!(print(
+            &conn,
+            Some("infer_test"),
+            &table_filter,
+            &Config::default(),
+            &mut out
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn infer_schema_with_enums() {
+        use diesel::connection::SimpleConnection;
+
+        let conn = get_connection();
+        setup_simple_schema(&conn);
+        match conn {
+            InferConnection::Pg(ref conn) => conn
+                .batch_execute(
+                    "CREATE TYPE public.post_state AS ENUM ('archived');\
+                     CREATE TYPE infer_test.post_state AS ENUM ('draft', 'in review', 'published');\
+                     ALTER TABLE infer_test.posts ADD COLUMN state infer_test.post_state NOT NULL;",
+                )
+                .unwrap(),
+        }
+
+        let mut out = Vec::<u8>::new();
+        print(
+            &conn,
+            Some("infer_test"),
+            &TableFilter::default(),
+            &Config::default(),
+            &mut out,
+        )
+        .unwrap();
 
         let s = String::from_utf8(out).unwrap();
         insta::assert_snapshot!(&s);
     }
 
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn infer_schema_with_multiple_and_self_references() {
+        use diesel::connection::SimpleConnection;
+
+        let conn = get_connection();
+        setup_simple_schema(&conn);
+        match conn {
+            InferConnection::Pg(ref conn) => conn
+                .batch_execute(
+                    "ALTER TABLE infer_test.posts \
+                     ADD COLUMN editor INTEGER REFERENCES infer_test.users(id);\
+                     ALTER TABLE infer_test.comments \
+                     ADD COLUMN parent INTEGER REFERENCES infer_test.comments(id);",
+                )
+                .unwrap(),
+        }
+
+        let mut out = Vec::<u8>::new();
+        print(
+            &conn,
+            Some("infer_test"),
+            &TableFilter::default(),
+            &Config::default(),
+            &mut out,
+        )
+        .unwrap();
+
+        let s = String::from_utf8(out).unwrap();
+        assert!(s.contains("    author: Option<HasOne<i32, User>>,\n"));
+        assert!(s.contains("    editor: Option<HasOne<i32, User>>,\n"));
+        assert!(s.contains("    posts_by_author: HasMany<Post, posts::author>,\n"));
+        assert!(s.contains("    posts_by_editor: HasMany<Post, posts::editor>,\n"));
+        assert!(s.contains("    comments: HasMany<Comment, comments::commenter>,\n"));
+        assert!(s.contains("    parent: Option<HasOne<i32, Comment>>,\n"));
+        assert!(s.contains("    comments_by_parent: HasMany<Comment, comments::parent>,\n"));
+    }
+
     #[test]
     fn round_trip() {
         use std::fs::File;
@@ -158,6 +649,17 @@ mod tests {
         let conn = get_connection();
         setup_simple_schema(&conn);
 
+        let mut sdl = Vec::<u8>::new();
+        print_graphql_schema(
+            &conn,
+            Some("infer_test"),
+            &TableFilter::default(),
+            &Config::default(),
+            &mut sdl,
+        )
+        .unwrap();
+        let sdl = String::from_utf8(sdl).unwrap();
+
         let tmp_dir = tempdir::TempDir::new("roundtrip_test").unwrap();
 
         let listen_url = "127.0.0.1:8001";
@@ -171,7 +673,14 @@ mod tests {
 
         let api = tmp_dir.path().join("wundergraph_roundtrip_test/src/api.rs");
         let mut api_file = File::create(api).unwrap();
-        print(&conn, Some("infer_test"), &mut api_file).unwrap();
+        print(
+            &conn,
+            Some("infer_test"),
+            &TableFilter::default(),
+            &Config::default(),
+            &mut api_file,
+        )
+        .unwrap();
 
         let main = tmp_dir
             .path()
@@ -184,6 +693,10 @@ mod tests {
             acc += "\n";
             acc
         });
+        let migrations = format!(
+            "diesel::connection::SimpleConnection::batch_execute(&*conn, {:?}).unwrap();",
+            migrations
+        );
 
         #[cfg(feature = "postgres")]
         write!(
@@ -192,7 +705,10 @@ mod tests {
             conn = "PgConnection",
             db_url = std::env::var("DATABASE_URL").unwrap(),
             migrations = migrations,
-            listen_url = listen_url
+            listen_url = listen_url,
+            connection_customizer = format!(TEST_CONNECTION_CUSTOMIZER, conn = "PgConnection"),
+            customize_pool = "\n        .connection_customizer(Box::new(ConnectionHandler))",
+            pool_size = 1
         )
         .unwrap();
 
@@ -235,6 +751,7 @@ mod tests {
             )
             .unwrap();
         }
+        writeln!(cargo_toml_file, "{}", r#"diesel_migrations = "1.4""#).unwrap();
         writeln!(cargo_toml_file, "{}", r#"juniper = "0.14""#).unwrap();
         writeln!(cargo_toml_file, "{}", r#"failure = "0.1""#).unwrap();
         writeln!(cargo_toml_file, "{}", r#"actix-web = "1""#).unwrap();
@@ -309,7 +826,173 @@ mod tests {
             .unwrap();
         insta::assert_json_snapshot!(r.json::<serde_json::Value>().unwrap());
 
+        let introspection = serde_json::json!({ "query": INTROSPECTION_QUERY }).to_string();
+        let mut r = client
+            .post(&format!("http://{}/graphql", listen_url))
+            .body(introspection)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .send()
+            .unwrap();
+        let introspection = r.json::<serde_json::Value>().unwrap();
+
         child.kill().unwrap();
         child.wait().unwrap();
+
+        assert_eq!(
+            sdl_definitions(&sdl),
+            introspected_definitions(&introspection["data"]["__schema"]["types"])
+        );
+    }
+
+    const INTROSPECTION_QUERY: &str = "{
+        __schema {
+            types {
+                kind
+                name
+                fields { name args { ...InputValue } type { ...TypeRef } }
+                inputFields { ...InputValue }
+                enumValues { name }
+            }
+        }
+    }
+    fragment InputValue on __InputValue { name defaultValue type { ...TypeRef } }
+    fragment TypeRef on __Type {
+        kind name ofType { kind name ofType { kind name ofType { kind name } } }
+    }";
+
+    /// Normalize a field, argument or enum value definition, so that
+    /// the order of arguments does not matter
+    fn normalize_field(def: &str) -> String {
+        match (def.find('('), def.rfind(')')) {
+            (Some(start), Some(end)) => {
+                let mut args = def[start + 1..end].split(", ").collect::<Vec<_>>();
+                args.sort();
+                format!("{}({}){}", &def[..start], args.join(", "), &def[end + 1..])
+            }
+            _ => def.to_owned(),
+        }
+    }
+
+    /// Definitions of the printed schema as map from `kind name` to
+    /// the sorted list of fields, ignoring descriptions
+    fn sdl_definitions(sdl: &str) -> BTreeMap<String, Vec<String>> {
+        let mut definitions = BTreeMap::<_, Vec<_>>::new();
+        let mut current = None;
+        let mut in_description = false;
+        for line in sdl.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if line == "\"\"\"" {
+                in_description = !in_description;
+            } else if in_description {
+                continue;
+            } else if line == "}" {
+                current = None;
+            } else if let Some(fields) = current.as_ref().and_then(|c| definitions.get_mut(c)) {
+                fields.push(normalize_field(line));
+            } else if line.ends_with(" {") {
+                let name = line.trim_end_matches(" {").to_owned();
+                definitions.insert(name.clone(), Vec::new());
+                current = Some(name);
+            } else {
+                definitions.insert(line.to_owned(), Vec::new());
+            }
+        }
+        for fields in definitions.values_mut() {
+            fields.sort();
+        }
+        definitions
+    }
+
+    fn introspected_type(tpe: &serde_json::Value) -> String {
+        match tpe["kind"].as_str() {
+            Some("NON_NULL") => format!("{}!", introspected_type(&tpe["ofType"])),
+            Some("LIST") => format!("[{}]", introspected_type(&tpe["ofType"])),
+            _ => tpe["name"].as_str().unwrap().to_owned(),
+        }
+    }
+
+    fn introspected_input_value(value: &serde_json::Value) -> String {
+        let mut ret = format!(
+            "{}: {}",
+            value["name"].as_str().unwrap(),
+            introspected_type(&value["type"])
+        );
+        if let Some(default) = value["defaultValue"].as_str() {
+            ret += " = ";
+            ret += default;
+        }
+        ret
+    }
+
+    /// Definitions of the schema returned by the running server in
+    /// the same format as `sdl_definitions`, without builtin types
+    fn introspected_definitions(types: &serde_json::Value) -> BTreeMap<String, Vec<String>> {
+        types
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|t| {
+                let name = t["name"].as_str().unwrap();
+                if name.starts_with("__")
+                    || ["Int", "Float", "String", "Boolean", "ID"].contains(&name)
+                {
+                    return None;
+                }
+                let empty = Vec::new();
+                let (kind, mut fields) = match t["kind"].as_str().unwrap() {
+                    "OBJECT" => (
+                        "type",
+                        t["fields"]
+                            .as_array()
+                            .unwrap_or(&empty)
+                            .iter()
+                            .map(|f| {
+                                let args = f["args"]
+                                    .as_array()
+                                    .unwrap_or(&empty)
+                                    .iter()
+                                    .map(introspected_input_value)
+                                    .collect::<Vec<_>>();
+                                let args = if args.is_empty() {
+                                    String::new()
+                                } else {
+                                    format!("({})", args.join(", "))
+                                };
+                                normalize_field(&format!(
+                                    "{}{}: {}",
+                                    f["name"].as_str().unwrap(),
+                                    args,
+                                    introspected_type(&f["type"])
+                                ))
+                            })
+                            .collect::<Vec<_>>(),
+                    ),
+                    "INPUT_OBJECT" => (
+                        "input",
+                        t["inputFields"]
+                            .as_array()
+                            .unwrap_or(&empty)
+                            .iter()
+                            .map(introspected_input_value)
+                            .collect(),
+                    ),
+                    "ENUM" => (
+                        "enum",
+                        t["enumValues"]
+                            .as_array()
+                            .unwrap_or(&empty)
+                            .iter()
+                            .map(|v| v["name"].as_str().unwrap().to_owned())
+                            .collect(),
+                    ),
+                    "SCALAR" => ("scalar", Vec::new()),
+                    kind => (kind, Vec::new()),
+                };
+                fields.sort();
+                Some((format!("{} {}", kind, name), fields))
+            })
+            .collect()
     }
 }
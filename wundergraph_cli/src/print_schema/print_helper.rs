@@ -1,3 +1,4 @@
+use crate::config::{ColumnConfig, Config, TableConfig};
 use crate::infer_schema_internals::*;
 use std::fmt::{self, Display, Formatter, Write};
 
@@ -6,10 +7,16 @@ pub struct TableDefinitions<'a> {
     //    fk_constraints: Vec<ForeignKeyConstraint>,
     pub include_docs: bool,
     pub import_types: Option<&'a [String]>,
+    pub config: &'a Config,
 }
 
 impl<'a> Display for TableDefinitions<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for enum_type in enum_types(self.tables) {
+            writeln!(f, "{}", EnumDefinition { enum_type })?;
+            writeln!(f)?;
+        }
+
         let mut is_first = true;
         for table in self.tables {
             if is_first {
@@ -24,6 +31,7 @@ impl<'a> Display for TableDefinitions<'a> {
                     table,
                     include_docs: self.include_docs,
                     import_types: self.import_types,
+                    config: self.config.table(&table.name),
                 }
             )?;
         }
@@ -54,6 +62,7 @@ pub struct TableDefinition<'a> {
     table: &'a TableData,
     import_types: Option<&'a [String]>,
     include_docs: bool,
+    config: &'a TableConfig,
 }
 
 impl<'a> Display for TableDefinition<'a> {
@@ -68,12 +77,21 @@ impl<'a> Display for TableDefinition<'a> {
                     writeln!(out, "use {};", import)?;
                 }
                 writeln!(out)?;
+            } else {
+                let enum_types = enum_types(std::slice::from_ref(self.table));
+                if !enum_types.is_empty() {
+                    writeln!(out, "use diesel::sql_types::*;")?;
+                    for enum_type in enum_types {
+                        writeln!(out, "use super::{};", enum_type.sql_type_name())?;
+                    }
+                    writeln!(out)?;
+                }
             }
 
-            if self.include_docs {
-                for d in self.table.docs.lines() {
-                    writeln!(out, "///{}{}", if d.is_empty() { "" } else { " " }, d)?;
-                }
+            if let Some(ref comment) = self.table.comment {
+                write_docs(&mut out, comment)?;
+            } else if self.include_docs {
+                write_docs(&mut out, &self.table.docs)?;
             }
 
             write!(out, "{} (", self.table.name)?;
@@ -90,6 +108,7 @@ impl<'a> Display for TableDefinition<'a> {
                 ColumnDefinitions {
                     columns: &self.table.column_data,
                     include_docs: self.include_docs,
+                    config: self.config,
                 }
             )?;
         }
@@ -101,6 +120,7 @@ impl<'a> Display for TableDefinition<'a> {
 pub struct ColumnDefinitions<'a> {
     columns: &'a [ColumnDefinition],
     include_docs: bool,
+    config: &'a TableConfig,
 }
 
 impl<'a> Display for ColumnDefinitions<'a> {
@@ -109,16 +129,20 @@ impl<'a> Display for ColumnDefinitions<'a> {
             let mut out = PadAdapter::new(f);
             writeln!(out, "{{")?;
             for column in self.columns {
-                if self.include_docs {
-                    for d in column.docs.lines() {
-                        writeln!(out, "///{}{}", if d.is_empty() { "" } else { " " }, d)?;
-                    }
+                if let Some(ref comment) = column.comment {
+                    write_docs(&mut out, comment)?;
+                } else if self.include_docs {
+                    write_docs(&mut out, &column.docs)?;
                 }
+                let ty = SqlType {
+                    column,
+                    config: self.config.column(&column.sql_name),
+                };
                 if let Some(ref rust_name) = column.rust_name {
                     writeln!(out, r#"#[sql_name = "{}"]"#, column.sql_name)?;
-                    writeln!(out, "{} -> {},", rust_name, column.ty)?;
+                    writeln!(out, "{} -> {},", rust_name, ty)?;
                 } else {
-                    writeln!(out, "{} -> {},", column.sql_name, column.ty)?;
+                    writeln!(out, "{} -> {},", column.sql_name, ty)?;
                 }
             }
         }
@@ -127,6 +151,192 @@ impl<'a> Display for ColumnDefinitions<'a> {
     }
 }
 
+struct SqlType<'a> {
+    column: &'a ColumnDefinition,
+    config: &'a ColumnConfig,
+}
+
+impl<'a> Display for SqlType<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.config.sql_type {
+            Some(ref sql_type) if self.column.ty.is_nullable => write!(f, "Nullable<{}>", sql_type),
+            Some(ref sql_type) => write!(f, "{}", sql_type),
+            None => write!(f, "{}", self.column.ty),
+        }
+    }
+}
+
+/// The sql type used for the given column in `table!`
+pub fn column_sql_type(column: &ColumnDefinition, config: &TableConfig) -> String {
+    SqlType {
+        column,
+        config: config.column(&column.sql_name),
+    }
+    .to_string()
+}
+
+/// The rust type used for the given column, without the `Option<_>`
+/// wrapper of nullable columns
+pub fn column_rust_type(column: &ColumnDefinition, config: &ColumnConfig) -> String {
+    FieldType {
+        column: &column.ty,
+        rust_type: config.rust_type.as_ref().map(|t| t as &str),
+        allow_option: false,
+    }
+    .to_string()
+}
+
+/// Returns all enum types used by the given tables, without duplicates
+pub fn enum_types(tables: &[TableData]) -> Vec<&EnumType> {
+    let mut ret = Vec::<&EnumType>::new();
+    let enums = tables
+        .iter()
+        .flat_map(|t| &t.column_data)
+        .filter_map(|c| c.ty.enum_type.as_ref());
+    for enum_type in enums {
+        if !ret.iter().any(|e| e.name == enum_type.name) {
+            ret.push(enum_type);
+        }
+    }
+    ret
+}
+
+/// Definition of the diesel sql type and the rust enum
+/// for a custom enum type
+struct EnumDefinition<'a> {
+    enum_type: &'a EnumType,
+}
+
+impl<'a> Display for EnumDefinition<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rust_name = self.enum_type.rust_name();
+        let sql_type = self.enum_type.sql_type_name();
+        let variants = self
+            .enum_type
+            .variants
+            .iter()
+            .zip(self.enum_type.variant_names())
+            .collect::<Vec<_>>();
+
+        writeln!(f, "#[derive(SqlType)]")?;
+        match self.enum_type.pg_type_name {
+            Some(ref type_name) => writeln!(f, "#[postgres(type_name = \"{}\")]", type_name)?,
+            None => writeln!(f, "#[mysql_type = \"String\"]")?,
+        }
+        writeln!(f, "pub struct {};", sql_type)?;
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, AsExpression, FromSqlRow, \
+             juniper::GraphQLEnum, wundergraph::query_builder::types::WundergraphValue)]"
+        )?;
+        writeln!(f, "#[sql_type = \"{}\"]", sql_type)?;
+        write!(f, "pub enum {} {{", rust_name)?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            for (_, name) in &variants {
+                writeln!(out, "{},", name)?;
+            }
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "impl<DB: diesel::backend::Backend> diesel::serialize::ToSql<{}, DB> for {}",
+            sql_type, rust_name
+        )?;
+        writeln!(f, "where")?;
+        writeln!(
+            f,
+            "    str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,"
+        )?;
+        write!(f, "{{")?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            writeln!(out, "fn to_sql<W: std::io::Write>(")?;
+            writeln!(out, "    &self,")?;
+            writeln!(out, "    out: &mut diesel::serialize::Output<'_, W, DB>,")?;
+            write!(out, ") -> diesel::serialize::Result {{")?;
+            {
+                let mut out = PadAdapter::new(&mut out);
+                writeln!(out)?;
+                write!(out, "let value = match *self {{")?;
+                {
+                    let mut out = PadAdapter::new(&mut out);
+                    writeln!(out)?;
+                    for (value, name) in &variants {
+                        writeln!(out, "{}::{} => {:?},", rust_name, name, value)?;
+                    }
+                }
+                writeln!(out, "}};")?;
+                writeln!(
+                    out,
+                    "diesel::serialize::ToSql::<diesel::sql_types::Text, DB>::to_sql(value, out)"
+                )?;
+            }
+            writeln!(out, "}}")?;
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "impl<DB: diesel::backend::Backend> diesel::deserialize::FromSql<{}, DB> for {}",
+            sql_type, rust_name
+        )?;
+        writeln!(f, "where")?;
+        writeln!(
+            f,
+            "    String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,"
+        )?;
+        write!(f, "{{")?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            write!(
+                out,
+                "fn from_sql(bytes: Option<&DB::RawValue>) -> diesel::deserialize::Result<Self> {{"
+            )?;
+            {
+                let mut out = PadAdapter::new(&mut out);
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "let value = <String as diesel::deserialize::FromSql<\
+                     diesel::sql_types::Text, DB>>::from_sql(bytes)?;"
+                )?;
+                write!(out, "match &value as &str {{")?;
+                {
+                    let mut out = PadAdapter::new(&mut out);
+                    writeln!(out)?;
+                    for (value, name) in &variants {
+                        writeln!(out, "{:?} => Ok({}::{}),", value, rust_name, name)?;
+                    }
+                    writeln!(
+                        out,
+                        "v => Err(format!(\"Unrecognized enum variant `{{}}`\", v).into()),"
+                    )?;
+                }
+                writeln!(out, "}}")?;
+            }
+            writeln!(out, "}}")?;
+        }
+        writeln!(f, "}}")?;
+        Ok(())
+    }
+}
+
+fn write_docs<W: Write>(f: &mut W, docs: &str) -> fmt::Result {
+    for d in docs.lines() {
+        writeln!(f, "///{}{}", if d.is_empty() { "" } else { " " }, d)?;
+    }
+    Ok(())
+}
+
 /// Lifted directly from libcore/fmt/builders.rs
 pub struct PadAdapter<'a, W> {
     fmt: &'a mut W,
@@ -134,7 +344,7 @@ pub struct PadAdapter<'a, W> {
 }
 
 impl<'a, W: 'a> PadAdapter<'a, W> {
-    fn new(fmt: &'a mut W) -> PadAdapter<'a, W> {
+    pub fn new(fmt: &'a mut W) -> PadAdapter<'a, W> {
         PadAdapter {
             fmt,
             on_newline: false,
@@ -173,6 +383,7 @@ where
 pub struct GraphqlDefinition<'a> {
     pub tables: &'a [TableData],
     pub foreign_keys: Vec<ForeignKeyConstraint>,
+    pub config: &'a Config,
 }
 
 impl<'a> Display for GraphqlDefinition<'a> {
@@ -184,11 +395,31 @@ impl<'a> Display for GraphqlDefinition<'a> {
                 GraphqlData {
                     table: t,
                     foreign_keys: &self.foreign_keys,
+                    config: self.config.table(&t.name),
+                    paths: ItemPaths::SameModule,
                 }
             )?;
         }
         writeln!(f)?;
         writeln!(f)?;
+        write!(
+            f,
+            "{}",
+            GraphqlQuery {
+                tables: self.tables,
+                config: self.config,
+            }
+        )
+    }
+}
+
+pub struct GraphqlQuery<'a> {
+    pub tables: &'a [TableData],
+    pub config: &'a Config,
+}
+
+impl<'a> Display for GraphqlQuery<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "wundergraph::query_object!{{")?;
         {
             let mut out = PadAdapter::new(f);
@@ -198,6 +429,20 @@ impl<'a> Display for GraphqlDefinition<'a> {
                 let mut out = PadAdapter::new(&mut out);
                 writeln!(out)?;
                 for t in self.tables {
+                    let config = self.config.table(&t.name);
+                    let mut flags = Vec::new();
+                    if let Some(ref graphql_name) = config.graphql_name {
+                        flags.push(format!("graphql_name = \"{}\"", graphql_name));
+                    }
+                    if !config.filter {
+                        flags.push(String::from("filter = false"));
+                    }
+                    if !config.order {
+                        flags.push(String::from("order = false"));
+                    }
+                    if !flags.is_empty() {
+                        writeln!(out, "#[wundergraph({})]", flags.join(", "))?;
+                    }
                     let single = fix_table_name(&t.name.name);
                     writeln!(out, "{},", single)?;
                 }
@@ -209,9 +454,50 @@ impl<'a> Display for GraphqlDefinition<'a> {
     }
 }
 
-struct GraphqlData<'a> {
-    table: &'a TableData,
-    foreign_keys: &'a [ForeignKeyConstraint],
+pub struct GraphqlData<'a> {
+    pub table: &'a TableData,
+    pub foreign_keys: &'a [ForeignKeyConstraint],
+    pub config: &'a TableConfig,
+    pub paths: ItemPaths,
+}
+
+/// Determines how generated items refer to each other
+#[derive(Debug, Clone, Copy)]
+pub enum ItemPaths {
+    /// All items are placed in the same module
+    SameModule,
+    /// Each entity is placed in a separate module next to a `schema`
+    /// module containing the table definitions
+    SeparateModules,
+}
+
+impl ItemPaths {
+    fn entity(self, table: &TableName) -> String {
+        match self {
+            ItemPaths::SameModule => fix_table_name(&table.name),
+            ItemPaths::SeparateModules => format!(
+                "super::{}::{}",
+                entity_module_name(&table.name),
+                fix_table_name(&table.name)
+            ),
+        }
+    }
+
+    fn table(self, table: &TableName) -> String {
+        match self {
+            ItemPaths::SameModule => table.name.clone(),
+            ItemPaths::SeparateModules => format!("super::schema::{}", table.name),
+        }
+    }
+}
+
+/// Name of the module containing the entity for the given table
+pub fn entity_module_name(name: &str) -> String {
+    let mut name = name.to_lowercase();
+    if name.ends_with('s') {
+        name.pop();
+    }
+    name
 }
 
 fn uppercase_table_name(name: &str) -> String {
@@ -232,7 +518,7 @@ fn uppercase_table_name(name: &str) -> String {
         .fold(String::new(), |acc, s| acc + &s)
 }
 
-fn fix_table_name(name: &str) -> String {
+pub fn fix_table_name(name: &str) -> String {
     let mut name = uppercase_table_name(name);
     if name.ends_with('s') {
         name.pop();
@@ -262,6 +548,14 @@ where
 
 impl<'a> Display for GraphqlData<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(doc) = self
+            .config
+            .doc
+            .as_ref()
+            .or_else(|| self.table.comment.as_ref())
+        {
+            write_docs(f, doc)?;
+        }
         writeln!(
             f,
             "#[derive(Clone, Debug, Identifiable, WundergraphEntity)]"
@@ -281,6 +575,8 @@ impl<'a> Display for GraphqlData<'a> {
                         foreign_key: self.foreign_keys.iter().find(|f| f.child_table
                             == self.table.name
                             && f.foreign_key == c.sql_name),
+                        config: self.config.column(&c.sql_name),
+                        paths: self.paths,
                     }
                 )?;
             }
@@ -292,9 +588,9 @@ impl<'a> Display for GraphqlData<'a> {
                 writeln!(
                     out,
                     "{}: HasMany<{}, {}::{}>,",
-                    f.child_table.name,
-                    fix_table_name(&f.child_table.name),
-                    f.child_table.name,
+                    f.has_many_field_name(self.foreign_keys),
+                    self.paths.entity(&f.child_table),
+                    self.paths.table(&f.child_table),
                     f.foreign_key,
                 )?;
             }
@@ -307,12 +603,26 @@ impl<'a> Display for GraphqlData<'a> {
 struct GraphqlColumn<'a> {
     column: &'a ColumnDefinition,
     foreign_key: Option<&'a ForeignKeyConstraint>,
+    config: &'a ColumnConfig,
+    paths: ItemPaths,
 }
 
 impl<'a> Display for GraphqlColumn<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut tpe = GraphqlType {
-            sql_type: &self.column.ty,
+        if let Some(doc) = self
+            .config
+            .doc
+            .as_ref()
+            .or_else(|| self.column.comment.as_ref())
+        {
+            write_docs(f, doc)?;
+        }
+        if let Some(ref graphql_name) = self.config.graphql_name {
+            writeln!(f, "#[wundergraph(graphql_name = \"{}\")]", graphql_name)?;
+        }
+        let mut tpe = FieldType {
+            column: &self.column.ty,
+            rust_type: self.config.rust_type.as_ref().map(|t| t as &str),
             allow_option: true,
         };
         let name = self
@@ -322,7 +632,7 @@ impl<'a> Display for GraphqlColumn<'a> {
             .unwrap_or(&self.column.sql_name);
         if let Some(foreign_key) = self.foreign_key {
             tpe.allow_option = false;
-            let referenced = fix_table_name(&foreign_key.parent_table.name);
+            let referenced = self.paths.entity(&foreign_key.parent_table);
             if self.column.ty.is_nullable {
                 write!(f, "{}: Option<HasOne<{}, {}>>,", name, tpe, referenced)?;
             } else {
@@ -335,6 +645,33 @@ impl<'a> Display for GraphqlColumn<'a> {
     }
 }
 
+/// The rust type of a field, either given by the configuration
+/// or derived from the sql type
+struct FieldType<'a> {
+    column: &'a ColumnType,
+    rust_type: Option<&'a str>,
+    allow_option: bool,
+}
+
+impl<'a> Display for FieldType<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.rust_type {
+            Some(rust_type) if self.column.is_nullable && self.allow_option => {
+                write!(f, "Option<{}>", rust_type)
+            }
+            Some(rust_type) => write!(f, "{}", rust_type),
+            None => write!(
+                f,
+                "{}",
+                GraphqlType {
+                    sql_type: self.column,
+                    allow_option: self.allow_option,
+                }
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[allow(clippy::needless_borrow)]
 struct GraphqlType<'a> {
@@ -352,17 +689,10 @@ impl<'a> Display for GraphqlType<'a> {
                 t.allow_option = false;
                 write!(f, "Option<{}>", t)?;
             }
-            ColumnType {
-                is_array: true,
-                is_nullable,
-                ref rust_name,
-                is_unsigned,
-            } => {
+            ColumnType { is_array: true, .. } => {
                 let t = ColumnType {
                     is_array: false,
-                    is_nullable,
-                    rust_name: rust_name.clone(),
-                    is_unsigned,
+                    ..self.sql_type.clone()
                 };
                 write!(
                     f,
@@ -373,6 +703,33 @@ impl<'a> Display for GraphqlType<'a> {
                     }
                 )?;
             }
+            ColumnType {
+                enum_type: Some(ref enum_type),
+                ..
+            } => {
+                write!(f, "{}", enum_type.rust_name())?;
+            }
+            ColumnType {
+                ref rust_name,
+                is_unsigned: true,
+                ..
+            } if rust_name.eq_ignore_ascii_case("SmallInt") => {
+                write!(f, "u16")?;
+            }
+            ColumnType {
+                ref rust_name,
+                is_unsigned: true,
+                ..
+            } if rust_name.eq_ignore_ascii_case("Integer") => {
+                write!(f, "u32")?;
+            }
+            ColumnType {
+                ref rust_name,
+                is_unsigned: true,
+                ..
+            } if rust_name.eq_ignore_ascii_case("BigInt") => {
+                write!(f, "u64")?;
+            }
             ColumnType { ref rust_name, .. } if rust_name == "Int2" || rust_name == "SmallInt" => {
                 write!(f, "i16")?;
             }
@@ -401,12 +758,26 @@ impl<'a> Display for GraphqlType<'a> {
             ColumnType { ref rust_name, .. } if rust_name == "Timestamp" => {
                 write!(f, "NaiveDateTime")?;
             }
+            ColumnType { ref rust_name, .. } if rust_name == "Date" => {
+                write!(f, "NaiveDate")?;
+            }
+            ColumnType { ref rust_name, .. } if rust_name == "Time" => {
+                write!(f, "NaiveTime")?;
+            }
+            ColumnType { ref rust_name, .. } if rust_name == "Interval" => {
+                write!(f, "diesel::pg::data_types::PgInterval")?;
+            }
             ColumnType { ref rust_name, .. } if rust_name == "Uuid" => {
                 write!(f, "Uuid")?;
             }
             ColumnType { ref rust_name, .. } if rust_name == "Numeric" => {
                 write!(f, "BigDecimal")?;
             }
+            ColumnType { ref rust_name, .. }
+                if rust_name == "Bytea" || rust_name == "Binary" || rust_name == "Blob" =>
+            {
+                write!(f, "wundergraph::query_builder::types::Bytes")?;
+            }
             ColumnType { ref rust_name, .. } => write!(f, "{}", fix_table_name(rust_name))?,
         }
         Ok(())
@@ -415,15 +786,55 @@ impl<'a> Display for GraphqlType<'a> {
 
 pub struct GraphqlMutations<'a> {
     pub tables: &'a [TableData],
+    pub config: &'a Config,
 }
 
 impl<'a> Display for GraphqlMutations<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for t in self.tables {
-            writeln!(f, "{}", GraphqlInsertable { table: t })?;
-            writeln!(f, "{}", GraphqlChangeSet { table: t })?;
+        for t in self.tables.iter().filter(|t| !t.is_view) {
+            let config = self.config.table(&t.name);
+            if config.insert {
+                writeln!(f, "{}", GraphqlInsertable { table: t, config })?;
+            }
+            if config.update {
+                writeln!(f, "{}", GraphqlChangeSet { table: t, config })?;
+            }
         }
 
+        write!(
+            f,
+            "{}",
+            GraphqlMutationObject {
+                tables: self.tables,
+                config: self.config,
+            }
+        )
+    }
+}
+
+/// Returns which of the insert, update and delete mutations
+/// are generated for the given table
+///
+/// Views are always read only
+pub fn generated_mutations(table: &TableData, config: &TableConfig) -> (bool, bool, bool) {
+    if table.is_view {
+        return (false, false, false);
+    }
+    let only_primary_key = table.primary_key.len() == table.column_data.len();
+    (
+        config.insert && !only_primary_key,
+        config.update && !only_primary_key,
+        config.delete,
+    )
+}
+
+pub struct GraphqlMutationObject<'a> {
+    pub tables: &'a [TableData],
+    pub config: &'a Config,
+}
+
+impl<'a> Display for GraphqlMutationObject<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "wundergraph::mutation_object!{{")?;
         {
             let mut out = PadAdapter::new(f);
@@ -433,16 +844,23 @@ impl<'a> Display for GraphqlMutations<'a> {
                 let mut out = PadAdapter::new(&mut out);
                 writeln!(out)?;
                 for t in self.tables {
-                    if t.primary_key.len() == t.column_data.len() {
-                        writeln!(out, "{}(),", fix_table_name(&t.name.name))?;
-                    //writeln!(out, "{name}")
-                    } else {
-                        writeln!(
-                            out,
-                            "{name}(insert = New{name}, update = {name}Changeset, ),",
-                            name = fix_table_name(&t.name.name)
-                        )?;
+                    let (insert, update, delete) =
+                        generated_mutations(t, self.config.table(&t.name));
+                    if !insert && !update && !delete {
+                        continue;
+                    }
+                    let name = fix_table_name(&t.name.name);
+                    let mut args = String::new();
+                    if insert {
+                        args += &format!("insert = New{}, ", name);
+                    }
+                    if update {
+                        args += &format!("update = {}Changeset, ", name);
                     }
+                    if !delete {
+                        args += "delete = false";
+                    }
+                    writeln!(out, "{}({}),", name, args)?;
                 }
             }
             writeln!(out, "}}")?;
@@ -452,8 +870,9 @@ impl<'a> Display for GraphqlMutations<'a> {
     }
 }
 
-struct GraphqlInsertable<'a> {
-    table: &'a TableData,
+pub struct GraphqlInsertable<'a> {
+    pub table: &'a TableData,
+    pub config: &'a TableConfig,
 }
 
 impl<'a> Display for GraphqlInsertable<'a> {
@@ -476,12 +895,7 @@ impl<'a> Display for GraphqlInsertable<'a> {
             let mut out = PadAdapter::new(f);
             writeln!(out)?;
             for c in self.table.column_data.iter().filter(|c| !c.has_default) {
-                let t = GraphqlType {
-                    sql_type: &c.ty,
-                    allow_option: true,
-                };
-                let name = c.rust_name.as_ref().unwrap_or(&c.sql_name);
-                writeln!(out, "{}: {},", name, t)?;
+                write_input_field(&mut out, c, self.config.column(&c.sql_name))?;
             }
         }
         writeln!(f, "}}")?;
@@ -489,8 +903,9 @@ impl<'a> Display for GraphqlInsertable<'a> {
     }
 }
 
-struct GraphqlChangeSet<'a> {
-    table: &'a TableData,
+pub struct GraphqlChangeSet<'a> {
+    pub table: &'a TableData,
+    pub config: &'a TableConfig,
 }
 
 impl<'a> Display for GraphqlChangeSet<'a> {
@@ -514,15 +929,33 @@ impl<'a> Display for GraphqlChangeSet<'a> {
             let mut out = PadAdapter::new(f);
             writeln!(out)?;
             for c in &self.table.column_data {
-                let t = GraphqlType {
-                    sql_type: &c.ty,
-                    allow_option: true,
-                };
-                let name = c.rust_name.as_ref().unwrap_or(&c.sql_name);
-                writeln!(out, "{}: {},", name, t)?;
+                write_input_field(&mut out, c, self.config.column(&c.sql_name))?;
             }
         }
         writeln!(f, "}}")?;
         Ok(())
     }
 }
+
+fn write_input_field<W: Write>(
+    out: &mut W,
+    column: &ColumnDefinition,
+    config: &ColumnConfig,
+) -> fmt::Result {
+    if let Some(doc) = config.doc.as_ref().or_else(|| column.comment.as_ref()) {
+        write_docs(out, doc)?;
+    }
+    if let Some(ref graphql_name) = config.graphql_name {
+        writeln!(out, "#[graphql(name = \"{}\")]", graphql_name)?;
+    }
+    // Diesel is not able to insert unsigned rust values into the
+    // signed columns used to store them, so use the signed type here
+    let ty = column.ty.storage_type();
+    let t = FieldType {
+        column: &ty,
+        rust_type: config.rust_type.as_ref().map(|t| t as &str),
+        allow_option: true,
+    };
+    let name = column.rust_name.as_ref().unwrap_or(&column.sql_name);
+    writeln!(out, "{}: {},", name, t)
+}
@@ -0,0 +1,696 @@
+use super::print_helper::{
+    column_rust_type, enum_types, fix_table_name, generated_mutations, PadAdapter,
+};
+use crate::config::{ColumnConfig, Config, TableConfig};
+use crate::infer_schema_internals::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter, Write};
+
+/// Scalars that are part of every GraphQL schema
+const BUILTIN_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
+
+/// The GraphQL schema exposed by the code generated by `print-schema`
+pub struct GraphqlSchema<'a> {
+    pub tables: &'a [TableData],
+    pub foreign_keys: &'a [ForeignKeyConstraint],
+    pub config: &'a Config,
+}
+
+impl<'a> Display for GraphqlSchema<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let enums = enum_types(self.tables);
+        let mut scalars = BTreeSet::new();
+        let mut column_filters = BTreeMap::new();
+        let mut uses_date_parts = false;
+        let mut uses_order = false;
+        let mut uses_delete = false;
+        for t in self.tables {
+            let config = self.config.table(&t.name);
+            uses_order |= config.order;
+            uses_delete |= generated_mutations(t, config).2;
+            for c in &t.column_data {
+                let value = ColumnValue::new(c, config.column(&c.sql_name));
+                if !enums
+                    .iter()
+                    .any(|e| e.rust_name() == value.value.graphql_name)
+                {
+                    scalars.insert(value.value.graphql_name.clone());
+                    scalars.insert(value.value.input_name.clone());
+                }
+                if foreign_key_for(self.foreign_keys, t, c).is_none() {
+                    uses_date_parts |= !value.is_array
+                        && value.value.additional_filter == AdditionalFilter::DatePart;
+                    column_filters.insert(value.filter_name(), value);
+                }
+            }
+        }
+        if uses_delete {
+            scalars.insert(String::from("BigInt"));
+        }
+
+        let mut definitions = Vec::new();
+        let scalars = scalars
+            .into_iter()
+            .filter(|s| !BUILTIN_SCALARS.contains(&&**s))
+            .map(|s| format!("scalar {}\n", s))
+            .collect::<String>();
+        if !scalars.is_empty() {
+            definitions.push(scalars);
+        }
+        for enum_type in enums {
+            definitions.push(EnumDefinition { enum_type }.to_string());
+        }
+        if uses_order {
+            definitions.push(String::from("enum Order {\n    ASC\n    DESC\n}\n"));
+        }
+        for t in self.tables {
+            let config = self.config.table(&t.name);
+            let entity = Entity {
+                table: t,
+                foreign_keys: self.foreign_keys,
+                config,
+            };
+            definitions.push(EntityType(&entity).to_string());
+            definitions.push(EntityFilter(&entity).to_string());
+            if config.order {
+                definitions.push(EntityOrder(&entity).to_string());
+            }
+            definitions.push(PrimaryKeyInput(&entity).to_string());
+            let (insert, update, _) = generated_mutations(t, config);
+            if insert {
+                definitions.push(EntityInput(&entity, InputKind::Insert).to_string());
+            }
+            if update {
+                definitions.push(EntityInput(&entity, InputKind::Update).to_string());
+            }
+        }
+        for value in column_filters.values() {
+            definitions.push(ColumnFilter(value).to_string());
+        }
+        if uses_date_parts {
+            definitions.push(String::from(DATE_PART_FILTER));
+        }
+        if uses_delete {
+            definitions.push(String::from("type DeletedCount {\n    count: BigInt!\n}\n"));
+        }
+        definitions.push(
+            QueryType {
+                tables: self.tables,
+                config: self.config,
+            }
+            .to_string(),
+        );
+        let mutation = MutationType {
+            tables: self.tables,
+            config: self.config,
+        }
+        .to_string();
+        if !mutation.is_empty() {
+            definitions.push(mutation);
+        }
+        write!(f, "{}", definitions.join("\n"))
+    }
+}
+
+/// Input types used by the `date_part` filter of date and time columns
+const DATE_PART_FILTER: &str = "input DateParts {
+    year: DatePartComparison
+    month: DatePartComparison
+    day: DatePartComparison
+    hour: DatePartComparison
+    weekday: DatePartComparison
+}
+
+input DatePartComparison {
+    eq: Int
+    not_eq: Int
+    gt: Int
+    ge: Int
+    lt: Int
+    le: Int
+}
+";
+
+/// Filter operations that are only available for some types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AdditionalFilter {
+    None,
+    Like,
+    DatePart,
+}
+
+/// GraphQL representation of a single value of a column
+#[derive(Debug, Clone)]
+struct ValueType {
+    /// Name of the GraphQL type
+    graphql_name: String,
+    /// Name used by wundergraph to build the name of the filter type,
+    /// see `wundergraph::juniper_ext::Nameable`
+    filter_name: String,
+    /// Name of the GraphQL type used for filter values and mutation
+    /// inputs, differs from `graphql_name` for unsigned integers as
+    /// those are stored as signed values
+    input_name: String,
+    additional_filter: AdditionalFilter,
+}
+
+impl ValueType {
+    fn from_rust_type(rust_type: &str) -> Self {
+        let (graphql_name, filter_name, additional_filter) = match rust_type {
+            "i16" => ("SmallInt", "SmallInt", AdditionalFilter::None),
+            "i32" => ("Int", "Int", AdditionalFilter::None),
+            "i64" => ("BigInt", "BigInt", AdditionalFilter::None),
+            "u16" => (
+                "UnsignedSmallInt",
+                "UnsignedSmallInt",
+                AdditionalFilter::None,
+            ),
+            "u32" => ("UnsignedInt", "UnsignedInt", AdditionalFilter::None),
+            "u64" => ("UnsignedBigInt", "UnsignedBigInt", AdditionalFilter::None),
+            "f32" => ("SmallFloat", "Float", AdditionalFilter::None),
+            "f64" => ("Float", "Double", AdditionalFilter::None),
+            "bool" => ("Boolean", "bool", AdditionalFilter::None),
+            "String" => ("String", "String", AdditionalFilter::Like),
+            "DateTime<Utc>" => ("DateTimeUtc", "DateTime", AdditionalFilter::DatePart),
+            "NaiveDateTime" => ("NaiveDateTime", "NaiveDateTime", AdditionalFilter::DatePart),
+            "NaiveDate" => ("NaiveDate", "Date", AdditionalFilter::DatePart),
+            "NaiveTime" => ("NaiveTime", "NaiveTime", AdditionalFilter::None),
+            "BigDecimal" => ("Decimal", "Decimal", AdditionalFilter::None),
+            "diesel::pg::data_types::PgInterval" => {
+                ("Interval", "Interval", AdditionalFilter::None)
+            }
+            "wundergraph::query_builder::types::Bytes" => {
+                ("Bytes", "Bytes", AdditionalFilter::None)
+            }
+            // Enums and custom types configured in `wundergraph.toml`
+            // are named after the rust type
+            _ => {
+                let name = rust_type.rsplit("::").next().unwrap_or(rust_type);
+                (name, name, AdditionalFilter::None)
+            }
+        };
+        let input_name = match rust_type {
+            "u16" => "Int",
+            "u32" | "u64" => "BigInt",
+            _ => graphql_name,
+        };
+        Self {
+            graphql_name: graphql_name.to_owned(),
+            filter_name: filter_name.to_owned(),
+            input_name: input_name.to_owned(),
+            additional_filter,
+        }
+    }
+}
+
+/// GraphQL representation of a column
+#[derive(Debug, Clone)]
+struct ColumnValue {
+    value: ValueType,
+    is_array: bool,
+    is_nullable: bool,
+}
+
+impl ColumnValue {
+    fn new(column: &ColumnDefinition, config: &ColumnConfig) -> Self {
+        let rust_type = column_rust_type(column, config);
+        let is_array = rust_type.starts_with("Vec<") && rust_type.ends_with('>');
+        let value = if is_array {
+            &rust_type[4..rust_type.len() - 1]
+        } else {
+            &rust_type[..]
+        };
+        Self {
+            value: ValueType::from_rust_type(value),
+            is_array,
+            is_nullable: column.ty.is_nullable,
+        }
+    }
+
+    /// The GraphQL type without the outer non null marker
+    fn base_type(&self) -> String {
+        self.wrap_array(&self.value.graphql_name)
+    }
+
+    /// The GraphQL type used for filter values and mutation inputs,
+    /// without the outer non null marker
+    fn input_base_type(&self) -> String {
+        self.wrap_array(&self.value.input_name)
+    }
+
+    fn wrap_array(&self, name: &str) -> String {
+        if self.is_array {
+            format!("[{}!]", name)
+        } else {
+            name.to_owned()
+        }
+    }
+
+    fn field_type(&self) -> String {
+        self.non_null(self.base_type())
+    }
+
+    fn input_field_type(&self) -> String {
+        self.non_null(self.input_base_type())
+    }
+
+    fn non_null(&self, tpe: String) -> String {
+        if self.is_nullable {
+            tpe
+        } else {
+            format!("{}!", tpe)
+        }
+    }
+
+    /// Name of the filter type generated by wundergraph for this column
+    fn filter_name(&self) -> String {
+        let mut name = self.value.filter_name.clone();
+        if self.is_array {
+            name = format!("Vec_{}_", name);
+        }
+        if self.is_nullable {
+            name = format!("Nullable_{}_", name);
+        }
+        format!("Filter_{}_", name)
+    }
+}
+
+/// A table together with the information required to
+/// derive the corresponding GraphQL types
+struct Entity<'a> {
+    table: &'a TableData,
+    foreign_keys: &'a [ForeignKeyConstraint],
+    config: &'a TableConfig,
+}
+
+impl<'a> Entity<'a> {
+    fn name(&self) -> String {
+        fix_table_name(&self.table.name.name)
+    }
+
+    fn has_many(&self) -> impl Iterator<Item = &'a ForeignKeyConstraint> + '_ {
+        self.foreign_keys
+            .iter()
+            .filter(move |f| f.parent_table == self.table.name)
+    }
+}
+
+fn foreign_key_for<'a>(
+    foreign_keys: &'a [ForeignKeyConstraint],
+    table: &TableData,
+    column: &ColumnDefinition,
+) -> Option<&'a ForeignKeyConstraint> {
+    foreign_keys
+        .iter()
+        .find(|f| f.child_table == table.name && f.foreign_key == column.sql_name)
+}
+
+/// Name of the GraphQL field of the entity for the given column
+fn field_name<'a>(column: &'a ColumnDefinition, config: &'a ColumnConfig) -> &'a str {
+    config
+        .graphql_name
+        .as_ref()
+        .or_else(|| column.rust_name.as_ref())
+        .unwrap_or(&column.sql_name)
+}
+
+/// Name of the primary key input type,
+/// see `wundergraph::helper::PrimaryKeyInfo`
+fn primary_key_type_name(table: &TableName) -> String {
+    let name = match table.schema {
+        Some(ref schema) => format!("{}_{}", schema, table.name),
+        None => table.name.clone(),
+    };
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) => format!("{}{}PrimaryKey", c.to_uppercase(), chars.as_str()),
+        None => String::from("PrimaryKey"),
+    }
+}
+
+/// Field names of input objects derived by `juniper::GraphQLInputObject`
+fn to_lower_camel_case(name: &str) -> String {
+    let mut ret = String::with_capacity(name.len());
+    for (i, part) in name.split('_').enumerate() {
+        let mut chars = part.chars();
+        match chars.next() {
+            Some(c) if i > 0 => {
+                ret.extend(c.to_uppercase());
+                ret.push_str(chars.as_str());
+            }
+            _ => ret.push_str(part),
+        }
+    }
+    ret
+}
+
+/// Enum values as named by `#[derive(WundergraphValue)]`
+fn to_upper_snake_case(name: &str) -> String {
+    let mut last_lower = false;
+    let mut ret = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c == '_' {
+            last_lower = false;
+        } else if c.is_lowercase() {
+            last_lower = true;
+        } else if c.is_uppercase() {
+            if last_lower {
+                ret.push('_');
+            }
+            last_lower = false;
+        }
+        ret.extend(c.to_uppercase());
+    }
+    ret
+}
+
+fn write_description<W: Write>(f: &mut W, docs: &str) -> fmt::Result {
+    if docs.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "\"\"\"")?;
+    for d in docs.lines() {
+        writeln!(f, "{}", d.replace("\"\"\"", "\\\"\"\""))?;
+    }
+    writeln!(f, "\"\"\"")
+}
+
+struct EnumDefinition<'a> {
+    enum_type: &'a EnumType,
+}
+
+impl<'a> Display for EnumDefinition<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "enum {} {{", self.enum_type.rust_name())?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            for v in self.enum_type.variant_names() {
+                writeln!(out, "{}", to_upper_snake_case(&v))?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+struct EntityType<'a>(&'a Entity<'a>);
+
+impl<'a> Display for EntityType<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let entity = self.0;
+        if let Some(doc) = entity
+            .config
+            .doc
+            .as_ref()
+            .or_else(|| entity.table.comment.as_ref())
+        {
+            write_description(f, doc)?;
+        }
+        write!(f, "type {} {{", entity.name())?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            for c in &entity.table.column_data {
+                let config = entity.config.column(&c.sql_name);
+                if let Some(doc) = config.doc.as_ref().or_else(|| c.comment.as_ref()) {
+                    write_description(&mut out, doc)?;
+                }
+                let tpe = match foreign_key_for(entity.foreign_keys, entity.table, c) {
+                    Some(fk) if c.ty.is_nullable => fix_table_name(&fk.parent_table.name),
+                    Some(fk) => format!("{}!", fix_table_name(&fk.parent_table.name)),
+                    None => ColumnValue::new(c, config).field_type(),
+                };
+                writeln!(out, "{}: {}", field_name(c, config), tpe)?;
+            }
+            for fk in entity.has_many() {
+                writeln!(
+                    out,
+                    "{}: [{}!]!",
+                    fk.has_many_field_name(entity.foreign_keys),
+                    fix_table_name(&fk.child_table.name)
+                )?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+struct EntityFilter<'a>(&'a Entity<'a>);
+
+impl<'a> Display for EntityFilter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let entity = self.0;
+        let name = format!("{}Filter", entity.name());
+        write!(f, "input {} {{", name)?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            writeln!(out, "and: [{}!]", name)?;
+            writeln!(out, "or: [{}!]", name)?;
+            writeln!(out, "not: {}", name)?;
+            for c in &entity.table.column_data {
+                let config = entity.config.column(&c.sql_name);
+                let filter = match foreign_key_for(entity.foreign_keys, entity.table, c) {
+                    Some(fk) => format!("{}Filter", fix_table_name(&fk.parent_table.name)),
+                    None => ColumnValue::new(c, config).filter_name(),
+                };
+                writeln!(out, "{}: {}", field_name(c, config), filter)?;
+            }
+            for fk in entity.has_many() {
+                writeln!(
+                    out,
+                    "{}: {}Filter",
+                    fk.has_many_field_name(entity.foreign_keys),
+                    fix_table_name(&fk.child_table.name)
+                )?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+struct EntityOrder<'a>(&'a Entity<'a>);
+
+impl<'a> Display for EntityOrder<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let entity = self.0;
+        write!(f, "enum {}Columns {{", entity.name())?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            for c in &entity.table.column_data {
+                writeln!(out, "{}", field_name(c, entity.config.column(&c.sql_name)))?;
+            }
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+        write!(f, "input {}OrderBy {{", entity.name())?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            writeln!(out, "column: {}Columns!", entity.name())?;
+            writeln!(out, "direction: Order = ASC")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+struct PrimaryKeyInput<'a>(&'a Entity<'a>);
+
+impl<'a> Display for PrimaryKeyInput<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let entity = self.0;
+        write!(f, "input {} {{", primary_key_type_name(&entity.table.name))?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            for k in &entity.table.primary_key {
+                let column = entity.table.column_data.iter().find(|c| c.sql_name == *k);
+                if let Some(c) = column {
+                    let value = ColumnValue::new(c, entity.config.column(&c.sql_name));
+                    writeln!(out, "{}: {}!", k, value.base_type())?;
+                }
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputKind {
+    Insert,
+    Update,
+}
+
+struct EntityInput<'a>(&'a Entity<'a>, InputKind);
+
+impl<'a> Display for EntityInput<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let entity = self.0;
+        match self.1 {
+            InputKind::Insert => write!(f, "input New{} {{", entity.name())?,
+            InputKind::Update => write!(f, "input {}Changeset {{", entity.name())?,
+        }
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            for c in &entity.table.column_data {
+                if self.1 == InputKind::Insert && c.has_default {
+                    continue;
+                }
+                let config = entity.config.column(&c.sql_name);
+                if let Some(doc) = config.doc.as_ref().or_else(|| c.comment.as_ref()) {
+                    write_description(&mut out, doc)?;
+                }
+                let name = match config.graphql_name {
+                    Some(ref graphql_name) => graphql_name.clone(),
+                    None => to_lower_camel_case(c.rust_name.as_ref().unwrap_or(&c.sql_name)),
+                };
+                writeln!(
+                    out,
+                    "{}: {}",
+                    name,
+                    ColumnValue::new(c, config).input_field_type()
+                )?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+struct ColumnFilter<'a>(&'a ColumnValue);
+
+impl<'a> Display for ColumnFilter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = self.0;
+        let base = value.input_base_type();
+        write!(f, "input {} {{", value.filter_name())?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            writeln!(out, "eq: {}", base)?;
+            writeln!(out, "not_eq: {}", base)?;
+            if value.is_nullable {
+                writeln!(out, "eq_any: [{}]", base)?;
+                writeln!(out, "is_null: Boolean")?;
+            } else {
+                writeln!(out, "eq_any: [{}!]", base)?;
+            }
+            if value.is_array {
+                writeln!(out, "contains: {}", base)?;
+                writeln!(out, "is_contained_by: {}", base)?;
+                writeln!(out, "overlaps_with: {}", base)?;
+            } else {
+                match value.value.additional_filter {
+                    AdditionalFilter::Like => writeln!(out, "like: String")?,
+                    AdditionalFilter::DatePart => writeln!(out, "date_part: DateParts")?,
+                    AdditionalFilter::None => {}
+                }
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+struct QueryType<'a> {
+    tables: &'a [TableData],
+    config: &'a Config,
+}
+
+impl<'a> Display for QueryType<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "type Query {{")?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            for t in self.tables {
+                let config = self.config.table(&t.name);
+                let name = fix_table_name(&t.name.name);
+                let mut args = Vec::new();
+                if config.filter {
+                    args.push(format!("filter: {}Filter", name));
+                }
+                args.push(String::from("limit: Int"));
+                args.push(String::from("offset: Int"));
+                if config.order {
+                    args.push(format!("order: [{}OrderBy!]", name));
+                }
+                let list_name = config
+                    .graphql_name
+                    .clone()
+                    .unwrap_or_else(|| format!("{}s", name));
+                let primary_key = primary_key_type_name(&t.name);
+                writeln!(out, "{}({}): [{}!]!", list_name, args.join(", "), name)?;
+                writeln!(out, "{}(primaryKey: {}!): {}", name, primary_key, name)?;
+                if t.primary_key.len() == 1 {
+                    writeln!(out, "{}ByIds(ids: [ID!]!): [{}]!", list_name, name)?;
+                }
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// The mutation type, empty if no table has any mutation
+struct MutationType<'a> {
+    tables: &'a [TableData],
+    config: &'a Config,
+}
+
+impl<'a> Display for MutationType<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mutations = self
+            .tables
+            .iter()
+            .map(|t| (t, generated_mutations(t, self.config.table(&t.name))))
+            .collect::<Vec<_>>();
+        if mutations.iter().all(|(_, m)| *m == (false, false, false)) {
+            return Ok(());
+        }
+        write!(f, "type Mutation {{")?;
+        {
+            let mut out = PadAdapter::new(f);
+            writeln!(out)?;
+            // Same order as the fields registered by `mutation_object!`
+            for (t, _) in mutations.iter().filter(|(_, m)| m.0) {
+                let name = fix_table_name(&t.name.name);
+                writeln!(out, "Create{0}(New{0}: New{0}!): {0}", name)?;
+                writeln!(out, "Create{0}s(New{0}s: [New{0}!]!): [{0}!]!", name)?;
+            }
+            for (t, _) in mutations.iter().filter(|(_, m)| m.1) {
+                let name = fix_table_name(&t.name.name);
+                writeln!(out, "Update{0}(Update{0}: {0}Changeset!): {0}", name)?;
+            }
+            for (t, _) in mutations.iter().filter(|(_, m)| m.2) {
+                writeln!(
+                    out,
+                    "Delete{0}(Delete{0}: {1}!): DeletedCount",
+                    fix_table_name(&t.name.name),
+                    primary_key_type_name(&t.name)
+                )?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graphql_names() {
+        assert_eq!(to_lower_camel_case("published_at"), "publishedAt");
+        assert_eq!(to_lower_camel_case("title"), "title");
+        assert_eq!(to_upper_snake_case("InReview"), "IN_REVIEW");
+        assert_eq!(to_upper_snake_case("Draft"), "DRAFT");
+        assert_eq!(
+            primary_key_type_name(&TableName::new("users", "infer_test")),
+            "Infer_test_usersPrimaryKey"
+        );
+        assert_eq!(
+            primary_key_type_name(&TableName::from_name("users")),
+            "UsersPrimaryKey"
+        );
+    }
+}
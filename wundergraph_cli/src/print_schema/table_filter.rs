@@ -0,0 +1,135 @@
+use crate::infer_schema_internals::TableName;
+use regex::Regex;
+use std::str::FromStr;
+use structopt::StructOpt;
+
+/// A pattern matching table names
+///
+/// By default patterns are interpreted as glob, where `*` matches any
+/// sequence of characters and `?` matches a single character.
+/// Patterns prefixed with `regex:` are interpreted as regular expression.
+#[derive(Debug, Clone)]
+pub struct TablePattern(Regex);
+
+impl TablePattern {
+    fn matches(&self, table: &TableName) -> bool {
+        self.0.is_match(&table.name) || self.0.is_match(&table.to_string())
+    }
+}
+
+impl FromStr for TablePattern {
+    type Err = regex::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("regex:") {
+            Regex::new(&s["regex:".len()..]).map(Self)
+        } else {
+            Regex::new(&glob_to_regex(s)).map(Self)
+        }
+    }
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut ret = String::from("^");
+    for c in glob.chars() {
+        match c {
+            '*' => ret.push_str(".*"),
+            '?' => ret.push('.'),
+            c => ret.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    ret.push('$');
+    ret
+}
+
+const DEFAULT_VIEW_KEY_COLUMN: &str = "id";
+
+/// Options to select the tables for which code is generated
+///
+/// Foreign keys pointing to tables that are not selected are
+/// ignored while generating code.
+#[derive(Debug, Default, StructOpt)]
+pub struct TableFilter {
+    /// Only generate code for tables matching one of the given patterns
+    #[structopt(
+        long = "only-tables",
+        use_delimiter = true,
+        conflicts_with = "except-tables"
+    )]
+    only_tables: Vec<TablePattern>,
+    /// Do not generate code for tables matching one of the given patterns
+    #[structopt(long = "except-tables", use_delimiter = true)]
+    except_tables: Vec<TablePattern>,
+    /// Also generate read only entities for views
+    #[structopt(long = "include-views")]
+    include_views: bool,
+    /// Column used as key for views, defaults to `id`
+    ///
+    /// Can be overwritten per view using the `primary_key` option
+    /// in the configuration file
+    #[structopt(long = "view-key-column")]
+    view_key_column: Option<String>,
+}
+
+impl TableFilter {
+    pub fn should_ignore_table(&self, table: &TableName) -> bool {
+        if !self.only_tables.is_empty() && !self.only_tables.iter().any(|p| p.matches(table)) {
+            return true;
+        }
+        self.except_tables.iter().any(|p| p.matches(table))
+    }
+
+    pub fn include_views(&self) -> bool {
+        self.include_views
+    }
+
+    pub fn view_key_column(&self) -> &str {
+        self.view_key_column
+            .as_ref()
+            .map_or(DEFAULT_VIEW_KEY_COLUMN, |c| c as &str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(only_tables: &[&str], except_tables: &[&str]) -> TableFilter {
+        TableFilter {
+            only_tables: only_tables.iter().map(|p| p.parse().unwrap()).collect(),
+            except_tables: except_tables.iter().map(|p| p.parse().unwrap()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn empty_filter_keeps_all_tables() {
+        let filter = TableFilter::default();
+        assert!(!filter.should_ignore_table(&TableName::from_name("users")));
+    }
+
+    #[test]
+    fn only_tables_with_glob() {
+        let filter = filter(&["user*", "posts"], &[]);
+        assert!(!filter.should_ignore_table(&TableName::from_name("users")));
+        assert!(!filter.should_ignore_table(&TableName::from_name("posts")));
+        assert!(filter.should_ignore_table(&TableName::from_name("comments")));
+        assert!(filter.should_ignore_table(&TableName::from_name("posts_audit")));
+    }
+
+    #[test]
+    fn except_tables_with_regex() {
+        let filter = filter(&[], &["regex:^(staging|internal)_", "*_audit"]);
+        assert!(!filter.should_ignore_table(&TableName::from_name("users")));
+        assert!(filter.should_ignore_table(&TableName::from_name("staging_users")));
+        assert!(filter.should_ignore_table(&TableName::from_name("internal_jobs")));
+        assert!(filter.should_ignore_table(&TableName::from_name("users_audit")));
+    }
+
+    #[test]
+    fn patterns_match_schema_qualified_names() {
+        let filter = filter(&["infer_test.*"], &[]);
+        assert!(!filter.should_ignore_table(&TableName::new("users", "infer_test")));
+        assert!(filter.should_ignore_table(&TableName::new("users", "public")));
+    }
+}
@@ -1,4 +1,5 @@
 #[macro_use] extern crate diesel;
+#[macro_use] extern crate diesel_migrations;
 
 use actix_web::web::Data;
 use actix_web::web::Json;
@@ -11,9 +12,6 @@ use diesel::{conn};
 use diesel::r2d2::ConnectionManager;
 use diesel::r2d2::Pool;
 use diesel::r2d2::PooledConnection;
-use diesel::r2d2::CustomizeConnection;
-use diesel::Connection;
-use diesel::connection::SimpleConnection;
 use juniper::graphiql::graphiql_source;
 use juniper::http::GraphQLRequest;
 use serde::Deserialize;
@@ -58,25 +56,16 @@ fn graphql(
         .body(serde_json::to_string(&res)?))
 }}
 
-#[derive(Debug)]
-struct ConnectionHandler;
-
-impl<E> CustomizeConnection<{conn}, E> for ConnectionHandler {{
-    fn on_acquire(&self, conn: &mut {conn}) -> Result<(), E> {{
-        Ok(conn.begin_test_transaction().unwrap())
-    }}
-}}
-
+{connection_customizer}
 fn main() {{
     let manager = ConnectionManager::<{conn}>::new("{db_url}");
     let pool = Pool::builder()
-        .max_size(1)
-        .connection_customizer(Box::new(ConnectionHandler))
+        .max_size({pool_size}){customize_pool}
         .build(manager)
         .expect("Failed to init pool");
     {{
         let conn = pool.get().unwrap();
-        conn.batch_execute("{migrations}").unwrap();
+        {migrations}
     }}
 
     let query = self::api::Query::default();
@@ -3,7 +3,6 @@ use crate::model::Model;
 use crate::utils::{inner_of_option_ty, inner_ty_args, wrap_in_dummy_mod};
 use proc_macro2::TokenStream;
 use quote::quote;
-use std::collections::HashMap;
 use syn::parse_quote;
 
 pub fn derive(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
@@ -38,14 +37,12 @@ pub fn derive_belonging_to(
                 } else {
                     panic!("No parent type found");
                 };
-                Some((parent_ty, (key_ty, f)))
+                Some((parent_ty, key_ty, f))
             } else {
                 None
             }
         })
-        .collect::<HashMap<_, _>>()
-        .into_iter()
-        .map(|(parent_ty, (key_ty, f))| {
+        .map(|(parent_ty, key_ty, f)| {
             let pg = if cfg!(feature = "postgres") {
                 Some(derive_belongs_to(
                     model,
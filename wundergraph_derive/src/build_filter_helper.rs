@@ -88,9 +88,11 @@ pub fn derive_non_table_filter(
 
             fn from_inner_look_ahead(
                 objs: &[(&str, wundergraph::juniper::LookAheadValue<wundergraph::scalar::WundergraphScalarValue>)]
-            ) -> #filter {
+            ) -> wundergraph::error::Result<#filter> {
                 use wundergraph::query_builder::selection::filter::InnerFilter;
-                wundergraph::query_builder::selection::filter::FilterBuildHelper::<#filter, #struct_type #ty_generics, #backend, __Ctx>::from_inner_look_ahead(objs).0
+                std::result::Result::Ok(
+                    wundergraph::query_builder::selection::filter::FilterBuildHelper::<#filter, #struct_type #ty_generics, #backend, __Ctx>::from_inner_look_ahead(objs)?.0
+                )
             }
 
             fn from_inner_input_value(
@@ -59,6 +59,10 @@ use proc_macro::TokenStream;
 ///     * `#[primary_key(primary_key_name)]`: Names the fields that represent
 ///       the primary key on the underlying database table. Set
 ///        to `id` if not given (In this case a primary key field named `id` must exist)
+///     * `#[wundergraph(unique = "field_a, field_b")]`: Marks the given fields
+///       as unique key of the entity. For each key a single entity lookup field
+///       named like `HeroByName` is added to the query object. This attribute
+///       may be given multiple times.
 ///     * `/// Documentation`/`#[doc = "Documentation"]`: Set as GraphQL
 ///       description text.
 ///
@@ -12,6 +12,7 @@ pub struct Model {
     table_name: Option<syn::Path>,
     pub docs: Option<String>,
     primary_keys: Vec<syn::Path>,
+    unique_keys: Vec<syn::LitStr>,
 }
 
 impl Model {
@@ -31,6 +32,18 @@ impl Model {
             },
             |m| m.nested()?.map(|m| m.path()).collect(),
         )?;
+        let unique_keys = MetaItem::all_with_name(&item.attrs, "wundergraph")
+            .iter()
+            .map(|m| {
+                m.nested()?
+                    .filter(|n| n.name().is_ident("unique"))
+                    .map(|n| n.lit_str_value().map(Clone::clone))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect();
         Ok(Self {
             name: item.ident.clone(),
             fields,
@@ -38,6 +51,7 @@ impl Model {
             table_name,
             docs,
             primary_keys,
+            unique_keys,
         })
     }
 
@@ -60,6 +74,10 @@ impl Model {
         &self.primary_keys
     }
 
+    pub fn unique_keys(&self) -> &[syn::LitStr] {
+        &self.unique_keys
+    }
+
     pub fn filter_type(&self) -> Option<syn::Path> {
         self.flags.get_flag("filter").ok()
     }
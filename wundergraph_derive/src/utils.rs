@@ -38,6 +38,10 @@ pub fn is_has_many(ty: &Type) -> bool {
     inner_ty_arg(inner_of_option_ty(ty), "HasMany", 0).is_some()
 }
 
+pub fn is_has_one(ty: &Type) -> bool {
+    inner_ty_arg(inner_of_option_ty(ty), "HasOne", 0).is_some()
+}
+
 pub fn inner_ty_args<'a>(
     ty: &'a Type,
     type_name: &str,
@@ -1,8 +1,8 @@
 use crate::build_filter_helper::derive_non_table_filter;
 use crate::diagnostic_shim::{Diagnostic, DiagnosticShim};
-use crate::field::Field;
+use crate::field::{Field, FieldName};
 use crate::model::Model;
-use crate::utils::{is_has_many, wrap_in_dummy_mod};
+use crate::utils::{is_has_many, is_has_one, wrap_in_dummy_mod};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::parse_quote;
@@ -136,6 +136,70 @@ fn derive_loading_handler(
         |d| quote!(std::option::Option::Some(#d)),
     );
 
+    let unique_keys = unique_keys(model)?;
+    let unique_key_arms = unique_keys
+        .iter()
+        .enumerate()
+        .map(|(i, (field_name, _))| {
+            quote!(#field_name => std::option::Option::Some(&<Self as LoadingHandler<#backend, __Ctx>>::UNIQUE_KEYS[#i]))
+        });
+    let unique_key_filter = if unique_keys.is_empty() {
+        None
+    } else {
+        Some(unique_key_filter(&table, backend, &unique_keys))
+    };
+    let unique_keys = unique_keys.iter().map(|(field_name, fields)| {
+        let indices = fields.iter().map(|(i, _)| i);
+        quote! {
+            wundergraph::query_builder::selection::UniqueKey {
+                field_name: #field_name,
+                fields: &[#(#indices,)*],
+            }
+        }
+    });
+
+    let global_ids = if cfg!(feature = "relay") {
+        Some(quote! {
+            fn global_ids(
+                placeholder: &[<
+                    <Self as LoadingHandler<#backend, __Ctx>>::FieldList
+                    as wundergraph::query_builder::selection::fields::WundergraphFieldList<
+                        #backend,
+                        <Self as LoadingHandler<#backend, __Ctx>>::PrimaryKeyIndex,
+                        <Self as wundergraph::diesel::associations::HasTable>::Table,
+                        __Ctx,
+                    >
+                >::PlaceHolder],
+            ) -> std::vec::Vec<std::option::Option<std::string::String>> {
+                <
+                    <Self as LoadingHandler<#backend, __Ctx>>::FieldList
+                    as wundergraph::query_builder::selection::fields::WundergraphFieldList<
+                        #backend,
+                        <Self as LoadingHandler<#backend, __Ctx>>::PrimaryKeyIndex,
+                        <Self as wundergraph::diesel::associations::HasTable>::Table,
+                        __Ctx,
+                    >
+                >::primary_key_values(placeholder)
+                    .into_iter()
+                    .map(|key| {
+                        key.map(|key| {
+                            let key = <
+                                <<Self as wundergraph::diesel::associations::HasTable>::Table as wundergraph::diesel::Table>::PrimaryKey
+                                as wundergraph::helper::PrimaryKeyInputObject<_, ()>
+                            >::to_input_value(&key);
+                            wundergraph::relay::encode_global_id(
+                                <Self as LoadingHandler<#backend, __Ctx>>::TYPE_NAME,
+                                &key,
+                            )
+                        })
+                    })
+                    .collect()
+            }
+        })
+    } else {
+        None
+    };
+
     let filter = model.filter_type().map_or_else(
         || {
             quote! {
@@ -157,6 +221,38 @@ fn derive_loading_handler(
     }
     let (impl_generics, _, where_clause) = generics.split_for_impl();
 
+    // `HasOne` fields are resolved by a single primary key column,
+    // so only those entities could be referenced by them
+    let has_one_target = if primary_keys.len() == 1 {
+        Some(quote! {
+            impl #impl_generics wundergraph::query_builder::selection::fields::WundergraphHasOneTarget<
+                <<&'static #struct_type #ty_generics as wundergraph::diesel::Identifiable>::Id as wundergraph::helper::UnRef<'static>>::UnRefed,
+                #backend,
+                __Ctx,
+            > for #struct_type #ty_generics
+                #where_clause
+            {
+                fn resolve(
+                    global_args: &[wundergraph::juniper::LookAheadArgument<wundergraph::scalar::WundergraphScalarValue>],
+                    look_ahead: &wundergraph::juniper::LookAheadSelection<wundergraph::scalar::WundergraphScalarValue>,
+                    selection: std::option::Option<&[wundergraph::juniper::Selection<wundergraph::scalar::WundergraphScalarValue>]>,
+                    keys: &[std::option::Option<
+                        <<&'static Self as wundergraph::diesel::Identifiable>::Id as wundergraph::helper::UnRef<'static>>::UnRefed
+                    >],
+                    executor: &wundergraph::juniper::Executor<__Ctx, wundergraph::scalar::WundergraphScalarValue>,
+                ) -> wundergraph::error::Result<std::vec::Vec<wundergraph::juniper::Value<wundergraph::scalar::WundergraphScalarValue>>> {
+                    <Self as wundergraph::query_builder::selection::fields::WundergraphHasOneTarget<
+                        <<&'static Self as wundergraph::diesel::Identifiable>::Id as wundergraph::helper::UnRef<'static>>::UnRefed,
+                        #backend,
+                        __Ctx,
+                    >>::load_by_keys(global_args, look_ahead, selection, keys, executor)
+                }
+            }
+        })
+    } else {
+        None
+    };
+
     Ok(quote! {
 
         impl #impl_generics WundergraphGraphqlMapper<#backend, __Ctx> for #struct_type #ty_generics
@@ -194,6 +290,7 @@ fn derive_loading_handler(
             const FIELD_NAMES: &'static [&'static str] = &[#(stringify!(#field_names),)*];
             const TYPE_NAME: &'static str = stringify!(#struct_type);
             const TYPE_DESCRIPTION: std::option::Option<&'static str> = #type_description;
+            const UNIQUE_KEYS: &'static [wundergraph::query_builder::selection::UniqueKey] = &[#(#unique_keys,)*];
 
             fn field_description(idx: usize) -> std::option::Option<&'static str> {
                 match idx {
@@ -208,6 +305,130 @@ fn derive_loading_handler(
                     _ => std::option::Option::None,
                 }
             }
+
+            fn unique_key(field_name: &str) -> std::option::Option<&'static wundergraph::query_builder::selection::UniqueKey> {
+                match field_name {
+                    #(#unique_key_arms,)*
+                    _ => std::option::Option::None,
+                }
+            }
+
+            #unique_key_filter
+
+            #global_ids
         }
+
+
+        #has_one_target
     })
 }
+
+fn unique_keys(model: &Model) -> Result<Vec<(String, Vec<(usize, &Field)>)>, Diagnostic> {
+    model
+        .unique_keys()
+        .iter()
+        .map(|key| {
+            let fields = key
+                .value()
+                .split(',')
+                .map(str::trim)
+                .map(|name| {
+                    model
+                        .fields()
+                        .iter()
+                        .enumerate()
+                        .find(|(_, f)| match *f.rust_name() {
+                            FieldName::Named(ref p) => p.is_ident(name),
+                            FieldName::Unnamed(_) => false,
+                        })
+                        .filter(|(_, f)| !is_has_many(&f.ty) && !is_has_one(&f.ty))
+                        .ok_or_else(|| {
+                            key.span().error(format!(
+                                "No field named `{}` usable as part of a unique key found",
+                                name
+                            ))
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let name = fields
+                .iter()
+                .map(|(_, f)| {
+                    let graphql_name = f.graphql_name();
+                    upper_camel_case(&quote!(#graphql_name).to_string())
+                })
+                .collect::<Vec<_>>()
+                .join("And");
+            Ok((format!("{}By{}", model.name, name), fields))
+        })
+        .collect()
+}
+
+fn unique_key_filter(
+    table: &syn::Path,
+    backend: &TokenStream,
+    unique_keys: &[(String, Vec<(usize, &Field)>)],
+) -> TokenStream {
+    let arms = unique_keys.iter().map(|(field_name, fields)| {
+        let conditions = fields
+            .iter()
+            .map(|(_, f)| {
+                let column = f.sql_name();
+                let ty = &f.ty;
+                let graphql_name = f.graphql_name();
+                quote! {
+                    ExpressionMethods::eq(
+                        #table::#column,
+                        select
+                            .argument(stringify!(#graphql_name))
+                            .and_then(|a| {
+                                <<#ty as wundergraph::query_builder::selection::filter::FilterValue<#table::#column>>::RawValue
+                                    as wundergraph::juniper_ext::FromLookAheadValue>::from_look_ahead(a.value())
+                            })
+                            .ok_or(wundergraph::error::WundergraphError::NoUniqueKeyArgumentFound)?,
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+        let first = &conditions[0];
+        let rest = &conditions[1..];
+        quote! {
+            #field_name => std::result::Result::Ok(std::boxed::Box::new(#first #(.and(#rest))*))
+        }
+    });
+    quote! {
+        fn unique_key_filter(
+            key: &wundergraph::query_builder::selection::UniqueKey,
+            select: &wundergraph::juniper::LookAheadSelection<'_, wundergraph::scalar::WundergraphScalarValue>,
+        ) -> wundergraph::error::Result<
+            std::boxed::Box<
+                dyn wundergraph::diesel_ext::BoxableFilter<
+                    <Self as wundergraph::diesel::associations::HasTable>::Table,
+                    #backend,
+                    SqlType = wundergraph::diesel::sql_types::Bool,
+                >,
+            >,
+        > {
+            #[allow(unused_imports)]
+            use wundergraph::diesel::{BoolExpressionMethods, ExpressionMethods};
+            use wundergraph::juniper::LookAheadMethods;
+
+            match key.field_name {
+                #(#arms,)*
+                _ => std::result::Result::Err(
+                    wundergraph::error::WundergraphError::NoUniqueKeyArgumentFound,
+                ),
+            }
+        }
+    }
+}
+
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |c| {
+                c.to_uppercase().chain(chars).collect::<String>()
+            })
+        })
+        .collect()
+}
@@ -97,7 +97,7 @@ fn inner_filter(item: &syn::DeriveInput) -> Result<TokenStream, Diagnostic> {
 
             fn from_inner_look_ahead(
                 obj: &[(&str, LookAheadValue<WundergraphScalarValue>)]
-            ) -> Self {
+            ) -> wundergraph::error::Result<Self> {
                 #from_inner_look_ahead
             }
 
@@ -159,17 +159,18 @@ fn build_from_look_ahead(model: &Model) -> Result<TokenStream, Diagnostic> {
         };
         let ty = inner_of_box_ty(ty);
         quote!{
-            let #field_name = obj.iter()
-                .find(|o| o.0 == stringify!(#graphq_name))
-                .and_then(|o| <#ty as wundergraph::helper::FromLookAheadValue>::from_look_ahead(&o.1))
-                #map_box;
+            let #field_name = wundergraph::query_builder::selection::filter::operator_from_look_ahead::<#ty>(
+                obj,
+                stringify!(#graphq_name),
+            )?
+            #map_box;
         }
     });
     let fields = model.fields().iter().map(Field::rust_name);
     Ok(quote! {
         #(#build_field)*
 
-        Self{ #(#fields,)* }
+        std::result::Result::Ok(Self{ #(#fields,)* })
     })
 }
 
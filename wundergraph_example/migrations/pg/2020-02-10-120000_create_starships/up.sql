@@ -0,0 +1,10 @@
+CREATE TABLE starships(
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    length NUMERIC
+);
+
+INSERT INTO starships(id, name, length)
+    VALUES (1, 'Millennium Falcon', 34.75),
+           (2, 'Death Star', 120000);
+ALTER SEQUENCE starships_id_seq RESTART WITH 3;
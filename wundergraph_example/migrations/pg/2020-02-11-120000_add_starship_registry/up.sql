@@ -0,0 +1,3 @@
+ALTER TABLE starships ADD COLUMN registry BYTEA;
+
+UPDATE starships SET registry = '\x0001fe'::BYTEA WHERE id = 1;
@@ -0,0 +1,4 @@
+ALTER TABLE starships ADD COLUMN weapons TEXT[];
+
+UPDATE starships SET weapons = '{"laser cannon", "concussion missile"}' WHERE id = 1;
+UPDATE starships SET weapons = '{"superlaser", "turbolaser", "laser cannon"}' WHERE id = 2;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE starships DROP COLUMN travel_time;
+ALTER TABLE starships DROP COLUMN commissioned;
+ALTER TABLE starships DROP COLUMN departure;
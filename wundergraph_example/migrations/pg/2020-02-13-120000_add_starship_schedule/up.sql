@@ -0,0 +1,10 @@
+ALTER TABLE starships ADD COLUMN departure TIME;
+ALTER TABLE starships ADD COLUMN commissioned TIMESTAMP;
+ALTER TABLE starships ADD COLUMN travel_time INTERVAL;
+
+UPDATE starships
+    SET departure = '12:30:00', commissioned = '1977-05-25 08:00:00', travel_time = '3 days 4 hours'
+    WHERE id = 1;
+UPDATE starships
+    SET departure = '08:15:30.5', commissioned = '1983-05-22 20:30:00', travel_time = '14 days'
+    WHERE id = 2;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE starships DROP COLUMN cargo_capacity;
+ALTER TABLE starships DROP COLUMN crew;
+ALTER TABLE starships DROP COLUMN passengers;
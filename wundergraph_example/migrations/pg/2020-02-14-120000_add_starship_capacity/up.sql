@@ -0,0 +1,8 @@
+ALTER TABLE starships ADD COLUMN passengers INTEGER;
+ALTER TABLE starships ADD COLUMN crew BIGINT;
+ALTER TABLE starships ADD COLUMN cargo_capacity BIGINT;
+
+UPDATE starships SET passengers = 6, crew = 4, cargo_capacity = 100000 WHERE id = 1;
+UPDATE starships
+    SET passengers = 65535, crew = 342953, cargo_capacity = 9223372036854775807
+    WHERE id = 2;
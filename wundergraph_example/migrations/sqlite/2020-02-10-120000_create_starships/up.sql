@@ -0,0 +1,8 @@
+CREATE TABLE starships(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL
+);
+
+INSERT INTO starships(id, name)
+    VALUES (1, 'Millennium Falcon'),
+           (2, 'Death Star');
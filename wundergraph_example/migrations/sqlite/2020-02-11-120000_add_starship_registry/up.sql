@@ -0,0 +1,3 @@
+ALTER TABLE starships ADD COLUMN registry BLOB;
+
+UPDATE starships SET registry = X'0001fe' WHERE id = 1;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE starships DROP COLUMN commissioned;
+ALTER TABLE starships DROP COLUMN departure;
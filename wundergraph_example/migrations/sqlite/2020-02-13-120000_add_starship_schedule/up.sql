@@ -0,0 +1,9 @@
+ALTER TABLE starships ADD COLUMN departure TIME;
+ALTER TABLE starships ADD COLUMN commissioned TIMESTAMP;
+
+UPDATE starships
+    SET departure = '12:30:00', commissioned = '1977-05-25 08:00:00'
+    WHERE id = 1;
+UPDATE starships
+    SET departure = '08:15:30.500', commissioned = '1983-05-22 20:30:00'
+    WHERE id = 2;
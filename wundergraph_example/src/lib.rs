@@ -36,18 +36,23 @@ extern crate juniper;
 
 use wundergraph;
 
+#[cfg(feature = "postgres")]
+use bigdecimal::BigDecimal;
+use chrono::{NaiveDateTime, NaiveTime};
 use diesel::backend::Backend;
 use diesel::deserialize::{self, FromSql};
+#[cfg(feature = "postgres")]
+use diesel::pg::data_types::PgInterval;
 use diesel::r2d2::{ConnectionManager, PooledConnection};
 use diesel::serialize::{self, ToSql};
 use diesel::sql_types::SmallInt;
 use diesel::{Connection, Identifiable};
 use juniper::LookAheadSelection;
 use std::io::Write;
-use wundergraph::error::Result;
+use wundergraph::error::{RedactionPolicy, Result};
 use wundergraph::query_builder::selection::offset::ApplyOffset;
 use wundergraph::query_builder::selection::{BoxedQuery, LoadingHandler, QueryModifier};
-use wundergraph::query_builder::types::{HasMany, HasOne, WundergraphValue};
+use wundergraph::query_builder::types::{Bytes, HasMany, HasOne, WundergraphValue};
 use wundergraph::scalar::WundergraphScalarValue;
 use wundergraph::WundergraphContext;
 use wundergraph::WundergraphEntity;
@@ -129,6 +134,37 @@ table! {
     }
 }
 
+#[cfg(feature = "postgres")]
+table! {
+    starships {
+        id -> Integer,
+        name -> Text,
+        length -> Nullable<Numeric>,
+        registry -> Nullable<Binary>,
+        weapons -> Nullable<Array<Text>>,
+        departure -> Nullable<Time>,
+        commissioned -> Nullable<Timestamp>,
+        travel_time -> Nullable<Interval>,
+        passengers -> Nullable<Integer>,
+        crew -> Nullable<BigInt>,
+        cargo_capacity -> Nullable<BigInt>,
+    }
+}
+
+#[cfg(feature = "sqlite")]
+table! {
+    starships {
+        id -> Integer,
+        name -> Text,
+        registry -> Nullable<Binary>,
+        departure -> Nullable<Time>,
+        commissioned -> Nullable<Timestamp>,
+        passengers -> Nullable<Integer>,
+        crew -> Nullable<BigInt>,
+        cargo_capacity -> Nullable<BigInt>,
+    }
+}
+
 #[derive(Clone, Debug, Identifiable, Queryable, WundergraphEntity)]
 #[primary_key(hero_id, episode)]
 #[table_name = "appears_in"]
@@ -188,6 +224,7 @@ pub use self::hero::Hero;
 
 #[derive(Clone, Debug, Identifiable, WundergraphEntity)]
 #[table_name = "species"]
+#[wundergraph(unique = "name")]
 /// A species
 pub struct Species {
     /// Internal id of a species
@@ -198,6 +235,37 @@ pub struct Species {
     heros: HasMany<Hero, heros::species>,
 }
 
+#[derive(Clone, Debug, Identifiable, WundergraphEntity)]
+#[table_name = "starships"]
+/// A starship
+pub struct Starship {
+    /// Internal id of a starship
+    id: i32,
+    /// The name of a starship
+    name: String,
+    /// The length of a starship in meters
+    #[cfg(feature = "postgres")]
+    length: Option<BigDecimal>,
+    /// The registry code of a starship
+    registry: Option<Bytes>,
+    /// The weapons of a starship
+    #[cfg(feature = "postgres")]
+    weapons: Option<Vec<String>>,
+    /// The daily departure time of a starship
+    departure: Option<NaiveTime>,
+    /// The point in time a starship was commissioned
+    commissioned: Option<NaiveDateTime>,
+    /// The usual travel time of a starship
+    #[cfg(feature = "postgres")]
+    travel_time: Option<PgInterval>,
+    /// The number of passengers a starship is able to carry
+    passengers: Option<u16>,
+    /// The number of crew members of a starship
+    crew: Option<u32>,
+    /// The cargo capacity of a starship in kilograms
+    cargo_capacity: Option<u64>,
+}
+
 wundergraph::query_object! {
     /// Global query object for the schema
     Query {
@@ -207,6 +275,8 @@ wundergraph::query_object! {
         Species,
         /// Access to HomeWorlds
         HomeWorld,
+        /// Access to Starships
+        Starship,
     }
 }
 
@@ -216,6 +286,8 @@ where
     Conn: Connection + 'static,
 {
     conn: PooledConnection<ConnectionManager<Conn>>,
+    read_snapshot: bool,
+    redaction_policy: RedactionPolicy,
 }
 
 impl<Conn> MyContext<Conn>
@@ -223,7 +295,27 @@ where
     Conn: Connection + 'static,
 {
     pub fn new(conn: PooledConnection<ConnectionManager<Conn>>) -> Self {
-        Self { conn }
+        Self {
+            conn,
+            read_snapshot: false,
+            redaction_policy: RedactionPolicy::default(),
+        }
+    }
+
+    /// Load all parts of a query from the same database snapshot
+    pub fn with_read_snapshot(self) -> Self {
+        Self {
+            read_snapshot: true,
+            ..self
+        }
+    }
+
+    /// Control which error details are sent to clients
+    pub fn with_redaction_policy(self, redaction_policy: RedactionPolicy) -> Self {
+        Self {
+            redaction_policy,
+            ..self
+        }
     }
 }
 
@@ -253,6 +345,14 @@ impl WundergraphContext for MyContext<DBConnection> {
     fn get_connection(&self) -> &Self::Connection {
         &self.conn
     }
+
+    fn read_snapshot(&self) -> bool {
+        self.read_snapshot
+    }
+
+    fn redaction_policy(&self) -> RedactionPolicy {
+        self.redaction_policy
+    }
 }
 
 #[cfg(feature = "postgres")]
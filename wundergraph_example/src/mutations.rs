@@ -3,13 +3,22 @@ use super::friends;
 use super::heros;
 use super::home_worlds;
 use super::species;
+use super::starships;
 use super::AppearsIn;
 use super::Episode;
 use super::Friend;
 use super::Hero;
 use super::HomeWorld;
 use super::Species;
+use super::Starship;
+#[cfg(feature = "postgres")]
+use bigdecimal::BigDecimal;
+use chrono::{NaiveDateTime, NaiveTime};
+#[cfg(feature = "postgres")]
+use diesel::pg::data_types::PgInterval;
 use juniper::*;
+use wundergraph::query_builder::types::Bytes;
+use wundergraph::scalar::WundergraphScalarValue;
 
 #[derive(Insertable, GraphQLInputObject, Clone, Debug)]
 #[table_name = "heros"]
@@ -70,6 +79,22 @@ pub struct NewAppearsIn {
     episode: Episode,
 }
 
+#[derive(Insertable, GraphQLInputObject, Clone, Debug)]
+#[table_name = "starships"]
+#[graphql(scalar = "WundergraphScalarValue")]
+pub struct NewStarship {
+    name: String,
+    #[cfg(feature = "postgres")]
+    length: Option<BigDecimal>,
+    registry: Option<Bytes>,
+    #[cfg(feature = "postgres")]
+    weapons: Option<Vec<String>>,
+    departure: Option<NaiveTime>,
+    commissioned: Option<NaiveDateTime>,
+    #[cfg(feature = "postgres")]
+    travel_time: Option<PgInterval>,
+}
+
 wundergraph::mutation_object! {
     /// Global mutation object for the schema
     Mutation {
@@ -78,5 +103,6 @@ wundergraph::mutation_object! {
         HomeWorld(insert = NewHomeWorld, update = HomeWorldChangeset,),
         Friend( insert = NewFriend,),
         AppearsIn(insert = NewAppearsIn, ),
+        Starship(insert = NewStarship, ),
     }
 }